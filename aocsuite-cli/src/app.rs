@@ -2,24 +2,31 @@ use std::{
     fmt::Write as _,
     io::{BufRead, Write},
     path::{Path, PathBuf},
+    time::{Duration, SystemTime},
 };
 
 use crate::{
     commands::{CleanAction, EnvAction, LibAction},
+    compare::{compare_solutions, render_comparison},
+    leaderboard::render_leaderboard,
+    verify::{render_verification, verify_solutions},
     AocCliError, AocCliResult, AocCommand,
 };
 use aocsuite_client::{AocClient, AocPage};
 use aocsuite_config::{ConfigKey, Configuration};
 use aocsuite_lang::{
-    ConfirmedLibraryRemoval, ConfirmedTemplateReset, Language, LanguageRunOutput, PartResult,
-    SolverFile,
+    BenchStats, ConfirmedLibraryRemoval, ConfirmedTemplateReset, Language, LanguageRunOutput,
+    LanguageSettings, PartResult, RunOptions, SolverFile, TemplateVariables,
 };
 use aocsuite_launcher::{Launcher, OpenPuzzleRequest};
 use aocsuite_parser::{parse_calendar, parse_submission, AocSubmissionResult, Calendar};
-use aocsuite_storage::{CacheCleanScope, ContentStore, GitMode, Workspace};
+use aocsuite_storage::{
+    CacheCleanScope, ContentStore, GitMode, PuzzleAnswers, RunBenchmark, RunHistory, Workspace,
+};
 use aocsuite_utils::{
-    valid_puzzle_release, valid_year_release, CommandExecutor, LanguageId, PartSelection,
-    PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear, RunHistoryLimit,
+    format_cooldown, valid_puzzle_release, valid_year_release, CommandExecutor, FileWatcher,
+    LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear, RunHistoryLimit,
+    RunTimeout, SolutionId, WatchList,
 };
 use colored::Colorize;
 
@@ -46,6 +53,12 @@ pub fn run_aocsuite(
             valid_year_release(day, year)?;
             let calendar = content.load_calendar(year)?;
             println!("{}", render_calendar(&parse_calendar(&calendar)?));
+            let stars = content.year_stars(year)?;
+            println!(
+                "\n{} stars, {} completed days",
+                stars.total().to_string().yellow().bold(),
+                stars.completed_days()
+            );
         }
 
         AocCommand::View => {
@@ -53,15 +66,27 @@ pub fn run_aocsuite(
             launcher.open_browser(&AocPage::Puzzle(PuzzleId::new(day, year)).to_string())?;
         }
 
-        AocCommand::Submit { part, answer } => {
+        AocCommand::Submit {
+            part,
+            answer,
+            force,
+        } => {
             valid_puzzle_release(day, year)?;
+            let puzzle = PuzzleId::new(day, year);
+            if !force {
+                if let Some(remaining) = content.submission_cooldown(puzzle)? {
+                    return Err(AocCliError::SubmissionCooldown {
+                        remaining: format_cooldown(remaining),
+                    });
+                }
+            }
             let answer = match answer {
                 Some(answer) => answer,
                 None => prompt_answer()?,
             };
-            let puzzle = PuzzleId::new(day, year);
+            content.check_submission(puzzle, part, &answer)?;
             let result = parse_submission(&client.submit(puzzle, part, &answer)?)?;
-            content.record_submission(puzzle, part, &result)?;
+            content.record_submission(puzzle, part, &answer, &result)?;
             println!("{}", format_submission_result(&result));
         }
 
@@ -69,63 +94,216 @@ pub fn run_aocsuite(
             language,
             part,
             test,
+            bench,
+            watch,
+            variant,
         } => {
             valid_puzzle_release(day, year)?;
             let part = part.map_or(PartSelection::Both, PartSelection::from);
-            let path = match test {
-                Some(file) => {
-                    if file.is_empty() {
-                        workspace.ensure_example(PuzzleId::new(day, year))?
-                    } else {
-                        resolve_custom_input_path(&file, &std::env::current_dir()?)?
+            let puzzle = PuzzleId::new(day, year);
+            let language = resolve_language(config, language, workspace, executor)?;
+            if let Some(variant) = variant {
+                language.select_solution(SolutionId::new(puzzle, variant.0))?;
+            }
+            let run_history_limit = config.get::<RunHistoryLimit>(ConfigKey::RunHistoryLimit)?;
+            let options = RunOptions {
+                bench,
+                timeout: Some(config.get::<RunTimeout>(ConfigKey::RunTimeout)?.get()),
+                ..RunOptions::default()
+            };
+            let run = |input: &RunInput| {
+                run_solution(
+                    content,
+                    &language,
+                    puzzle,
+                    part,
+                    input,
+                    &options,
+                    run_history_limit,
+                )
+            };
+            let input = resolve_run_input(content, workspace, puzzle, test.as_deref())?;
+            if !watch {
+                print!("{}", run(&input)?);
+                return Ok(());
+            }
+
+            let watch_list = || -> AocCliResult<WatchList> {
+                let mut watched = language.watch_list(puzzle)?;
+                match test.as_deref() {
+                    Some("") => {
+                        watched.files.push(input.path.clone());
+                        watched.files.extend(workspace.example_answer_paths(puzzle));
                     }
+                    Some(_) => watched.files.push(input.path.clone()),
+                    None => {}
                 }
-                None => content.ensure_input(PuzzleId::new(day, year))?,
+                Ok(watched)
             };
+            let mut watched = watch_list()?;
+            let mut watcher = FileWatcher::new(&watched)?;
+            loop {
+                // Example answers may have been edited alongside the example itself.
+                let result = resolve_run_input(content, workspace, puzzle, test.as_deref())
+                    .and_then(|input| run(&input));
+                print!("\x1b[2J\x1b[H");
+                match result {
+                    Ok(rendered) => print!("{rendered}"),
+                    Err(error) => println!("{} {error}", "Run failed:".red().bold()),
+                }
+                println!(
+                    "\n{}",
+                    format!(
+                        "Watching {} file(s) and {} folder(s) for changes, press Ctrl-C to stop",
+                        watched.files.len(),
+                        watched.dirs.len()
+                    )
+                    .dimmed()
+                );
+                std::io::stdout().flush()?;
+                watcher.wait()?;
+                // Selecting another variant changes which solution file the runs depend on.
+                let current = watch_list()?;
+                if current != watched {
+                    watcher = FileWatcher::new(&current)?;
+                    watched = current;
+                }
+            }
+        }
 
+        AocCommand::Compare {
+            language,
+            part,
+            test,
+            bench,
+        } => {
+            valid_puzzle_release(day, year)?;
+            let part = part.map_or(PartSelection::Both, PartSelection::from);
+            let puzzle = PuzzleId::new(day, year);
             let language = resolve_language(config, language, workspace, executor)?;
-            let run_history_limit = config.get::<RunHistoryLimit>(ConfigKey::RunHistoryLimit)?;
+            let options = RunOptions {
+                bench,
+                timeout: Some(config.get::<RunTimeout>(ConfigKey::RunTimeout)?.get()),
+                ..RunOptions::default()
+            };
+            let input = resolve_run_input(content, workspace, puzzle, test.as_deref())?;
+            let rows = compare_solutions(
+                &language,
+                puzzle,
+                part,
+                &input.path,
+                &options,
+                |part, answer| {
+                    input
+                        .expected
+                        .as_ref()
+                        .and_then(|expected| expected.matches(part, answer))
+                        .or_else(|| {
+                            input
+                                .accepted
+                                .as_ref()
+                                .and_then(|accepted| accepted.matches(part, answer))
+                        })
+                },
+            )?;
+            print!("{}", render_comparison(&rows));
+        }
+
+        AocCommand::History {
+            language,
+            part,
+            limit,
+        } => {
+            valid_puzzle_release(day, year)?;
             let puzzle = PuzzleId::new(day, year);
-            let run = language.execute(puzzle, part, path.as_ref())?;
-            for part in [PuzzlePart::One, PuzzlePart::Two] {
-                if let Some(result) = run.run.result.part(part) {
-                    content.record_run_timing(
-                        puzzle,
-                        language.language_id(),
-                        part,
-                        result.runtime_ms(),
-                        run_history_limit,
-                    )?;
-                }
+            let language = resolve_language(config, language, workspace, executor)?;
+            let limit = match limit {
+                Some(limit) => limit,
+                None => config.get::<RunHistoryLimit>(ConfigKey::RunHistoryLimit)?,
+            };
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![PuzzlePart::One, PuzzlePart::Two],
+            };
+            let now = SystemTime::now();
+            for part in parts {
+                let history = content.run_history(puzzle, language.language_id(), part, limit)?;
+                print!("{}", render_run_history(part, &history, now));
+            }
+        }
+
+        AocCommand::Verify {
+            language,
+            all_years,
+        } => {
+            let language = resolve_language(config, language, workspace, executor)?;
+            let run_history_limit = config.get::<RunHistoryLimit>(ConfigKey::RunHistoryLimit)?;
+            let options = RunOptions {
+                timeout: Some(config.get::<RunTimeout>(ConfigKey::RunTimeout)?.get()),
+                ..RunOptions::default()
+            };
+            let puzzles = language.puzzle_solutions((!all_years).then_some(year))?;
+            let rows = verify_solutions(content, &language, &puzzles, &options, run_history_limit)?;
+            print!("{}", render_verification(&rows));
+            let regressions = rows.iter().filter(|row| row.status.is_regression()).count();
+            if regressions > 0 {
+                return Err(AocCliError::VerificationFailed { regressions });
             }
-            print!("{}", render_language_run(&run));
         }
 
-        AocCommand::Open { language } => {
+        AocCommand::Open {
+            language,
+            variant,
+            template,
+        } => {
             valid_puzzle_release(day, year)?;
             let language = resolve_language(config, language, workspace, executor)?;
             let editor_program = config.get::<String>(ConfigKey::Editor)?;
             let puzzle = PuzzleId::new(day, year);
+            let solution = match variant {
+                Some(variant) => SolutionId::new(puzzle, variant.0),
+                None => language.active_solution(puzzle),
+            };
+            let puzzle_description = content.ensure_puzzle_markdown(puzzle)?;
+            let mut variables = TemplateVariables::new(puzzle);
+            if let Some(title) = content.cached_puzzle_title(puzzle)? {
+                variables = variables.with_title(title);
+            }
             let request = OpenPuzzleRequest {
-                puzzle: content.ensure_puzzle_markdown(puzzle)?,
-                example: workspace.ensure_example(puzzle)?,
-                solution: language.ensure_solver_file(&SolverFile::ActiveSolution(puzzle))?,
+                puzzle: puzzle_description,
+                example: workspace.ensure_example(puzzle, || {
+                    content.puzzle_examples(puzzle).map_err(AocCliError::from)
+                })?,
+                solution: language.prepare_solution(solution, template.as_ref(), &variables)?,
                 input: content.ensure_input(puzzle)?,
                 working_directory: language.project_dir().to_path_buf(),
             };
             launcher.open_puzzle(editor_program, request)?;
         }
-        AocCommand::Template { language, reset } => {
+        AocCommand::Template {
+            language,
+            name,
+            reset,
+            list,
+        } => {
             let language = resolve_language(config, language, workspace, executor)?;
+            if list {
+                for template in language.templates()? {
+                    println!("{template}");
+                }
+                return Ok(());
+            }
             let editor_program = config.get::<String>(ConfigKey::Editor)?;
-            let template_path = language.ensure_solver_file(&SolverFile::SolutionTemplate)?;
+            let template = name.unwrap_or_default();
+            let template_path =
+                language.ensure_solver_file(&SolverFile::SolutionTemplate(template.clone()))?;
             let path = if reset
                 && user_confirm(
                     &mut std::io::stdin().lock(),
                     &mut std::io::stdout().lock(),
                     "Are you sure you want to delete template file? (Y/n):",
                 )? {
-                language.reset_template(ConfirmedTemplateReset::Confirmed)?
+                language.reset_template(&template, ConfirmedTemplateReset::Confirmed)?
             } else {
                 template_path
             };
@@ -239,9 +417,21 @@ pub fn run_aocsuite(
                 }
             }
         }
-        AocCommand::Leaderboard { id } => {
+        AocCommand::Leaderboard { id, print } => {
             valid_year_release(day, year)?;
-            launcher.open_browser(&AocPage::Leaderboard(year, id).to_string())?;
+            match id {
+                Some(id) if print => {
+                    let leaderboard = content.private_leaderboard(year, id)?;
+                    print!("{}", render_leaderboard(&leaderboard));
+                    if let Some(remaining) = content.leaderboard_refresh_in(year, id)? {
+                        println!(
+                            "\n{}",
+                            format!("next refresh in {}", format_cooldown(remaining)).dimmed()
+                        );
+                    }
+                }
+                id => launcher.open_browser(&AocPage::Leaderboard(year, id).to_string())?,
+            }
         }
 
         AocCommand::Clean { action } => match action {
@@ -295,6 +485,88 @@ pub fn run_aocsuite(
     Ok(())
 }
 
+/// Input of a `run` invocation with the answers its results are compared against.
+struct RunInput {
+    path: PathBuf,
+    expected: Option<PuzzleAnswers>,
+    accepted: Option<PuzzleAnswers>,
+}
+
+fn resolve_run_input(
+    content: &ContentStore,
+    workspace: &Workspace,
+    puzzle: PuzzleId,
+    test: Option<&str>,
+) -> AocCliResult<RunInput> {
+    Ok(match test {
+        Some("") => {
+            let path = workspace.ensure_example(puzzle, || {
+                content.puzzle_examples(puzzle).map_err(AocCliError::from)
+            })?;
+            if let Some(answers) = content.cached_example_answers(puzzle)? {
+                workspace.save_example_answers(puzzle, &answers)?;
+            }
+            RunInput {
+                path,
+                expected: Some(workspace.example_answers(puzzle)?),
+                accepted: None,
+            }
+        }
+        Some(file) => RunInput {
+            path: resolve_custom_input_path(file, &std::env::current_dir()?)?,
+            expected: None,
+            accepted: None,
+        },
+        None => RunInput {
+            path: content.ensure_input(puzzle)?,
+            expected: None,
+            accepted: {
+                content.backfill_accepted_answers(puzzle)?;
+                Some(content.accepted_answers(puzzle)?)
+            },
+        },
+    })
+}
+
+/// Runs the solution, records its timings and renders the results.
+fn run_solution(
+    content: &ContentStore,
+    language: &Language,
+    puzzle: PuzzleId,
+    part: PartSelection,
+    input: &RunInput,
+    options: &RunOptions,
+    run_history_limit: RunHistoryLimit,
+) -> AocCliResult<String> {
+    let run = language.execute(puzzle, part, &input.path, options)?;
+    for part in [PuzzlePart::One, PuzzlePart::Two] {
+        let Some(result) = run.run.result.part(part) else {
+            continue;
+        };
+        match result.bench() {
+            Some(stats) => content.record_benchmark(
+                puzzle,
+                language.language_id(),
+                part,
+                &run_benchmark(stats),
+                run_history_limit,
+            )?,
+            None => content.record_run_timing(
+                puzzle,
+                language.language_id(),
+                part,
+                result.runtime_ms(),
+                run_history_limit,
+            )?,
+        }
+    }
+    Ok(render_language_run(
+        &run,
+        input.expected.as_ref(),
+        input.accepted.as_ref(),
+    ))
+}
+
 fn render_calendar(calendar: &Calendar) -> String {
     calendar
         .rows
@@ -313,27 +585,127 @@ fn render_calendar(calendar: &Calendar) -> String {
         .join("\n")
 }
 
-fn render_language_run(output: &LanguageRunOutput) -> String {
+fn render_language_run(
+    output: &LanguageRunOutput,
+    expected: Option<&PuzzleAnswers>,
+    accepted: Option<&PuzzleAnswers>,
+) -> String {
     let mut rendered = String::new();
     render_stream(&mut rendered, "Compiler output", &output.compile.stdout);
     render_stream(&mut rendered, "Compiler errors", &output.compile.stderr);
     render_stream(&mut rendered, "Solver output", &output.run.stdout);
     render_stream(&mut rendered, "Solver errors", &output.run.stderr);
 
+    let verdict = |part: PuzzlePart, result: &PartResult| {
+        expected.and_then(|expected| {
+            expected
+                .part(part)
+                .zip(expected.matches(part, result.answer()))
+        })
+    };
+    let regression = |part: PuzzlePart, result: &PartResult| {
+        accepted.and_then(|accepted| {
+            accepted
+                .part(part)
+                .zip(accepted.matches(part, result.answer()))
+        })
+    };
     let part1 = output.run.result.part(PuzzlePart::One);
     let part2 = output.run.result.part(PuzzlePart::Two);
-    if let Some(part) = part1 {
-        render_part(&mut rendered, "Part 1", part);
+    if let Some(result) = part1 {
+        render_part(
+            &mut rendered,
+            "Part 1",
+            result,
+            verdict(PuzzlePart::One, result),
+            regression(PuzzlePart::One, result),
+        );
     }
     if part1.is_some() && part2.is_some() {
         rendered.push('\n');
     }
-    if let Some(part) = part2 {
-        render_part(&mut rendered, "Part 2", part);
+    if let Some(result) = part2 {
+        render_part(
+            &mut rendered,
+            "Part 2",
+            result,
+            verdict(PuzzlePart::Two, result),
+            regression(PuzzlePart::Two, result),
+        );
     }
     rendered
 }
 
+fn render_run_history(part: PuzzlePart, history: &RunHistory, now: SystemTime) -> String {
+    let mut rendered = String::new();
+    writeln!(rendered, "{}", format!("Part {part}").bold()).expect("write to string");
+    let (Some(min), Some(median), Some(max)) = (history.min(), history.median(), history.max())
+    else {
+        writeln!(rendered, "  no recorded runs").expect("write to string");
+        return rendered;
+    };
+    writeln!(
+        rendered,
+        "  {} runs  min {}  median {}  max {}",
+        history.runs.len(),
+        format_runtime(min),
+        format_runtime(median),
+        format_runtime(max)
+    )
+    .expect("write to string");
+    for run in &history.runs {
+        let age = now
+            .duration_since(run.recorded_at)
+            .unwrap_or_default()
+            .as_secs();
+        let benchmark = run
+            .benchmark
+            .map(|benchmark| format!("  bench of {}", benchmark.iterations))
+            .unwrap_or_default();
+        writeln!(
+            rendered,
+            "  {:>10}  {}{benchmark}",
+            format_runtime(run.duration),
+            format_age(age)
+        )
+        .expect("write to string");
+    }
+    rendered
+}
+
+fn run_benchmark(stats: &BenchStats) -> RunBenchmark {
+    RunBenchmark {
+        iterations: stats.iterations,
+        min: Duration::from_nanos(stats.min_ns),
+        mean: Duration::from_nanos(stats.mean_ns),
+        median: Duration::from_nanos(stats.median_ns),
+        stddev: Duration::from_nanos(stats.stddev_ns),
+    }
+}
+
+/// Whole milliseconds stay as they are; benchmark figures keep their sub-millisecond precision.
+pub(crate) fn format_runtime(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1_000.0)
+    } else if nanos.is_multiple_of(1_000_000) {
+        format!("{} ms", duration.as_millis())
+    } else {
+        format!("{:.2} ms", nanos as f64 / 1_000_000.0)
+    }
+}
+
+fn format_age(seconds: u64) -> String {
+    match seconds {
+        0..60 => "just now".to_owned(),
+        60..3_600 => format!("{}m ago", seconds / 60),
+        3_600..86_400 => format!("{}h ago", seconds / 3_600),
+        _ => format!("{}d ago", seconds / 86_400),
+    }
+}
+
 fn render_stream(rendered: &mut String, label: &str, stream: &str) {
     if !stream.is_empty() {
         writeln!(rendered, "{label}:").expect("write to string");
@@ -341,12 +713,49 @@ fn render_stream(rendered: &mut String, label: &str, stream: &str) {
     }
 }
 
-fn render_part(rendered: &mut String, label: &str, part: &PartResult) {
+fn render_part(
+    rendered: &mut String,
+    label: &str,
+    part: &PartResult,
+    expected: Option<(&str, bool)>,
+    accepted: Option<(&str, bool)>,
+) {
     writeln!(rendered, "\n┌──────────────┐").expect("write to string");
     writeln!(rendered, "│   {label:<6}     │").expect("write to string");
     writeln!(rendered, "└──────────────┘").expect("write to string");
     writeln!(rendered, "Answer: {}", part.answer()).expect("write to string");
-    writeln!(rendered, "Runtime: {} ms", part.runtime_ms()).expect("write to string");
+    match part.bench() {
+        Some(stats) => {
+            let benchmark = run_benchmark(stats);
+            writeln!(rendered, "Bench: {} runs", benchmark.iterations).expect("write to string");
+            writeln!(
+                rendered,
+                "  min {}  mean {}  median {}  stddev {}",
+                format_runtime(benchmark.min),
+                format_runtime(benchmark.mean),
+                format_runtime(benchmark.median),
+                format_runtime(benchmark.stddev)
+            )
+            .expect("write to string");
+        }
+        None => writeln!(rendered, "Runtime: {} ms", part.runtime_ms()).expect("write to string"),
+    }
+    if let Some((answer, passed)) = expected {
+        let verdict = if passed {
+            "PASS".green().bold()
+        } else {
+            "FAIL".red().bold()
+        };
+        writeln!(rendered, "Expected: {answer} {verdict}").expect("write to string");
+    }
+    if let Some((answer, matched)) = accepted {
+        let verdict = if matched {
+            "MATCH".green().bold()
+        } else {
+            "MISMATCH".red().bold()
+        };
+        writeln!(rendered, "Accepted: {answer} {verdict}").expect("write to string");
+    }
 }
 
 fn is_interactive_git_command(args: &[String]) -> bool {
@@ -394,7 +803,9 @@ fn resolve_language<'workspace>(
         Some(language_id) => language_id,
         None => config.get::<LanguageId>(ConfigKey::Language)?,
     };
-    Ok(Language::new(language_id, workspace, executor))
+    config.check_language(language_id)?;
+    let settings = LanguageSettings::from_config(config)?;
+    Ok(Language::new(language_id, workspace, &settings, executor)?)
 }
 
 fn user_confirm(
@@ -445,9 +856,63 @@ fn resolve_custom_input_path(file: &str, invocation_dir: &Path) -> std::io::Resu
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use std::{
+        io::Cursor,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    use aocsuite_storage::{RunBenchmark, RunHistory, RunTiming};
+    use aocsuite_utils::PuzzlePart;
+
+    use super::{format_runtime, render_run_history, user_confirm};
+
+    #[test]
+    fn run_history_lists_summary_and_recent_runs() {
+        colored::control::set_override(false);
+        let now = UNIX_EPOCH + Duration::from_secs(100_000);
+        let run = |millis, age| RunTiming {
+            duration: Duration::from_millis(millis),
+            recorded_at: now - Duration::from_secs(age),
+            benchmark: None,
+        };
+        let history = RunHistory {
+            runs: vec![run(12, 30), run(30, 7_200), run(9, 90_000)],
+        };
 
-    use super::user_confirm;
+        assert_eq!(
+            render_run_history(PuzzlePart::Two, &history, now),
+            "Part 2\n  3 runs  min 9 ms  median 12 ms  max 30 ms\n       12 ms  just now\n       30 ms  2h ago\n        9 ms  1d ago\n"
+        );
+
+        let median = Duration::from_nanos(1_450);
+        let benchmarked = RunHistory {
+            runs: vec![RunTiming {
+                duration: median,
+                recorded_at: now,
+                benchmark: Some(RunBenchmark {
+                    iterations: 100,
+                    min: Duration::from_nanos(1_200),
+                    mean: Duration::from_nanos(1_530),
+                    median,
+                    stddev: Duration::from_nanos(210),
+                }),
+            }],
+        };
+        assert!(render_run_history(PuzzlePart::One, &benchmarked, now)
+            .ends_with("    1.4 µs  just now  bench of 100\n"));
+        assert_eq!(
+            render_run_history(PuzzlePart::One, &RunHistory::default(), now),
+            "Part 1\n  no recorded runs\n"
+        );
+    }
+
+    #[test]
+    fn runtimes_keep_sub_millisecond_precision() {
+        assert_eq!(format_runtime(Duration::from_nanos(840)), "840 ns");
+        assert_eq!(format_runtime(Duration::from_nanos(12_345)), "12.3 µs");
+        assert_eq!(format_runtime(Duration::from_millis(7)), "7 ms");
+        assert_eq!(format_runtime(Duration::from_nanos(2_500_000)), "2.50 ms");
+    }
 
     #[test]
     fn confirmations_reject_eof_but_accept_empty_and_yes() {
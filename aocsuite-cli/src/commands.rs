@@ -1,5 +1,10 @@
+use std::str::FromStr;
+
 use aocsuite_config::ConfigKey;
-use aocsuite_utils::{LanguageId, PuzzlePart};
+use aocsuite_utils::{
+    BenchIterations, DomainError, LanguageId, PuzzlePart, RunHistoryLimit, SolutionVariant,
+    TemplateName,
+};
 use clap::{Subcommand, ValueEnum};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -8,6 +13,18 @@ pub enum ConfigCommandKey {
     Year,
     Editor,
     RunHistoryLimit,
+    RunTimeout,
+    CppCompiler,
+    CppFlags,
+    TypeScriptRuntime,
+    PythonBackend,
+    PythonVersion,
+    RustToolchain,
+    RustEdition,
+    RustProfile,
+    RustReleaseOptions,
+    Leaderboards,
+    Offline,
     Session,
 }
 
@@ -18,6 +35,18 @@ impl std::fmt::Display for ConfigCommandKey {
             Self::Year => "year",
             Self::Editor => "editor",
             Self::RunHistoryLimit => "run_history_limit",
+            Self::RunTimeout => "run_timeout",
+            Self::CppCompiler => "cpp_compiler",
+            Self::CppFlags => "cpp_flags",
+            Self::TypeScriptRuntime => "typescript_runtime",
+            Self::PythonBackend => "python_backend",
+            Self::PythonVersion => "python_version",
+            Self::RustToolchain => "rust_toolchain",
+            Self::RustEdition => "rust_edition",
+            Self::RustProfile => "rust_profile",
+            Self::RustReleaseOptions => "rust_release_options",
+            Self::Leaderboards => "leaderboards",
+            Self::Offline => "offline",
             Self::Session => "session",
         })
     }
@@ -30,11 +59,38 @@ impl From<ConfigCommandKey> for ConfigKey {
             ConfigCommandKey::Year => Self::Year,
             ConfigCommandKey::Editor => Self::Editor,
             ConfigCommandKey::RunHistoryLimit => Self::RunHistoryLimit,
+            ConfigCommandKey::RunTimeout => Self::RunTimeout,
+            ConfigCommandKey::CppCompiler => Self::CppCompiler,
+            ConfigCommandKey::CppFlags => Self::CppFlags,
+            ConfigCommandKey::TypeScriptRuntime => Self::TypeScriptRuntime,
+            ConfigCommandKey::PythonBackend => Self::PythonBackend,
+            ConfigCommandKey::PythonVersion => Self::PythonVersion,
+            ConfigCommandKey::RustToolchain => Self::RustToolchain,
+            ConfigCommandKey::RustEdition => Self::RustEdition,
+            ConfigCommandKey::RustProfile => Self::RustProfile,
+            ConfigCommandKey::RustReleaseOptions => Self::RustReleaseOptions,
+            ConfigCommandKey::Leaderboards => Self::Leaderboards,
+            ConfigCommandKey::Offline => Self::Offline,
             ConfigCommandKey::Session => Self::Session,
         }
     }
 }
 
+/// A `--variant` argument: a variant name, or `default` for the puzzle's main solution.
+#[derive(Debug, Clone, Copy)]
+pub struct VariantArg(pub Option<SolutionVariant>);
+
+impl FromStr for VariantArg {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == SolutionVariant::DEFAULT_NAME {
+            return Ok(Self(None));
+        }
+        value.parse().map(|variant| Self(Some(variant)))
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum AocCommand {
     /// Show the Advent of Code calendar
@@ -47,6 +103,14 @@ pub enum AocCommand {
     Open {
         #[arg(long)]
         language: Option<LanguageId>,
+
+        /// Select and open a named solution variant, created from the main solution if missing
+        #[arg(long)]
+        variant: Option<VariantArg>,
+
+        /// Template to create a new solution from; refused if the solution exists (default: the default template)
+        #[arg(long)]
+        template: Option<TemplateName>,
     },
     /// Manage execution environment
     Env {
@@ -69,8 +133,16 @@ pub enum AocCommand {
         #[arg(long)]
         language: Option<LanguageId>,
 
+        /// Named template to edit, created from the default template if missing
+        #[arg(long)]
+        name: Option<TemplateName>,
+
         #[arg(long)]
         reset: bool,
+
+        /// List the templates of the language instead of editing one
+        #[arg(long, conflicts_with_all = ["name", "reset"])]
+        list: bool,
     },
 
     /// Run the day
@@ -86,6 +158,61 @@ pub enum AocCommand {
         /// --test with no arg
         #[arg(long, default_missing_value = "", num_args=0..=1)]
         test: Option<String>,
+
+        /// Time each part N times after a warmup and report nanosecond statistics
+        #[arg(long, value_name = "N")]
+        bench: Option<BenchIterations>,
+
+        /// Re-run whenever the solution, a library or the --test input is saved
+        #[arg(long)]
+        watch: bool,
+
+        /// Select a named solution variant before running ("default" for the main solution)
+        #[arg(long)]
+        variant: Option<VariantArg>,
+    },
+
+    /// Run every solution variant of the day on the same input and compare them
+    Compare {
+        #[arg(long)]
+        language: Option<LanguageId>,
+
+        /// Puzzle part
+        #[arg(long)]
+        part: Option<PuzzlePart>,
+
+        /// Input file to use instead of AoC input. AocSuite Example file can be used by supplying
+        /// --test with no arg
+        #[arg(long, default_missing_value = "", num_args=0..=1)]
+        test: Option<String>,
+
+        /// Time each part N times after a warmup and report median runtimes
+        #[arg(long, value_name = "N")]
+        bench: Option<BenchIterations>,
+    },
+
+    /// Show recent solver runtimes of the day
+    History {
+        #[arg(long)]
+        language: Option<LanguageId>,
+
+        /// Puzzle part
+        #[arg(long)]
+        part: Option<PuzzlePart>,
+
+        /// Number of runs to show (default: the run history limit)
+        #[arg(long, short = 'n')]
+        limit: Option<RunHistoryLimit>,
+    },
+
+    /// Re-run stored solutions on cached input and compare them with accepted answers
+    Verify {
+        #[arg(long)]
+        language: Option<LanguageId>,
+
+        /// Verify solutions of every year instead of the selected one
+        #[arg(long)]
+        all_years: bool,
     },
 
     /// Submit answer to Advent of Code
@@ -96,11 +223,19 @@ pub enum AocCommand {
 
         /// Puzzle answer. Will prompt if not specified
         answer: Option<String>,
+
+        /// Submit even while a submission cooldown is active
+        #[arg(long, short)]
+        force: bool,
     },
-    /// Open Leaderboard in browser
+    /// Open a leaderboard in the browser, or print a private one
     Leaderboard {
         /// Leaderboard ID for private leaderboard
         id: Option<u32>,
+
+        /// Print the private leaderboard in the terminal instead of opening it
+        #[arg(long, requires = "id")]
+        print: bool,
     },
     /// Change a configuration (year, language, and session )
     Config {
@@ -0,0 +1,166 @@
+use std::{fmt::Write as _, path::Path, time::Duration};
+
+use aocsuite_lang::{Language, RunOptions};
+use aocsuite_utils::{PartSelection, PuzzleId, PuzzlePart, SolutionId};
+use colored::Colorize;
+
+use crate::{app::format_runtime, AocCliResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CompareRow {
+    pub solution: SolutionId,
+    pub part: Option<PuzzlePart>,
+    pub outcome: CompareOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CompareOutcome {
+    Answer {
+        answer: String,
+        /// The runtime of a single run, or the median of a benchmark.
+        runtime: Duration,
+        /// Whether the answer matches the expected or accepted one, when that is known.
+        verdict: Option<bool>,
+    },
+    Error(String),
+}
+
+/// Runs every stored solution of the puzzle on the same input.
+///
+/// `check` compares an answer with the known answer of its part. Timings are not recorded in the
+/// run history, which tracks the selected solution only.
+pub(crate) fn compare_solutions(
+    language: &Language,
+    puzzle: PuzzleId,
+    part: PartSelection,
+    input: &Path,
+    options: &RunOptions,
+    check: impl Fn(PuzzlePart, &str) -> Option<bool>,
+) -> AocCliResult<Vec<CompareRow>> {
+    let mut rows = Vec::new();
+    for (solution, run) in language.compare(puzzle, part, input, options)? {
+        let run = match run {
+            Ok(run) => run,
+            Err(error) => {
+                rows.push(CompareRow {
+                    solution,
+                    part: None,
+                    outcome: CompareOutcome::Error(error.to_string()),
+                });
+                continue;
+            }
+        };
+        for part in [PuzzlePart::One, PuzzlePart::Two] {
+            let Some(result) = run.run.result.part(part) else {
+                continue;
+            };
+            let runtime = match result.bench() {
+                Some(stats) => Duration::from_nanos(stats.median_ns),
+                None => Duration::from_millis(result.runtime_ms() as u64),
+            };
+            rows.push(CompareRow {
+                solution,
+                part: Some(part),
+                outcome: CompareOutcome::Answer {
+                    answer: result.answer().to_owned(),
+                    runtime,
+                    verdict: check(part, result.answer()),
+                },
+            });
+        }
+    }
+    Ok(rows)
+}
+
+pub(crate) fn render_comparison(rows: &[CompareRow]) -> String {
+    let mut rendered = String::new();
+    writeln!(
+        rendered,
+        "{:<24} {:<6} {:>10}  Answer",
+        "Variant", "Part", "Runtime"
+    )
+    .expect("write to string");
+    for row in rows {
+        let variant = row.solution.variant_name();
+        let part = row.part.map(|part| part.to_string()).unwrap_or_default();
+        match &row.outcome {
+            CompareOutcome::Answer {
+                answer,
+                runtime,
+                verdict,
+            } => {
+                let verdict = match verdict {
+                    Some(true) => format!(" {}", "PASS".green().bold()),
+                    Some(false) => format!(" {}", "FAIL".red().bold()),
+                    None => String::new(),
+                };
+                writeln!(
+                    rendered,
+                    "{variant:<24} {part:<6} {:>10}  {answer}{verdict}",
+                    format_runtime(*runtime)
+                )
+                .expect("write to string");
+            }
+            CompareOutcome::Error(message) => {
+                writeln!(
+                    rendered,
+                    "{variant:<24} {part:<6} {:>10}  {} {message}",
+                    "",
+                    "ERROR".red().bold()
+                )
+                .expect("write to string");
+            }
+        }
+    }
+    if rows.is_empty() {
+        writeln!(rendered, "\nno stored solutions for this puzzle").expect("write to string");
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aocsuite_utils::{PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear, SolutionId};
+
+    use super::{render_comparison, CompareOutcome, CompareRow};
+
+    #[test]
+    fn comparison_table_lists_every_variant_with_its_verdict() {
+        colored::control::set_override(false);
+        let puzzle = PuzzleId::new(PuzzleDay::new(5).unwrap(), PuzzleYear::new(2024).unwrap());
+        let fast: SolutionId = "year2024_day5.fast".parse().expect("variant id");
+        let broken: SolutionId = "year2024_day5.broken".parse().expect("variant id");
+        let rendered = render_comparison(&[
+            CompareRow {
+                solution: puzzle.into(),
+                part: Some(PuzzlePart::One),
+                outcome: CompareOutcome::Answer {
+                    answer: "143".to_owned(),
+                    runtime: Duration::from_millis(12),
+                    verdict: Some(true),
+                },
+            },
+            CompareRow {
+                solution: fast,
+                part: Some(PuzzlePart::One),
+                outcome: CompareOutcome::Answer {
+                    answer: "142".to_owned(),
+                    runtime: Duration::from_nanos(840),
+                    verdict: Some(false),
+                },
+            },
+            CompareRow {
+                solution: broken,
+                part: None,
+                outcome: CompareOutcome::Error("timed out after 5s".to_owned()),
+            },
+        ]);
+
+        assert!(rendered.contains("default                  1           12 ms  143 PASS"));
+        assert!(rendered.contains("fast                     1          840 ns  142 FAIL"));
+        assert!(rendered
+            .contains("broken                                      ERROR timed out after 5s"));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Write as _;
+
+use aocsuite_parser::PrivateLeaderboard;
+use colored::Colorize;
+
+/// Renders a private leaderboard ranked by local score, with one star glyph per day (see
+/// [`aocsuite_parser::DayCompletion::glyph`]). Members tied on score share a rank.
+pub(crate) fn render_leaderboard(leaderboard: &PrivateLeaderboard) -> String {
+    let days = leaderboard.days(None);
+    let mut rendered = String::new();
+    let header = |digit: fn(u32) -> String| {
+        days.iter()
+            .map(|day| digit(u32::from(*day)))
+            .collect::<String>()
+    };
+    let tens = header(|day| match day / 10 {
+        0 => " ".to_owned(),
+        tens => tens.to_string(),
+    });
+    writeln!(rendered, "{:<10} {}", "", tens.trim_end()).expect("write to string");
+    writeln!(
+        rendered,
+        "{:<10} {}",
+        "",
+        header(|day| (day % 10).to_string())
+    )
+    .expect("write to string");
+
+    for (rank, member) in leaderboard.ranking() {
+        let rank = rank.map(|rank| format!("{rank})")).unwrap_or_default();
+        let stars: String = days
+            .iter()
+            .map(|day| {
+                let completion = member.day(*day);
+                let glyph = completion.glyph().to_string();
+                match completion.stars() {
+                    2 => glyph.yellow().bold().to_string(),
+                    1 => glyph.bright_blue().to_string(),
+                    _ => glyph.bright_black().to_string(),
+                }
+            })
+            .collect();
+        writeln!(
+            rendered,
+            "{rank:>4} {:>5} {stars} {}",
+            member.local_score,
+            member.display_name()
+        )
+        .expect("write to string");
+    }
+    if leaderboard.members.is_empty() {
+        writeln!(rendered, "\nno members on this leaderboard").expect("write to string");
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use aocsuite_parser::parse_private_leaderboard;
+
+    use super::render_leaderboard;
+
+    #[test]
+    fn leaderboard_table_ranks_members_with_star_glyphs() {
+        colored::control::set_override(false);
+        let leaderboard = parse_private_leaderboard(
+            r#"{"owner_id":1,"num_days":12,"members":{
+                "1":{"id":1,"name":"owner","stars":3,"local_score":8,"last_star_ts":10,
+                    "completion_day_level":{"1":{"1":{"get_star_ts":5},"2":{"get_star_ts":6}},
+                    "3":{"1":{"get_star_ts":10}}}},
+                "2":{"id":2,"name":null,"stars":3,"local_score":8,"last_star_ts":20,
+                    "completion_day_level":{"2":{"1":{"get_star_ts":7},"2":{"get_star_ts":20}},
+                    "12":{"1":{"get_star_ts":9}}}},
+                "3":{"id":3,"name":"late","stars":0,"local_score":0,"last_star_ts":0,
+                    "completion_day_level":{}}}}"#,
+        )
+        .expect("parse leaderboard");
+
+        assert_eq!(
+            render_leaderboard(&leaderboard),
+            concat!(
+                "                    111\n",
+                "           123456789012\n",
+                "  1)     8 *.+......... owner\n",
+                "         8 .*.........+ (anonymous user #2)\n",
+                "  3)     0 ............ late\n",
+            )
+        );
+    }
+}
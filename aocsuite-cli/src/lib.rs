@@ -8,16 +8,25 @@ use aocsuite_utils::ReleaseError;
 use thiserror::Error;
 mod app;
 mod commands;
+mod compare;
+mod leaderboard;
+mod verify;
 
 pub use app::run_aocsuite;
 
-pub use commands::{AocCommand, ConfigCommand, ConfigCommandKey};
+pub use commands::{AocCommand, ConfigCommand, ConfigCommandKey, VariantArg};
 
 #[derive(Error, Debug)]
 pub enum AocCliError {
     #[error("operation not allowed: {0}")]
     NotAllowed(&'static str),
 
+    #[error("submission cooldown active: {remaining} remaining (use --force to submit anyway)")]
+    SubmissionCooldown { remaining: String },
+
+    #[error("{regressions} solution part(s) failed verification")]
+    VerificationFailed { regressions: usize },
+
     #[error(transparent)]
     Client(#[from] AocClientError),
 
@@ -50,6 +59,9 @@ pub enum AocCliError {
 
     #[error(transparent)]
     Workspace(#[from] aocsuite_storage::WorkspaceError),
+
+    #[error(transparent)]
+    Watch(#[from] aocsuite_utils::WatchError),
 }
 
 type AocCliResult<T> = Result<T, AocCliError>;
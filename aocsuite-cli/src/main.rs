@@ -1,10 +1,16 @@
-use std::io::{BufRead, Write};
+use std::{
+    io::{BufRead, Write},
+    sync::Arc,
+};
 
 use aocsuite_cli::{run_aocsuite, AocCliError, AocCommand, ConfigCommand, ConfigCommandKey};
-use aocsuite_client::{AocClient, AocClientOptions};
+use aocsuite_client::{AocClient, AocClientOptions, RequestThrottle};
 use aocsuite_config::{AocConfigError, ConfigKey, Configuration};
 use aocsuite_storage::{get_aocsuite_dir, ContentStore, RuntimeLayout, Workspace};
-use aocsuite_utils::{default_puzzle_date, PuzzleDay, PuzzleYear, SystemCommandExecutor};
+use aocsuite_utils::{
+    default_puzzle_date, stop_supervised_commands_on_interrupt, PuzzleDay, PuzzleYear,
+    SystemCommandExecutor,
+};
 
 use clap::Parser;
 
@@ -22,6 +28,10 @@ struct AocCli {
     /// Specify year for calendar, exercises, etc (default: latest released or configured)
     #[arg(long)]
     year: Option<PuzzleYear>,
+
+    /// Never contact AoC and work from cached content only (default: the `offline` config key)
+    #[arg(long, global = true)]
+    offline: bool,
 }
 
 fn terminate_with_error(err: AocCliError) -> ! {
@@ -31,6 +41,8 @@ fn terminate_with_error(err: AocCliError) -> ! {
 
 fn main() {
     let args = AocCli::parse();
+    stop_supervised_commands_on_interrupt()
+        .unwrap_or_else(|error| terminate_with_error(error.into()));
     let root = get_aocsuite_dir().unwrap_or_else(|error| terminate_with_error(error.into()));
     let layout =
         RuntimeLayout::new(&root).unwrap_or_else(|error| terminate_with_error(error.into()));
@@ -61,7 +73,24 @@ fn main() {
         Err(AocConfigError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => None,
         Err(error) => terminate_with_error(error.into()),
     };
-    let client = AocClient::new(session.as_deref(), AocClientOptions::default())
+    let offline = args.offline
+        || config
+            .get::<bool>(ConfigKey::Offline)
+            .unwrap_or_else(|error| terminate_with_error(error.into()));
+    let options = AocClientOptions {
+        offline,
+        throttle: Some(RequestThrottle {
+            on_wait: Arc::new(|class, wait| {
+                eprintln!(
+                    "waiting {}s before the next AoC {class} request",
+                    wait.as_secs_f64().ceil()
+                )
+            }),
+            ..RequestThrottle::new(layout.throttle_dir())
+        }),
+        ..AocClientOptions::default()
+    };
+    let client = AocClient::new(session.as_deref(), options)
         .unwrap_or_else(|error| terminate_with_error(error.into()));
     let content = ContentStore::open(layout.cache_dir(), &client)
         .unwrap_or_else(|error| terminate_with_error(error.into()));
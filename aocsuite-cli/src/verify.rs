@@ -0,0 +1,328 @@
+use std::fmt::Write as _;
+
+use aocsuite_lang::{Language, RunOptions};
+use aocsuite_storage::ContentStore;
+use aocsuite_utils::{PartSelection, PuzzleId, PuzzlePart, RunHistoryLimit};
+use colored::Colorize;
+
+use crate::AocCliResult;
+
+/// Runtimes within this many milliseconds of the previous run never count as slower.
+const SLOWER_MIN_DELTA_MS: u128 = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VerifyRow {
+    pub puzzle: PuzzleId,
+    pub part: Option<PuzzlePart>,
+    pub runtime_ms: Option<u128>,
+    pub status: VerifyStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum VerifyStatus {
+    Pass,
+    Slower { previous_ms: u128 },
+    Fail { answer: String, accepted: String },
+    Error(String),
+    NoInput,
+    NoAcceptedAnswer,
+}
+
+impl VerifyStatus {
+    pub(crate) fn is_regression(&self) -> bool {
+        matches!(self, Self::Fail { .. } | Self::Error(_))
+    }
+}
+
+/// Runs each puzzle solution on its cached input and compares it with the accepted answers.
+///
+/// Puzzles without a cached input or an accepted answer are reported but not run, so
+/// verification never contacts AoC.
+pub(crate) fn verify_solutions(
+    content: &ContentStore,
+    language: &Language,
+    puzzles: &[PuzzleId],
+    options: &RunOptions,
+    run_history_limit: RunHistoryLimit,
+) -> AocCliResult<Vec<VerifyRow>> {
+    // Each run points the shared active solution link at its puzzle; keep the one being worked on.
+    let selection = language.keep_selection();
+    let mut rows = Vec::new();
+    for &puzzle in puzzles {
+        let skipped = |status| VerifyRow {
+            puzzle,
+            part: None,
+            runtime_ms: None,
+            status,
+        };
+        let Some(input) = content.cached_input(puzzle)? else {
+            rows.push(skipped(VerifyStatus::NoInput));
+            continue;
+        };
+        content.backfill_accepted_answers(puzzle)?;
+        let accepted = content.accepted_answers(puzzle)?;
+        let selection = match (&accepted.part1, &accepted.part2) {
+            (_, Some(_)) => PartSelection::Both,
+            (Some(_), None) => PartSelection::One,
+            (None, None) => {
+                rows.push(skipped(VerifyStatus::NoAcceptedAnswer));
+                continue;
+            }
+        };
+
+        let run = match language.execute(puzzle, selection, &input, options) {
+            Ok(run) => run,
+            Err(error) => {
+                rows.push(skipped(VerifyStatus::Error(error.to_string())));
+                continue;
+            }
+        };
+        for part in [PuzzlePart::One, PuzzlePart::Two] {
+            let Some(accepted) = accepted.part(part) else {
+                continue;
+            };
+            let Some(result) = run.run.result.part(part) else {
+                rows.push(VerifyRow {
+                    puzzle,
+                    part: Some(part),
+                    runtime_ms: None,
+                    status: VerifyStatus::Error("solver returned no answer".to_owned()),
+                });
+                continue;
+            };
+            let previous_ms = content.latest_runtime_ms(puzzle, language.language_id(), part)?;
+            content.record_run_timing(
+                puzzle,
+                language.language_id(),
+                part,
+                result.runtime_ms(),
+                run_history_limit,
+            )?;
+            let status = if result.answer().trim() != accepted.trim() {
+                VerifyStatus::Fail {
+                    answer: result.answer().to_owned(),
+                    accepted: accepted.to_owned(),
+                }
+            } else {
+                match previous_ms {
+                    Some(previous_ms) if is_slower(previous_ms, result.runtime_ms()) => {
+                        VerifyStatus::Slower { previous_ms }
+                    }
+                    _ => VerifyStatus::Pass,
+                }
+            };
+            rows.push(VerifyRow {
+                puzzle,
+                part: Some(part),
+                runtime_ms: Some(result.runtime_ms()),
+                status,
+            });
+        }
+    }
+    selection.restore()?;
+    Ok(rows)
+}
+
+/// A run is slower when it takes more than one and a half times the previous runtime.
+fn is_slower(previous_ms: u128, runtime_ms: u128) -> bool {
+    runtime_ms >= previous_ms + SLOWER_MIN_DELTA_MS && runtime_ms * 2 > previous_ms * 3
+}
+
+pub(crate) fn render_verification(rows: &[VerifyRow]) -> String {
+    let mut rendered = String::new();
+    writeln!(
+        rendered,
+        "{:<16} {:<6} {:<8} {:>10}  Details",
+        "Puzzle", "Part", "Status", "Runtime"
+    )
+    .expect("write to string");
+    for row in rows {
+        let (status, details) = match &row.status {
+            VerifyStatus::Pass => ("PASS".green().bold(), String::new()),
+            VerifyStatus::Slower { previous_ms } => (
+                "SLOWER".yellow().bold(),
+                format!("previously {previous_ms} ms"),
+            ),
+            VerifyStatus::Fail { answer, accepted } => (
+                "FAIL".red().bold(),
+                format!("got {answer}, accepted {accepted}"),
+            ),
+            VerifyStatus::Error(message) => ("ERROR".red().bold(), message.clone()),
+            VerifyStatus::NoInput => ("SKIP".dimmed(), "input not cached".to_owned()),
+            VerifyStatus::NoAcceptedAnswer => ("SKIP".dimmed(), "no accepted answer".to_owned()),
+        };
+        let puzzle = format!("{} day {}", row.puzzle.year, row.puzzle.day);
+        let part = row.part.map(|part| part.to_string()).unwrap_or_default();
+        let runtime = row
+            .runtime_ms
+            .map(|runtime_ms| format!("{runtime_ms} ms"))
+            .unwrap_or_default();
+        writeln!(
+            rendered,
+            "{puzzle:<16} {part:<6} {:<8} {runtime:>10}  {details}",
+            status.to_string()
+        )
+        .expect("write to string");
+    }
+
+    let passed = rows
+        .iter()
+        .filter(|row| matches!(row.status, VerifyStatus::Pass | VerifyStatus::Slower { .. }))
+        .count();
+    let regressions = rows.iter().filter(|row| row.status.is_regression()).count();
+    let skipped = rows.len() - passed - regressions;
+    writeln!(
+        rendered,
+        "\n{passed} passed, {regressions} failed, {skipped} skipped"
+    )
+    .expect("write to string");
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Read, Write},
+        net::TcpListener,
+        process::Output,
+        thread,
+    };
+
+    use aocsuite_client::{AocClient, AocClientOptions};
+    use aocsuite_lang::{Language, LanguageSettings, RunOptions};
+    use aocsuite_parser::AocSubmissionResult;
+    use aocsuite_storage::{ContentStore, RuntimeLayout, Workspace};
+    use aocsuite_utils::{
+        CommandExecutor, CommandRequest, LanguageId, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear,
+        RunHistoryLimit,
+    };
+
+    use super::{is_slower, render_verification, verify_solutions, VerifyRow, VerifyStatus};
+
+    /// Succeeds every command and answers `42` for every solver run.
+    struct SolverExecutor;
+
+    impl CommandExecutor for SolverExecutor {
+        fn execute(&self, request: &CommandRequest) -> io::Result<Output> {
+            if request.args.last().is_some_and(|part| part == "1") {
+                std::fs::write(
+                    &request.args[1],
+                    r#"{"part1":{"answer":"42","runtime_ms":1},"part2":null}"#,
+                )?;
+            }
+            Ok(Output {
+                status: std::process::ExitStatus::default(),
+                stdout: Vec::new(),
+                stderr: Vec::new(),
+            })
+        }
+    }
+
+    /// A client whose only request is answered with `body`.
+    fn serve_once(body: &'static str) -> AocClient {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test server");
+        let address = listener.local_addr().expect("read test server address");
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept test request");
+            let _ = stream.read(&mut [0; 4096]);
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .expect("write test response");
+        });
+        AocClient::new(
+            Some("test-session"),
+            AocClientOptions {
+                base_url: format!("http://{address}"),
+                ..AocClientOptions::default()
+            },
+        )
+        .expect("build test client")
+    }
+
+    #[test]
+    fn only_clearly_slower_runs_are_flagged() {
+        assert!(!is_slower(0, 9));
+        assert!(is_slower(0, 10));
+        assert!(!is_slower(100, 150));
+        assert!(is_slower(100, 151));
+        assert!(!is_slower(100, 20));
+    }
+
+    #[test]
+    fn verification_table_counts_regressions_and_skips() {
+        colored::control::set_override(false);
+        let puzzle = PuzzleId::new(PuzzleDay::new(3).unwrap(), PuzzleYear::new(2024).unwrap());
+        let row = |part, runtime_ms, status| VerifyRow {
+            puzzle,
+            part,
+            runtime_ms,
+            status,
+        };
+        let rendered = render_verification(&[
+            row(Some(PuzzlePart::One), Some(4), VerifyStatus::Pass),
+            row(
+                Some(PuzzlePart::Two),
+                Some(40),
+                VerifyStatus::Fail {
+                    answer: "7".to_owned(),
+                    accepted: "8".to_owned(),
+                },
+            ),
+            row(None, None, VerifyStatus::NoInput),
+        ]);
+
+        assert!(rendered.contains("2024 day 3       2      FAIL          40 ms  got 7, accepted 8"));
+        assert!(rendered.contains("input not cached"));
+        assert!(rendered.ends_with("1 passed, 1 failed, 1 skipped\n"));
+    }
+
+    #[test]
+    fn verification_leaves_the_active_solution_of_the_current_puzzle() {
+        let temp = tempfile::tempdir().expect("create test directory");
+        let layout = RuntimeLayout::new(temp.path().join("runtime")).unwrap();
+        layout.bootstrap().unwrap();
+        let workspace = Workspace::new(layout.workspace_dir());
+        let year = PuzzleYear::new(2024).unwrap();
+        let verified = PuzzleId::new(PuzzleDay::new(1).unwrap(), year);
+        let current = PuzzleId::new(PuzzleDay::new(7).unwrap(), year);
+        let client = serve_once("1\n");
+        let content = ContentStore::open(layout.cache_dir(), &client).unwrap();
+        content.ensure_input(verified).unwrap();
+        content
+            .record_submission(
+                verified,
+                PuzzlePart::One,
+                "42",
+                &AocSubmissionResult::Correct,
+            )
+            .unwrap();
+        let language = Language::new(
+            LanguageId::Rust,
+            &workspace,
+            &LanguageSettings::default(),
+            &SolverExecutor,
+        )
+        .unwrap();
+        let active = language.select_solution(current.into()).unwrap();
+        let link = workspace
+            .language_project_dir(LanguageId::Rust)
+            .join("src/solution.rs");
+
+        let rows = verify_solutions(
+            &content,
+            &language,
+            &[verified],
+            &RunOptions::default(),
+            RunHistoryLimit::new(10).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].status, VerifyStatus::Pass);
+        assert_eq!(language.active_solution(current), current.into());
+        assert_eq!(std::fs::read_link(link).unwrap(), active);
+    }
+}
@@ -2,13 +2,24 @@ use std::{thread, time::Duration};
 
 use aocsuite_utils::{PuzzleId, PuzzlePart, PuzzleYear};
 use reqwest::{
+    StatusCode,
     blocking::{Client, Response},
-    header::{COOKIE, HeaderMap, HeaderValue, RETRY_AFTER},
+    header::{
+        COOKIE, ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+        RETRY_AFTER,
+    },
     redirect::Policy,
 };
 use thiserror::Error;
 
-const BASE_URL: &str = "https://adventofcode.com";
+mod throttle;
+
+pub use throttle::{
+    DEFAULT_MAX_THROTTLE_WAIT, DEFAULT_THROTTLE_INTERVAL, EndpointClass, RequestThrottle,
+    ThrottleWaitHandler,
+};
+
+const BASE_URL: &str = aocsuite_utils::AOC_BASE_URL;
 const USER_AGENT: &str = concat!("aocsuite/", env!("CARGO_PKG_VERSION"));
 const GET_RETRY_ATTEMPTS: u32 = 3;
 const INITIAL_GET_RETRY_BACKOFF: Duration = Duration::from_secs(1);
@@ -21,6 +32,8 @@ pub enum AocPage {
     Submit(PuzzleId),
     Calendar(PuzzleYear),
     Leaderboard(PuzzleYear, Option<u32>),
+    /// The JSON export of a private leaderboard.
+    PrivateLeaderboardJson(PuzzleYear, u32),
 }
 
 impl AocPage {
@@ -35,13 +48,29 @@ impl AocPage {
                 Some(id) => format!("{base_url}/{year}/leaderboard/private/view/{id}"),
                 None => format!("{base_url}/{year}/leaderboard"),
             },
+            Self::PrivateLeaderboardJson(year, id) => {
+                format!("{base_url}/{year}/leaderboard/private/view/{id}.json")
+            }
+        }
+    }
+
+    pub fn endpoint_class(&self) -> EndpointClass {
+        match self {
+            Self::Puzzle(_) => EndpointClass::Puzzle,
+            Self::Input(_) => EndpointClass::Input,
+            Self::Submit(_) => EndpointClass::Submit,
+            Self::Calendar(_) => EndpointClass::Calendar,
+            Self::Leaderboard(..) | Self::PrivateLeaderboardJson(..) => EndpointClass::Leaderboard,
         }
     }
 
     fn requires_session(&self) -> bool {
         matches!(
             self,
-            Self::Input(_) | Self::Submit(_) | Self::Leaderboard(_, Some(_))
+            Self::Input(_)
+                | Self::Submit(_)
+                | Self::Leaderboard(_, Some(_))
+                | Self::PrivateLeaderboardJson(..)
         )
     }
 }
@@ -52,11 +81,48 @@ impl std::fmt::Display for AocPage {
     }
 }
 
+/// The `ETag` and `Last-Modified` headers a page was served with, sent back to revalidate it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+                .map(str::to_owned)
+        };
+        Self {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        }
+    }
+}
+
+/// A downloaded page body with the validators needed to revalidate it later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download {
+    pub body: String,
+    pub validators: CacheValidators,
+}
+
 #[derive(Debug, Clone)]
 pub struct AocClientOptions {
     pub base_url: String,
     pub timeout: Duration,
     pub user_agent: String,
+    /// Shared request throttle; requests are not throttled without one.
+    pub throttle: Option<RequestThrottle>,
+    /// Refuses every request with [`AocClientError::Offline`].
+    pub offline: bool,
 }
 
 impl Default for AocClientOptions {
@@ -65,6 +131,8 @@ impl Default for AocClientOptions {
             base_url: BASE_URL.to_owned(),
             timeout: Duration::from_secs(30),
             user_agent: USER_AGENT.to_owned(),
+            throttle: None,
+            offline: false,
         }
     }
 }
@@ -74,6 +142,8 @@ pub struct AocClient {
     client: Client,
     base_url: String,
     has_session: bool,
+    throttle: Option<RequestThrottle>,
+    offline: bool,
     _sleep: fn(Duration),
 }
 
@@ -102,27 +172,75 @@ impl AocClient {
             client,
             base_url: options.base_url.trim_end_matches('/').to_owned(),
             has_session: session.is_some(),
+            throttle: options.throttle,
+            offline: options.offline,
             _sleep: thread::sleep,
         })
     }
 
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
     pub fn download(&self, page: &AocPage) -> AocClientResult<String> {
+        Ok(self.fetch(page)?.body)
+    }
+
+    /// Downloads a page together with its cache validators.
+    pub fn fetch(&self, page: &AocPage) -> AocClientResult<Download> {
+        self.get(page, &CacheValidators::default())
+            .map(|download| download.expect("unconditional GET always returns a body"))
+    }
+
+    /// Downloads a page only if it changed since it was served with `validators`.
+    ///
+    /// Returns `None` when AoC answers `304 Not Modified`.
+    pub fn revalidate(
+        &self,
+        page: &AocPage,
+        validators: &CacheValidators,
+    ) -> AocClientResult<Option<Download>> {
+        self.get(page, validators)
+    }
+
+    fn get(
+        &self,
+        page: &AocPage,
+        validators: &CacheValidators,
+    ) -> AocClientResult<Option<Download>> {
+        self.ensure_online()?;
         self.ensure_session(page)?;
         let url = page.url(&self.base_url);
         let mut sleep_timer;
 
         for retry in 0..=GET_RETRY_ATTEMPTS {
-            match self.client.get(&url).send() {
-                Ok(response) => match parse_response(response) {
-                    Ok(body) => return Ok(body),
-                    Err(AocClientError::RateLimited(delay)) if retry < GET_RETRY_ATTEMPTS => {
-                        sleep_timer = delay.unwrap_or_else(|| default_retry_delay(retry));
-                    }
-                    Err(AocClientError::Http(_)) if retry < GET_RETRY_ATTEMPTS => {
-                        sleep_timer = default_retry_delay(retry);
+            self.throttle(page)?;
+            let mut request = self.client.get(&url);
+            if let Some(etag) = &validators.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &validators.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+            match request.send() {
+                Ok(response)
+                    if response.status() == StatusCode::NOT_MODIFIED && !validators.is_empty() =>
+                {
+                    return Ok(None);
+                }
+                Ok(response) => {
+                    let validators = CacheValidators::from_headers(response.headers());
+                    match parse_response(response) {
+                        Ok(body) => return Ok(Some(Download { body, validators })),
+                        Err(AocClientError::RateLimited(delay)) if retry < GET_RETRY_ATTEMPTS => {
+                            sleep_timer = delay.unwrap_or_else(|| default_retry_delay(retry));
+                        }
+                        Err(AocClientError::Http(_)) if retry < GET_RETRY_ATTEMPTS => {
+                            sleep_timer = default_retry_delay(retry);
+                        }
+                        Err(error) => return Err(error),
                     }
-                    Err(error) => return Err(error),
-                },
+                }
                 Err(_) if retry < GET_RETRY_ATTEMPTS => {
                     sleep_timer = default_retry_delay(retry);
                 }
@@ -144,7 +262,9 @@ impl AocClient {
         answer: &str,
     ) -> AocClientResult<String> {
         let page = AocPage::Submit(puzzle);
+        self.ensure_online()?;
         self.ensure_session(&page)?;
+        self.throttle(&page)?;
         let params = [("level", part.to_string()), ("answer", answer.to_owned())];
         let response = self
             .client
@@ -154,6 +274,28 @@ impl AocClient {
         parse_response(response)
     }
 
+    /// Waits for the next throttle slot of the page's endpoint class.
+    fn throttle(&self, page: &AocPage) -> AocClientResult<()> {
+        let Some(throttle) = &self.throttle else {
+            return Ok(());
+        };
+        let class = page.endpoint_class();
+        let wait = throttle.reserve(class)?;
+        if !wait.is_zero() {
+            (throttle.on_wait)(class, wait);
+            self.sleep(wait);
+        }
+        Ok(())
+    }
+
+    fn ensure_online(&self) -> AocClientResult<()> {
+        if self.offline {
+            Err(AocClientError::Offline)
+        } else {
+            Ok(())
+        }
+    }
+
     fn ensure_session(&self, page: &AocPage) -> AocClientResult<()> {
         if page.requires_session() && !self.has_session {
             Err(AocClientError::MissingSession)
@@ -220,6 +362,9 @@ pub enum AocClientError {
     #[error("this AoC request requires a session token")]
     MissingSession,
 
+    #[error("offline mode is on, so AoC is not contacted")]
+    Offline,
+
     #[error("AoC authentication failed")]
     Authentication,
 
@@ -228,6 +373,18 @@ pub enum AocClientError {
 
     #[error("AoC returned HTTP status {0}")]
     HttpStatus(u16),
+
+    #[error(
+        "too many AoC {class} requests; try again in {}s",
+        retry_in.as_secs() + u64::from(retry_in.subsec_nanos() > 0)
+    )]
+    Throttled {
+        class: EndpointClass,
+        retry_in: Duration,
+    },
+
+    #[error("request throttle state error: {0}")]
+    ThrottleState(std::io::Error),
 }
 
 #[cfg(test)]
@@ -235,13 +392,20 @@ mod tests {
     use std::{
         io::{Read, Write},
         net::TcpListener,
-        sync::mpsc::{self, Receiver},
+        sync::{
+            Arc, Mutex,
+            mpsc::{self, Receiver},
+        },
         thread,
+        time::{Duration, SystemTime, UNIX_EPOCH},
     };
 
     use aocsuite_utils::{PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear};
 
-    use super::{AocClient, AocClientError, AocClientOptions, AocPage};
+    use super::{
+        AocClient, AocClientError, AocClientOptions, AocPage, CacheValidators,
+        DEFAULT_THROTTLE_INTERVAL, EndpointClass, RequestThrottle,
+    };
 
     fn puzzle() -> PuzzleId {
         PuzzleId::new(
@@ -251,6 +415,14 @@ mod tests {
     }
 
     fn serve_once(status: u16, body: &'static str) -> (String, Receiver<String>) {
+        serve_once_with_headers(status, "", body)
+    }
+
+    fn serve_once_with_headers(
+        status: u16,
+        headers: &'static str,
+        body: &'static str,
+    ) -> (String, Receiver<String>) {
         let listener = TcpListener::bind("127.0.0.1:0").expect("bind test server");
         let address = listener.local_addr().expect("read test server address");
         let (sender, receiver) = mpsc::channel();
@@ -261,7 +433,7 @@ mod tests {
             let _ = sender.send(String::from_utf8_lossy(&request[..bytes]).into_owned());
             write!(
                 stream,
-                "HTTP/1.1 {status} Test\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                "HTTP/1.1 {status} Test\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
                 body.len()
             )
             .expect("write test response");
@@ -339,6 +511,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn private_leaderboard_json_is_fetched_with_the_session() {
+        let (base_url, request) = serve_once(200, r#"{"members":{}}"#);
+        let year = PuzzleYear::new(2024).expect("valid test year");
+
+        assert!(matches!(
+            client(base_url.clone(), None).download(&AocPage::PrivateLeaderboardJson(year, 42)),
+            Err(AocClientError::MissingSession)
+        ));
+        assert_eq!(
+            client(base_url, Some("test-session"))
+                .download(&AocPage::PrivateLeaderboardJson(year, 42))
+                .unwrap(),
+            r#"{"members":{}}"#
+        );
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2024/leaderboard/private/view/42.json HTTP/1.1"));
+        assert!(
+            request
+                .to_ascii_lowercase()
+                .contains("cookie: session=test-session")
+        );
+    }
+
     #[test]
     fn submissions_send_the_part_answer_and_session() {
         let (base_url, request) = serve_once(200, "correct");
@@ -402,4 +598,129 @@ mod tests {
         );
         assert_eq!(requests.recv().unwrap().len(), 2);
     }
+
+    #[test]
+    fn downloads_capture_validators_that_revalidate_the_page() {
+        let (base_url, _) = serve_once_with_headers(
+            200,
+            "ETag: \"v1\"\r\nLast-Modified: Sun, 01 Dec 2024 05:00:00 GMT\r\n",
+            "calendar",
+        );
+        let year = PuzzleYear::new(2024).unwrap();
+        let download = client(base_url, None)
+            .fetch(&AocPage::Calendar(year))
+            .unwrap();
+        assert_eq!(download.body, "calendar");
+        assert_eq!(
+            download.validators,
+            CacheValidators {
+                etag: Some("\"v1\"".to_owned()),
+                last_modified: Some("Sun, 01 Dec 2024 05:00:00 GMT".to_owned()),
+            }
+        );
+
+        let (base_url, request) = serve_once(304, "");
+        assert_eq!(
+            client(base_url, None)
+                .revalidate(&AocPage::Calendar(year), &download.validators)
+                .unwrap(),
+            None
+        );
+        let request = request.recv().unwrap().to_ascii_lowercase();
+        assert!(request.contains("if-none-match: \"v1\"\r\n"));
+        assert!(request.contains("if-modified-since: sun, 01 dec 2024 05:00:00 gmt\r\n"));
+    }
+
+    #[test]
+    fn throttled_requests_are_refused_before_reaching_aoc() {
+        let temp = tempfile::tempdir().expect("create throttle directory");
+        let (base_url, _) = serve_once(200, "puzzle");
+        let mut client = AocClient::new(
+            None,
+            AocClientOptions {
+                base_url,
+                throttle: Some(RequestThrottle {
+                    max_wait: Duration::ZERO,
+                    ..RequestThrottle::new(temp.path())
+                }),
+                ..AocClientOptions::default()
+            },
+        )
+        .expect("build test client");
+        client._sleep = |_| {};
+        let page = AocPage::Puzzle(puzzle());
+
+        assert_eq!(client.download(&page).unwrap(), "puzzle");
+        let error = client.download(&page).unwrap_err();
+        assert!(matches!(
+            error,
+            AocClientError::Throttled {
+                class: EndpointClass::Puzzle,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "too many AoC puzzle requests; try again in 5s"
+        );
+    }
+
+    #[test]
+    fn waiting_requests_are_reported_before_they_sleep() {
+        let temp = tempfile::tempdir().expect("create throttle directory");
+        let previous_slot = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        std::fs::write(
+            temp.path().join("input.next"),
+            previous_slot.as_millis().to_string(),
+        )
+        .unwrap();
+        let waits = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&waits);
+        let (base_url, _) = serve_once(200, "1 2 3");
+        let mut client = AocClient::new(
+            Some("test-session"),
+            AocClientOptions {
+                base_url,
+                throttle: Some(RequestThrottle {
+                    on_wait: Arc::new(move |class, wait| {
+                        recorded.lock().unwrap().push((class, wait));
+                    }),
+                    ..RequestThrottle::new(temp.path())
+                }),
+                ..AocClientOptions::default()
+            },
+        )
+        .expect("build test client");
+        client._sleep = |_| {};
+
+        assert_eq!(client.download(&AocPage::Input(puzzle())).unwrap(), "1 2 3");
+        let waits = waits.lock().unwrap();
+        assert_eq!(waits.len(), 1);
+        assert_eq!(waits[0].0, EndpointClass::Input);
+        assert!(waits[0].1 > Duration::ZERO && waits[0].1 <= DEFAULT_THROTTLE_INTERVAL);
+    }
+
+    #[test]
+    fn offline_clients_refuse_every_request() {
+        let client = AocClient::new(
+            Some("test-session"),
+            AocClientOptions {
+                base_url: "http://127.0.0.1:9".to_owned(),
+                offline: true,
+                ..AocClientOptions::default()
+            },
+        )
+        .expect("build test client");
+        let puzzle = puzzle();
+
+        assert!(client.is_offline());
+        assert!(matches!(
+            client.download(&AocPage::Input(puzzle)),
+            Err(AocClientError::Offline)
+        ));
+        assert!(matches!(
+            client.submit(puzzle, PuzzlePart::One, "1"),
+            Err(AocClientError::Offline)
+        ));
+    }
 }
@@ -0,0 +1,161 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{AocClientError, AocClientResult};
+
+/// Minimum time between two requests to the same endpoint class.
+pub const DEFAULT_THROTTLE_INTERVAL: Duration = Duration::from_secs(5);
+/// Longest a request waits for its slot before it is refused.
+pub const DEFAULT_MAX_THROTTLE_WAIT: Duration = Duration::from_secs(30);
+
+/// Kinds of AoC endpoints that are throttled independently of each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointClass {
+    Puzzle,
+    Input,
+    Calendar,
+    Submit,
+    Leaderboard,
+}
+
+impl fmt::Display for EndpointClass {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Puzzle => "puzzle",
+            Self::Input => "input",
+            Self::Calendar => "calendar",
+            Self::Submit => "submit",
+            Self::Leaderboard => "leaderboard",
+        })
+    }
+}
+
+/// Called with the endpoint class and the wait before a request waits for its slot.
+pub type ThrottleWaitHandler = Arc<dyn Fn(EndpointClass, Duration) + Send + Sync>;
+
+/// Spaces requests to each endpoint class across every process sharing `dir`.
+///
+/// Each class stores the time of its latest reserved request in `{dir}/{class}.next` under an
+/// exclusive file lock, so concurrent CLI and TUI processes queue behind each other.
+#[derive(Clone)]
+pub struct RequestThrottle {
+    pub dir: PathBuf,
+    pub interval: Duration,
+    pub max_wait: Duration,
+    pub on_wait: ThrottleWaitHandler,
+}
+
+impl fmt::Debug for RequestThrottle {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("RequestThrottle")
+            .field("dir", &self.dir)
+            .field("interval", &self.interval)
+            .field("max_wait", &self.max_wait)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RequestThrottle {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            interval: DEFAULT_THROTTLE_INTERVAL,
+            max_wait: DEFAULT_MAX_THROTTLE_WAIT,
+            on_wait: Arc::new(|_, _| {}),
+        }
+    }
+
+    /// Reserves the next request slot of `class` and returns how long to wait for it.
+    ///
+    /// Slots further away than `max_wait` are not reserved and fail with
+    /// [`AocClientError::Throttled`].
+    pub(crate) fn reserve(&self, class: EndpointClass) -> AocClientResult<Duration> {
+        fs::create_dir_all(&self.dir).map_err(AocClientError::ThrottleState)?;
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.dir.join(format!("{class}.next")))
+            .map_err(AocClientError::ThrottleState)?;
+        file.lock().map_err(AocClientError::ThrottleState)?;
+
+        let mut previous = String::new();
+        file.read_to_string(&mut previous)
+            .map_err(AocClientError::ThrottleState)?;
+        let now = unix_millis();
+        let interval = u64::try_from(self.interval.as_millis()).unwrap_or(u64::MAX);
+        let slot = previous
+            .trim()
+            .parse::<u64>()
+            .map_or(now, |previous| now.max(previous.saturating_add(interval)));
+        let wait = Duration::from_millis(slot - now);
+        if wait > self.max_wait {
+            return Err(AocClientError::Throttled {
+                class,
+                retry_in: wait.saturating_sub(self.max_wait),
+            });
+        }
+
+        file.set_len(0)
+            .and_then(|()| file.seek(SeekFrom::Start(0)))
+            .and_then(|_| write!(file, "{slot}"))
+            .map_err(AocClientError::ThrottleState)?;
+        Ok(wait)
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tempfile::tempdir;
+
+    use super::{EndpointClass, RequestThrottle};
+    use crate::AocClientError;
+
+    #[test]
+    fn slots_are_shared_through_the_directory_and_kept_per_class() {
+        let temp = tempdir().expect("create throttle directory");
+        let throttle = |max_wait| RequestThrottle {
+            interval: Duration::from_secs(60),
+            max_wait,
+            ..RequestThrottle::new(temp.path())
+        };
+
+        let first = throttle(Duration::from_secs(90));
+        assert_eq!(
+            first.reserve(EndpointClass::Puzzle).unwrap(),
+            Duration::ZERO
+        );
+        let queued = throttle(Duration::from_secs(90))
+            .reserve(EndpointClass::Puzzle)
+            .unwrap();
+        assert!(queued > Duration::from_secs(55) && queued <= Duration::from_secs(60));
+        assert_eq!(first.reserve(EndpointClass::Input).unwrap(), Duration::ZERO);
+
+        match first.reserve(EndpointClass::Puzzle) {
+            Err(AocClientError::Throttled { class, retry_in }) => {
+                assert_eq!(class, EndpointClass::Puzzle);
+                assert!(retry_in > Duration::from_secs(25) && retry_in <= Duration::from_secs(30));
+            }
+            other => panic!("expected a throttled request, got {other:?}"),
+        }
+    }
+}
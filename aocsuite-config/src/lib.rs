@@ -1,6 +1,10 @@
-use std::{collections::HashMap, env, fs, path::PathBuf};
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
 
-use aocsuite_utils::{atomic_write, set_owner_only_permissions};
+use aocsuite_utils::{atomic_write, set_owner_only_permissions, CustomLanguage, LanguageId};
 use thiserror::Error;
 
 mod setting;
@@ -11,6 +15,7 @@ pub use setting::{ConfigKey, ConfigValue};
 pub struct Configuration {
     config_path: PathBuf,
     session_path: PathBuf,
+    manifest_dir: PathBuf,
     values: HashMap<ConfigKey, ConfigValue>,
 }
 
@@ -19,6 +24,7 @@ impl Configuration {
         let config_dir = config_dir.into();
         let session_path = config_dir.join("session");
         let config_path = config_dir.join("config.json");
+        let manifest_dir = config_dir.join("languages");
 
         let file_values = match fs::read(&config_path) {
             Ok(contents) => serde_json::from_slice::<HashMap<ConfigKey, String>>(&contents)?,
@@ -33,6 +39,7 @@ impl Configuration {
         Ok(Self {
             config_path,
             session_path,
+            manifest_dir,
             values,
         })
     }
@@ -61,7 +68,11 @@ impl Configuration {
         let mut values = self.values.clone();
         match value.map(str::trim).filter(|value| !value.is_empty()) {
             Some(value) => {
-                values.insert(key, key.parse_value(value.to_owned())?);
+                let value = key.parse_value(value.to_owned())?;
+                if let ConfigValue::Language(language) = &value {
+                    self.check_language(*language)?;
+                }
+                values.insert(key, value);
             }
             None => {
                 values.remove(&key);
@@ -80,6 +91,32 @@ impl Configuration {
         Ok(())
     }
 
+    /// Directory holding the manifests of custom languages, one `{name}.toml` per language.
+    pub fn manifest_dir(&self) -> &Path {
+        &self.manifest_dir
+    }
+
+    pub fn language_manifest(&self, language: CustomLanguage) -> PathBuf {
+        self.manifest_dir.join(language.manifest_file_name())
+    }
+
+    /// Rejects a custom language without a manifest, so a misspelled built-in language fails
+    /// where it is entered instead of when the language is first used.
+    pub fn check_language(&self, language: LanguageId) -> AocConfigResult<()> {
+        let LanguageId::Custom(custom) = language else {
+            return Ok(());
+        };
+        let manifest = self.language_manifest(custom);
+        if manifest.is_file() {
+            Ok(())
+        } else {
+            Err(AocConfigError::UnknownLanguage {
+                language: custom.to_string(),
+                manifest,
+            })
+        }
+    }
+
     pub fn session(&self) -> AocConfigResult<String> {
         Ok(fs::read_to_string(&self.session_path)?)
     }
@@ -127,6 +164,12 @@ pub enum AocConfigError {
     SessionInConfig,
     #[error("reading the session configuration value is not allowed")]
     SessionReadNotAllowed,
+    #[error(
+        "unknown language '{language}': expected one of {} or a custom language with a manifest at '{}'",
+        LanguageId::BUILT_IN.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+        manifest.display()
+    )]
+    UnknownLanguage { language: String, manifest: PathBuf },
 }
 
 pub type AocConfigResult<T> = Result<T, AocConfigError>;
@@ -225,4 +268,23 @@ mod tests {
             Err(AocConfigError::SessionReadNotAllowed)
         ));
     }
+
+    #[test]
+    fn custom_languages_must_have_a_manifest_before_they_are_configured() {
+        let temp = TempDir::new().unwrap();
+        let mut config = configuration(&temp);
+
+        let error = config.set(ConfigKey::Language, Some("pyhton")).unwrap_err();
+        assert!(matches!(
+            &error,
+            AocConfigError::UnknownLanguage { language, .. } if language == "pyhton"
+        ));
+        assert!(error.to_string().contains("rust, python"));
+        assert!(!temp.path().join("config/config.json").exists());
+
+        fs::create_dir_all(config.manifest_dir()).unwrap();
+        fs::write(config.manifest_dir().join("zig.toml"), "").unwrap();
+        config.set(ConfigKey::Language, Some("zig")).unwrap();
+        config.set(ConfigKey::Language, Some("go")).unwrap();
+    }
 }
@@ -1,4 +1,7 @@
-use aocsuite_utils::{LanguageId, PuzzleYear, RunHistoryLimit};
+use aocsuite_utils::{
+    CppFlags, LanguageId, LeaderboardIds, PuzzleYear, PythonBackend, RunHistoryLimit, RunTimeout,
+    RustProfile, RustReleaseOptions, TypeScriptRuntime, DEFAULT_CPP_COMPILER,
+};
 
 use super::{AocConfigError, AocConfigResult};
 
@@ -9,6 +12,18 @@ pub enum ConfigKey {
     Year,
     Editor,
     RunHistoryLimit,
+    RunTimeout,
+    CppCompiler,
+    CppFlags,
+    TypeScriptRuntime,
+    PythonBackend,
+    PythonVersion,
+    RustToolchain,
+    RustEdition,
+    RustProfile,
+    RustReleaseOptions,
+    Leaderboards,
+    Offline,
     Session,
 }
 
@@ -19,6 +34,18 @@ impl std::fmt::Display for ConfigKey {
             Self::Year => "year",
             Self::Editor => "editor",
             Self::RunHistoryLimit => "run_history_limit",
+            Self::RunTimeout => "run_timeout",
+            Self::CppCompiler => "cpp_compiler",
+            Self::CppFlags => "cpp_flags",
+            Self::TypeScriptRuntime => "typescript_runtime",
+            Self::PythonBackend => "python_backend",
+            Self::PythonVersion => "python_version",
+            Self::RustToolchain => "rust_toolchain",
+            Self::RustEdition => "rust_edition",
+            Self::RustProfile => "rust_profile",
+            Self::RustReleaseOptions => "rust_release_options",
+            Self::Leaderboards => "leaderboards",
+            Self::Offline => "offline",
             Self::Session => "session",
         })
     }
@@ -30,6 +57,18 @@ pub enum ConfigValue {
     Year(PuzzleYear),
     Editor(String),
     RunHistoryLimit(RunHistoryLimit),
+    RunTimeout(RunTimeout),
+    CppCompiler(String),
+    CppFlags(CppFlags),
+    TypeScriptRuntime(TypeScriptRuntime),
+    PythonBackend(PythonBackend),
+    PythonVersion(String),
+    RustToolchain(String),
+    RustEdition(String),
+    RustProfile(RustProfile),
+    RustReleaseOptions(RustReleaseOptions),
+    Leaderboards(LeaderboardIds),
+    Offline(bool),
 }
 
 impl std::fmt::Display for ConfigValue {
@@ -39,6 +78,18 @@ impl std::fmt::Display for ConfigValue {
             Self::Year(value) => value.fmt(formatter),
             Self::Editor(value) => value.fmt(formatter),
             Self::RunHistoryLimit(value) => value.fmt(formatter),
+            Self::RunTimeout(value) => value.fmt(formatter),
+            Self::CppCompiler(value) => value.fmt(formatter),
+            Self::CppFlags(value) => value.fmt(formatter),
+            Self::TypeScriptRuntime(value) => value.fmt(formatter),
+            Self::PythonBackend(value) => value.fmt(formatter),
+            Self::PythonVersion(value) => value.fmt(formatter),
+            Self::RustToolchain(value) => value.fmt(formatter),
+            Self::RustEdition(value) => value.fmt(formatter),
+            Self::RustProfile(value) => value.fmt(formatter),
+            Self::RustReleaseOptions(value) => value.fmt(formatter),
+            Self::Leaderboards(value) => value.fmt(formatter),
+            Self::Offline(value) => value.fmt(formatter),
         }
     }
 }
@@ -67,6 +118,69 @@ impl ConfigKey {
                 .parse()
                 .map(ConfigValue::RunHistoryLimit)
                 .map_err(|_| invalid()),
+            Self::RunTimeout => value
+                .parse()
+                .map(ConfigValue::RunTimeout)
+                .map_err(|_| invalid()),
+            Self::CppCompiler => {
+                if value.is_empty() {
+                    Err(invalid())
+                } else {
+                    Ok(ConfigValue::CppCompiler(value))
+                }
+            }
+            Self::CppFlags => value
+                .parse()
+                .map(ConfigValue::CppFlags)
+                .map_err(|_| invalid()),
+            Self::TypeScriptRuntime => value
+                .parse()
+                .map(ConfigValue::TypeScriptRuntime)
+                .map_err(|_| invalid()),
+            Self::PythonBackend => value
+                .parse()
+                .map(ConfigValue::PythonBackend)
+                .map_err(|_| invalid()),
+            // A single argument such as `3.12`, passed to `uv venv --python` or appended to `python`.
+            Self::PythonVersion => {
+                if value.chars().any(char::is_whitespace) {
+                    Err(invalid())
+                } else {
+                    Ok(ConfigValue::PythonVersion(value))
+                }
+            }
+            // A channel such as `stable`, `nightly-2024-12-01` or `1.83.0`.
+            Self::RustToolchain => {
+                if value.chars().any(char::is_whitespace) {
+                    Err(invalid())
+                } else {
+                    Ok(ConfigValue::RustToolchain(value))
+                }
+            }
+            Self::RustEdition => {
+                // The generated harness needs at least the 2018 module system.
+                if ["2018", "2021", "2024"].contains(&value.as_str()) {
+                    Ok(ConfigValue::RustEdition(value))
+                } else {
+                    Err(invalid())
+                }
+            }
+            Self::RustProfile => value
+                .parse()
+                .map(ConfigValue::RustProfile)
+                .map_err(|_| invalid()),
+            Self::RustReleaseOptions => value
+                .parse()
+                .map(ConfigValue::RustReleaseOptions)
+                .map_err(|_| invalid()),
+            Self::Leaderboards => value
+                .parse()
+                .map(ConfigValue::Leaderboards)
+                .map_err(|_| invalid()),
+            Self::Offline => value
+                .parse()
+                .map(ConfigValue::Offline)
+                .map_err(|_| invalid()),
             Self::Session => Err(AocConfigError::SessionInConfig),
         }
     }
@@ -77,6 +191,21 @@ impl ConfigKey {
             Self::RunHistoryLimit => Ok(ConfigValue::RunHistoryLimit(
                 RunHistoryLimit::new(10).expect("10 is a valid run history limit"),
             )),
+            Self::RunTimeout => Ok(ConfigValue::RunTimeout(
+                RunTimeout::from_secs(30).expect("30 seconds is a valid run timeout"),
+            )),
+            Self::CppCompiler => Ok(ConfigValue::CppCompiler(DEFAULT_CPP_COMPILER.to_owned())),
+            Self::CppFlags => Ok(ConfigValue::CppFlags(CppFlags::default())),
+            Self::TypeScriptRuntime => {
+                Ok(ConfigValue::TypeScriptRuntime(TypeScriptRuntime::default()))
+            }
+            Self::PythonBackend => Ok(ConfigValue::PythonBackend(PythonBackend::default())),
+            Self::RustProfile => Ok(ConfigValue::RustProfile(RustProfile::default())),
+            Self::RustReleaseOptions => Ok(ConfigValue::RustReleaseOptions(
+                RustReleaseOptions::default(),
+            )),
+            Self::Leaderboards => Ok(ConfigValue::Leaderboards(LeaderboardIds::default())),
+            Self::Offline => Ok(ConfigValue::Offline(false)),
             Self::Editor => std::env::var("EDITOR")
                 .map(ConfigValue::Editor)
                 .map_err(AocConfigError::from),
@@ -104,8 +233,16 @@ macro_rules! impl_config_value_conversion {
 
 impl_config_value_conversion! {
     LanguageId => Language,
+    CppFlags => CppFlags,
     PuzzleYear => Year,
     RunHistoryLimit => RunHistoryLimit,
+    RunTimeout => RunTimeout,
+    TypeScriptRuntime => TypeScriptRuntime,
+    PythonBackend => PythonBackend,
+    RustProfile => RustProfile,
+    RustReleaseOptions => RustReleaseOptions,
+    LeaderboardIds => Leaderboards,
+    bool => Offline,
 }
 
 impl TryFrom<ConfigValue> for String {
@@ -0,0 +1,115 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Output,
+};
+
+use crate::{
+    traits::{DepManager, Solver},
+    AocLanguageError, AocLanguageResult,
+};
+
+use super::CppRunner;
+use aocsuite_utils::{execute_command, CommandRequest};
+
+/// Header-only libraries are vendored into `vendor/<name>`, either cloned from a git URL or
+/// copied from a local directory, and added to the include path.
+impl DepManager for CppRunner<'_> {
+    fn setup_env(&self) -> AocLanguageResult<Option<Output>> {
+        self.migrate_runtime()?;
+        Ok(None)
+    }
+    fn add_package(&self, package: &str) -> AocLanguageResult<()> {
+        let name = package_name(package).ok_or_else(|| {
+            AocLanguageError::DepAdd(
+                package.to_string(),
+                "expected a git URL or a directory of headers".to_string(),
+            )
+        })?;
+        let destination = self.vendor_dir().join(&name);
+        if destination.exists() {
+            return Err(AocLanguageError::DepAdd(
+                package.to_string(),
+                format!("'{name}' is already vendored"),
+            ));
+        }
+        std::fs::create_dir_all(self.vendor_dir())?;
+
+        if is_git_url(package) {
+            execute_command(
+                self.executor,
+                CommandRequest::new("git")
+                    .arg("clone")
+                    .arg("--depth=1")
+                    .arg(package)
+                    .arg(&destination)
+                    .current_dir(&self.root_dir),
+            )?;
+            // The clone is vendored source, not a nested repository of the workspace.
+            remove_dir_if_present(&destination.join(".git"))?;
+        } else {
+            let source = Path::new(package);
+            if !source.is_dir() {
+                return Err(AocLanguageError::DepAdd(
+                    package.to_string(),
+                    "not a directory".to_string(),
+                ));
+            }
+            copy_dir(source, &destination)?;
+        }
+        Ok(())
+    }
+    fn clean_env(&self) -> AocLanguageResult<()> {
+        remove_dir_if_present(&self.build_dir())
+    }
+
+    fn list_packages(&self) -> AocLanguageResult<Vec<String>> {
+        self.vendored_packages()
+    }
+    fn remove_packages(&self, package: &str) -> AocLanguageResult<()> {
+        if !self.vendored_packages()?.iter().any(|name| name == package) {
+            return Err(AocLanguageError::DepRemove(
+                package.to_string(),
+                "not a vendored package".to_string(),
+            ));
+        }
+        remove_dir_if_present(&self.vendor_dir().join(package))
+    }
+}
+
+fn is_git_url(package: &str) -> bool {
+    package.contains("://") || package.starts_with("git@")
+}
+
+/// Vendored directory name: the last path or URL segment without a `.git` suffix.
+fn package_name(package: &str) -> Option<String> {
+    let segment = package
+        .trim_end_matches(['/', '\\'])
+        .rsplit(['/', '\\', ':'])
+        .next()?;
+    let name = segment.strip_suffix(".git").unwrap_or(segment);
+    (!name.is_empty() && name != "." && name != "..").then(|| name.to_string())
+}
+
+fn copy_dir(source: &Path, destination: &Path) -> AocLanguageResult<()> {
+    std::fs::create_dir_all(destination)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let target: PathBuf = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != ".git" {
+                copy_dir(&entry.path(), &target)?;
+            }
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+fn remove_dir_if_present(path: &Path) -> AocLanguageResult<()> {
+    match std::fs::remove_dir_all(path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error.into()),
+    }
+}
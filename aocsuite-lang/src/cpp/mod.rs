@@ -0,0 +1,308 @@
+mod dependencies;
+mod solver;
+mod user_library;
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+};
+
+use aocsuite_utils::{CommandExecutor, CppFlags};
+
+use crate::{traits::LibManager, AocLanguageResult};
+
+/// Compiled binaries kept in `build/`, so switching between recent puzzles does not recompile.
+const CACHED_BINARIES: usize = 16;
+
+pub struct CppRunner<'executor> {
+    root_dir: PathBuf,
+    compiler: String,
+    flags: CppFlags,
+    executor: &'executor dyn CommandExecutor,
+    /// Binary produced or found by the latest `compile`, so `run` does not hash the sources again.
+    compiled_binary: RefCell<Option<PathBuf>>,
+}
+
+impl<'executor> CppRunner<'executor> {
+    pub fn new(
+        root_dir: PathBuf,
+        compiler: String,
+        flags: CppFlags,
+        executor: &'executor dyn CommandExecutor,
+    ) -> Self {
+        Self {
+            root_dir,
+            compiler,
+            flags,
+            executor,
+            compiled_binary: RefCell::new(None),
+        }
+    }
+    fn build_dir(&self) -> PathBuf {
+        self.root_dir.join("build")
+    }
+    fn vendor_dir(&self) -> PathBuf {
+        self.root_dir.join("vendor")
+    }
+
+    /// Include directories of the vendored header-only libraries: their `include` directory when
+    /// they have one, otherwise their root.
+    fn vendor_include_dirs(&self) -> AocLanguageResult<Vec<PathBuf>> {
+        let mut dirs = Vec::new();
+        for package in self.vendored_packages()? {
+            let package_dir = self.vendor_dir().join(package);
+            let include_dir = package_dir.join("include");
+            dirs.push(if include_dir.is_dir() {
+                include_dir
+            } else {
+                package_dir
+            });
+        }
+        Ok(dirs)
+    }
+
+    fn vendored_packages(&self) -> AocLanguageResult<Vec<String>> {
+        let entries = match std::fs::read_dir(self.vendor_dir()) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+        let mut packages = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                packages.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        packages.sort();
+        Ok(packages)
+    }
+
+    /// Files of the vendored libraries relative to `vendor/`, sorted, leaving out `.git`.
+    fn vendored_files(&self) -> AocLanguageResult<Vec<PathBuf>> {
+        fn collect(dir: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+            for entry in std::fs::read_dir(dir)? {
+                let entry = entry?;
+                let name = entry.file_name();
+                if name == ".git" {
+                    continue;
+                }
+                let file_type = entry.file_type()?;
+                if file_type.is_dir() {
+                    collect(&entry.path(), &relative.join(&name), files)?;
+                } else if file_type.is_file() {
+                    files.push(relative.join(&name));
+                }
+            }
+            Ok(())
+        }
+
+        let mut files = Vec::new();
+        match collect(&self.vendor_dir(), Path::new(""), &mut files) {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Path of the binary built from the current sources, vendored libraries, compiler and flags.
+    fn binary_path(&self) -> AocLanguageResult<PathBuf> {
+        let mut hash = SourceHash::new();
+        hash.write(self.compiler.as_bytes());
+        for flag in self.flags.as_slice() {
+            hash.write(flag.as_bytes());
+        }
+        for file in self.vendored_files()? {
+            hash.write(file.to_string_lossy().as_bytes());
+            hash.write_file(&self.vendor_dir().join(file))?;
+        }
+        hash.write_file(&self.root_dir.join("main.cpp"))?;
+        hash.write_file(&self.root_dir.join("solution.cpp"))?;
+        let mut headers = crate::scan_lib_directory(&self.lib_dir(), &self.file_extention())?;
+        headers.sort();
+        for header in headers {
+            hash.write(header.as_bytes());
+            hash.write_file(&self.get_lib_path(&header))?;
+        }
+        Ok(self.build_dir().join(format!(
+            "aocsuite-solution-{:016x}{}",
+            hash.finish(),
+            std::env::consts::EXE_SUFFIX
+        )))
+    }
+
+    /// Removes all but the most recently built binaries.
+    fn prune_binaries(&self) -> AocLanguageResult<()> {
+        let mut binaries = Vec::new();
+        for entry in std::fs::read_dir(self.build_dir())? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                binaries.push((entry.metadata()?.modified()?, entry.path()));
+            }
+        }
+        binaries.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        for (_, path) in binaries.into_iter().skip(CACHED_BINARIES) {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a, stable across Rust releases unlike `DefaultHasher`, so cached binaries survive
+/// upgrades of aocsuite.
+struct SourceHash(u64);
+
+impl SourceHash {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // Length-prefix each field so that ("ab", "c") and ("a", "bc") hash differently.
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_file(&mut self, path: &Path) -> AocLanguageResult<()> {
+        match std::fs::read(path) {
+            Ok(contents) => self.write(&contents),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => self.write(&[]),
+            Err(error) => return Err(error.into()),
+        }
+        Ok(())
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aocsuite_storage::Workspace;
+    use aocsuite_utils::{LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzleYear};
+
+    use crate::{
+        test_support::{successful_output, test_root, ScriptedExecutor},
+        AocLanguageError, Language, LanguageSettings, RunOptions,
+    };
+
+    #[test]
+    fn cpp_binaries_are_cached_by_source_hash() {
+        let root = test_root("cpp-cache");
+        let workspace = Workspace::new(root.clone());
+        let project = workspace.language_project_dir(LanguageId::Cpp);
+        let input = root.join("input.txt");
+        fs::create_dir_all(project.join("vendor/ranges/include")).expect("vendor a library");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::new(|request| {
+            if request.program == "clang++" {
+                fs::write(request.args.last().unwrap(), "binary")?;
+            } else {
+                fs::write(
+                    &request.args[1],
+                    r#"{"part1":{"answer":"1","runtime_ms":0},"part2":null}"#,
+                )?;
+            }
+            Ok(successful_output())
+        });
+        let compilations = || {
+            executor
+                .requests()
+                .iter()
+                .filter(|request| request.program == "clang++")
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let settings = LanguageSettings {
+            cpp_compiler: "clang++".to_owned(),
+            cpp_flags: "-O3 '-DTITLE=\"a b\"'".parse().unwrap(),
+            ..LanguageSettings::default()
+        };
+        let language = Language::new(LanguageId::Cpp, &workspace, &settings, &executor)
+            .expect("load language");
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+        let run = || {
+            language
+                .execute(puzzle, PartSelection::One, &input, &RunOptions::default())
+                .expect("run solution")
+        };
+
+        run();
+        run();
+        assert_eq!(compilations().len(), 1);
+        let solution = language
+            .ensure_solver_file(&crate::SolverFile::PuzzleSolution(puzzle.into()))
+            .expect("solution path");
+        fs::write(&solution, "// edited\n").expect("edit solution");
+        run();
+        fs::write(project.join("vendor/ranges/include/ranges.hpp"), "// v2\n")
+            .expect("update vendored library");
+        run();
+
+        let compilations = compilations();
+        assert_eq!(compilations.len(), 3);
+        assert_eq!(compilations[0].args[..2], ["-O3", "-DTITLE=\"a b\""]);
+        assert!(compilations[0]
+            .args
+            .contains(&project.join("vendor/ranges/include").into_os_string()));
+        assert_ne!(compilations[0].args.last(), compilations[1].args.last());
+        assert_eq!(language.list_packages().unwrap(), ["ranges"]);
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    #[test]
+    fn timed_out_builds_leave_no_partial_binary() {
+        let root = test_root("cpp-partial");
+        let workspace = Workspace::new(root.clone());
+        let project = workspace.language_project_dir(LanguageId::Cpp);
+        let input = root.join("input.txt");
+        fs::create_dir_all(&root).expect("create test workspace");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::new(|request| {
+            if request.program == "clang++" {
+                fs::write(request.args.last().unwrap(), "half a binary")?;
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "command timed out",
+                ));
+            }
+            Ok(successful_output())
+        });
+        let settings = LanguageSettings {
+            cpp_compiler: "clang++".to_owned(),
+            ..LanguageSettings::default()
+        };
+        let language = Language::new(LanguageId::Cpp, &workspace, &settings, &executor)
+            .expect("load language");
+        let timeout = std::time::Duration::from_secs(30);
+
+        let error = language
+            .execute(
+                PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap()),
+                PartSelection::One,
+                &input,
+                &RunOptions {
+                    timeout: Some(timeout),
+                    ..RunOptions::default()
+                },
+            )
+            .expect_err("build times out");
+
+        assert!(matches!(error, AocLanguageError::TimedOut(limit) if limit == timeout));
+        assert_eq!(
+            fs::read_dir(project.join("build"))
+                .expect("read build directory")
+                .count(),
+            0
+        );
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+}
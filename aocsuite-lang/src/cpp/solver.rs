@@ -0,0 +1,349 @@
+use std::{path::PathBuf, process::Output};
+
+use crate::{
+    template::named_template_path,
+    traits::{LibManager, Solver},
+    utils::{AocLanguageResult, RunOptions, SolverFile},
+};
+use aocsuite_utils::{execute_command, CommandRequest};
+
+use super::CppRunner;
+
+/// Removes what a failed, timed-out or cancelled build left at its output path.
+struct PartialBinary(PathBuf);
+
+impl Drop for PartialBinary {
+    fn drop(&mut self) {
+        // Once the build succeeded the binary has been renamed and nothing is left to remove.
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+impl Solver for CppRunner<'_> {
+    fn compile(&self, options: &RunOptions) -> AocLanguageResult<Option<Output>> {
+        let binary_path = self.binary_path()?;
+        *self.compiled_binary.borrow_mut() = Some(binary_path.clone());
+        if binary_path.is_file() {
+            return Ok(None);
+        }
+        std::fs::create_dir_all(self.build_dir())?;
+        // Compile to a temporary path so an interrupted build never looks cached.
+        let partial = PartialBinary(binary_path.with_extension("partial"));
+        let output = execute_command(
+            self.executor,
+            options.supervise_build(
+                CommandRequest::new(&self.compiler)
+                    .args(self.flags.as_slice())
+                    .arg("-I")
+                    .arg(self.lib_dir())
+                    .args(
+                        self.vendor_include_dirs()?
+                            .into_iter()
+                            .flat_map(|dir| ["-I".into(), dir.into_os_string()]),
+                    )
+                    .arg(self.solver_file_path(&SolverFile::Entrypoint))
+                    .arg("-o")
+                    .arg(&partial.0)
+                    .current_dir(&self.root_dir),
+            ),
+        )?;
+        std::fs::rename(&partial.0, &binary_path)?;
+        self.prune_binaries()?;
+        Ok(Some(output))
+    }
+
+    fn run(
+        &self,
+        part: aocsuite_utils::PartSelection,
+        input: &std::path::Path,
+        output: &std::path::Path,
+        options: &RunOptions,
+    ) -> AocLanguageResult<Output> {
+        let binary_path = match self.compiled_binary.borrow().clone() {
+            Some(binary_path) => binary_path,
+            None => self.binary_path()?,
+        };
+        Ok(execute_command(
+            self.executor,
+            options.supervise(
+                CommandRequest::new(binary_path)
+                    .arg(input)
+                    .arg(output)
+                    .arg(part.to_string())
+                    .args(options.bench.map(|iterations| iterations.to_string()))
+                    .current_dir(&self.root_dir),
+            ),
+        )?)
+    }
+
+    fn migrate_runtime(&self) -> AocLanguageResult<()> {
+        crate::runtime::migrate_runtime(
+            &self.root_dir,
+            vec![(
+                self.solver_file_path(&SolverFile::Entrypoint),
+                self.main_contents(),
+            )],
+        )
+    }
+    fn clean_runtime(&self) -> AocLanguageResult<()> {
+        crate::runtime::clean_runtime(
+            &self.root_dir,
+            &[self.solver_file_path(&SolverFile::Entrypoint)],
+            &self.root_dir.join("solution.cpp"),
+        )
+    }
+
+    fn solver_file_path(&self, file: &SolverFile) -> std::path::PathBuf {
+        match file {
+            SolverFile::Entrypoint => self.root_dir.join("main.cpp"),
+            SolverFile::SolutionTemplate(template) if template.is_default() => {
+                self.root_dir.join("template.cpp")
+            }
+            SolverFile::SolutionTemplate(template) => {
+                named_template_path(&self.root_dir, template, "cpp")
+            }
+            SolverFile::ActiveSolution(_) => self.root_dir.join("solution.cpp"),
+            SolverFile::PuzzleSolution(solution) => {
+                self.solutions_dir().join(format!("{solution}.cpp"))
+            }
+        }
+    }
+    fn solutions_dir(&self) -> std::path::PathBuf {
+        self.root_dir.join("solutions")
+    }
+    fn template_contents(&self) -> String {
+        r#"// Implement your solution here. Headers in lib/ are included as "name.hpp".
+#include <string>
+
+// Solve part 1 of the puzzle
+std::string part1(const std::string& input) {
+    return "Part 1 not implemented yet. Input length: " + std::to_string(input.size());
+}
+
+// Solve part 2 of the puzzle
+std::string part2(const std::string& input) {
+    return "Part 2 not implemented yet. Input length: " + std::to_string(input.size());
+}
+"#
+        .to_string()
+    }
+    fn main_contents(&self) -> String {
+        r#"#include <algorithm>
+#include <chrono>
+#include <cmath>
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <filesystem>
+#include <fstream>
+#include <iostream>
+#include <optional>
+#include <sstream>
+#include <string>
+#include <vector>
+
+#include "solution.cpp"
+
+namespace aocsuite {
+
+struct BenchStats {
+    std::uint32_t iterations;
+    std::uint64_t min_ns;
+    std::uint64_t mean_ns;
+    std::uint64_t median_ns;
+    std::uint64_t stddev_ns;
+};
+
+struct PartResult {
+    std::string answer;
+    std::uint64_t runtime_ms;
+    std::optional<BenchStats> bench;
+};
+
+using PartFn = std::string (*)(const std::string&);
+using Clock = std::chrono::steady_clock;
+
+// Keeps benchmarked answers alive so the compiler cannot drop the calls.
+std::string sink;
+
+PartResult run_part(PartFn part_fn, const std::string& input) {
+    auto start = Clock::now();
+    std::string answer = part_fn(input);
+    auto elapsed = std::chrono::duration_cast<std::chrono::milliseconds>(Clock::now() - start);
+
+    return PartResult{answer, static_cast<std::uint64_t>(elapsed.count()), std::nullopt};
+}
+
+// Untimed runs before benchmarking: a tenth of the iterations, between one and ten.
+std::uint32_t warmup_runs(std::uint32_t iterations) {
+    return std::clamp<std::uint32_t>(iterations / 10, 1, 10);
+}
+
+PartResult bench_part(PartFn part_fn, const std::string& input, std::uint32_t iterations) {
+    // The first warmup run provides the reported answer.
+    std::string answer = part_fn(input);
+    for (std::uint32_t i = 1; i < warmup_runs(iterations); ++i) {
+        sink = part_fn(input);
+    }
+
+    std::vector<std::uint64_t> samples;
+    samples.reserve(iterations);
+    for (std::uint32_t i = 0; i < iterations; ++i) {
+        auto start = Clock::now();
+        sink = part_fn(input);
+        auto elapsed = std::chrono::duration_cast<std::chrono::nanoseconds>(Clock::now() - start);
+        samples.push_back(static_cast<std::uint64_t>(elapsed.count()));
+    }
+    std::sort(samples.begin(), samples.end());
+
+    double count = static_cast<double>(samples.size());
+    double mean = 0;
+    for (auto sample : samples) {
+        mean += static_cast<double>(sample);
+    }
+    mean /= count;
+    double variance = 0;
+    for (auto sample : samples) {
+        variance += std::pow(static_cast<double>(sample) - mean, 2);
+    }
+    variance /= count;
+    std::size_t middle = samples.size() / 2;
+    std::uint64_t median_ns = samples.size() % 2 == 0
+        ? samples[middle - 1] + (samples[middle] - samples[middle - 1]) / 2
+        : samples[middle];
+
+    return PartResult{
+        answer,
+        median_ns / 1000000,
+        BenchStats{
+            iterations,
+            samples.front(),
+            static_cast<std::uint64_t>(std::llround(mean)),
+            median_ns,
+            static_cast<std::uint64_t>(std::llround(std::sqrt(variance))),
+        },
+    };
+}
+
+std::string json_string(const std::string& value) {
+    std::string escaped = "\"";
+    for (unsigned char character : value) {
+        switch (character) {
+            case '"': escaped += "\\\""; break;
+            case '\\': escaped += "\\\\"; break;
+            case '\n': escaped += "\\n"; break;
+            case '\r': escaped += "\\r"; break;
+            case '\t': escaped += "\\t"; break;
+            default:
+                if (character < 0x20) {
+                    char buffer[7];
+                    std::snprintf(buffer, sizeof buffer, "\\u%04x", character);
+                    escaped += buffer;
+                } else {
+                    escaped += static_cast<char>(character);
+                }
+        }
+    }
+    return escaped + "\"";
+}
+
+std::string json_part(const std::optional<PartResult>& part) {
+    if (!part) {
+        return "null";
+    }
+    std::ostringstream json;
+    json << "{\"answer\": " << json_string(part->answer) << ", \"runtime_ms\": " << part->runtime_ms;
+    if (part->bench) {
+        const BenchStats& bench = *part->bench;
+        json << ", \"bench\": {\"iterations\": " << bench.iterations
+             << ", \"min_ns\": " << bench.min_ns
+             << ", \"mean_ns\": " << bench.mean_ns
+             << ", \"median_ns\": " << bench.median_ns
+             << ", \"stddev_ns\": " << bench.stddev_ns << "}";
+    }
+    json << "}";
+    return json.str();
+}
+
+[[noreturn]] void fail(const std::string& message) {
+    std::cerr << message << std::endl;
+    std::exit(1);
+}
+
+}  // namespace aocsuite
+
+int main(int argc, char** argv) {
+    using namespace aocsuite;
+
+    if (argc < 3 || argc > 5) {
+        fail(std::string("Usage: ") + argv[0] + " <input_file> <output_file> [1|2|both] [bench_iterations]");
+    }
+
+    std::string input_file = argv[1];
+    std::string output_file = argv[2];
+    std::string part = argc > 3 ? argv[3] : "both";
+    if (part != "1" && part != "2" && part != "both") {
+        fail("Invalid part '" + part + "'. Use '1', '2', or 'both'");
+    }
+    std::optional<std::uint32_t> iterations;
+    if (argc > 4) {
+        std::string value = argv[4];
+        char* end = nullptr;
+        unsigned long parsed = std::strtoul(value.c_str(), &end, 10);
+        if (value.empty() || *end != '\0' || value[0] == '-' || parsed == 0 || parsed > UINT32_MAX) {
+            fail("Invalid bench iterations '" + value + "'. Use a positive number");
+        }
+        iterations = static_cast<std::uint32_t>(parsed);
+    }
+
+    std::ifstream input_stream(input_file, std::ios::binary);
+    if (!input_stream) {
+        fail("Failed to read file '" + input_file + "'");
+    }
+    std::ostringstream input_buffer;
+    input_buffer << input_stream.rdbuf();
+    std::string input = input_buffer.str();
+
+    std::filesystem::path output_path(output_file);
+    std::error_code error;
+    if (output_path.has_parent_path()) {
+        std::filesystem::create_directories(output_path.parent_path(), error);
+        if (error) {
+            fail("Failed to create output directory: " + error.message());
+        }
+    }
+
+    auto measure = [&](PartFn part_fn) {
+        return iterations ? bench_part(part_fn, input, *iterations) : run_part(part_fn, input);
+    };
+    std::optional<PartResult> part1_result;
+    std::optional<PartResult> part2_result;
+    if (part == "1" || part == "both") {
+        part1_result = measure(part1);
+    }
+    if (part == "2" || part == "both") {
+        part2_result = measure(part2);
+    }
+
+    // Publish a complete result so readers never observe a partial JSON document.
+    std::filesystem::path temporary_output_path = output_path;
+    temporary_output_path.replace_extension(".tmp");
+    {
+        std::ofstream output(temporary_output_path, std::ios::binary | std::ios::trunc);
+        output << "{\n  \"part1\": " << json_part(part1_result) << ",\n  \"part2\": "
+               << json_part(part2_result) << "\n}\n";
+        if (!output) {
+            fail("Failed to write output file '" + output_file + "'");
+        }
+    }
+    std::filesystem::rename(temporary_output_path, output_path, error);
+    if (error) {
+        std::filesystem::remove(temporary_output_path, error);
+        fail("Failed to publish output file '" + output_file + "'");
+    }
+    return 0;
+}
+"#
+        .to_string()
+    }
+}
@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use crate::traits::LibManager;
+
+use super::CppRunner;
+
+impl LibManager for CppRunner<'_> {
+    fn get_lib_path(&self, lib_name: &str) -> PathBuf {
+        self.lib_dir()
+            .join(format!("{}.{}", lib_name, self.file_extention()))
+    }
+    // On the include path, so solutions use `#include "name.hpp"`.
+    fn lib_dir(&self) -> PathBuf {
+        self.root_dir.join("lib")
+    }
+    fn reserved_names(&self) -> Vec<&str> {
+        RESERVED_NAMES.to_vec()
+    }
+    fn file_extention(&self) -> String {
+        "hpp".to_string()
+    }
+}
+
+// Standard headers a library would shadow, besides the runtime's own files.
+const RESERVED_NAMES: &[&str] = &[
+    "algorithm",
+    "array",
+    "bitset",
+    "deque",
+    "functional",
+    "iostream",
+    "map",
+    "memory",
+    "numeric",
+    "queue",
+    "set",
+    "stack",
+    "string",
+    "tuple",
+    "unordered_map",
+    "unordered_set",
+    "utility",
+    "vector",
+    "main",
+    "solution",
+    "template",
+];
@@ -0,0 +1,70 @@
+use std::process::Output;
+
+use crate::{
+    traits::{DepManager, Solver},
+    AocLanguageError, AocLanguageResult,
+};
+
+use super::CustomRunner;
+use aocsuite_utils::execute_command;
+
+impl DepManager for CustomRunner<'_> {
+    fn setup_env(&self) -> AocLanguageResult<Option<Output>> {
+        self.migrate_runtime()?;
+        Ok(None)
+    }
+
+    fn add_package(&self, package: &str) -> AocLanguageResult<()> {
+        let Some(add) = &self.manifest.packages.add else {
+            return Err(AocLanguageError::DepAdd(
+                package.to_string(),
+                format!("{} does not declare packages.add", self.name),
+            ));
+        };
+        execute_command(self.executor, self.package_command(add, Some(package)))?;
+        Ok(())
+    }
+
+    fn list_packages(&self) -> AocLanguageResult<Vec<String>> {
+        let Some(list) = &self.manifest.packages.list else {
+            return Ok(Vec::new());
+        };
+        let output = execute_command(self.executor, self.package_command(list, None))?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn remove_packages(&self, package: &str) -> AocLanguageResult<()> {
+        let Some(remove) = &self.manifest.packages.remove else {
+            return Err(AocLanguageError::DepRemove(
+                package.to_string(),
+                format!("{} does not declare packages.remove", self.name),
+            ));
+        };
+        execute_command(self.executor, self.package_command(remove, Some(package)))?;
+        Ok(())
+    }
+
+    fn clean_env(&self) -> AocLanguageResult<()> {
+        Ok(())
+    }
+}
+
+impl CustomRunner<'_> {
+    /// Fills `{package}` in, or drops an argument that is exactly `{package}` without one.
+    fn package_command(
+        &self,
+        command: &[String],
+        package: Option<&str>,
+    ) -> aocsuite_utils::CommandRequest {
+        self.command(command, |arg| match package {
+            Some(package) => Some(arg.replace("{package}", package)),
+            None => (arg != "{package}").then(|| arg.to_owned()),
+        })
+    }
+}
@@ -0,0 +1,297 @@
+mod dependencies;
+mod solver;
+mod user_library;
+use std::path::{Component, Path, PathBuf};
+
+use aocsuite_utils::{CommandExecutor, CommandRequest, CustomLanguage};
+use serde::Deserialize;
+
+use crate::{AocLanguageError, AocLanguageResult};
+
+/// Describes how to build and run solutions of a user-defined language.
+///
+/// Commands are argument lists run from the language directory. Run commands may use the
+/// `{input}`, `{output}`, `{part}` and `{bench}` placeholders; an argument that is exactly
+/// `{bench}` is dropped when the run is not a benchmark. Package commands use `{package}`, and
+/// an argument that is exactly `{package}` is dropped from the list command.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LanguageManifest {
+    extension: String,
+    entrypoint: PathBuf,
+    active_solution: PathBuf,
+    #[serde(default = "default_lib_dir")]
+    lib_dir: PathBuf,
+    harness: String,
+    template: String,
+    #[serde(default)]
+    compile: Option<Vec<String>>,
+    run: Vec<String>,
+    #[serde(default)]
+    reserved: Vec<String>,
+    #[serde(default)]
+    packages: PackageCommands,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackageCommands {
+    add: Option<Vec<String>>,
+    remove: Option<Vec<String>>,
+    list: Option<Vec<String>>,
+}
+
+fn default_lib_dir() -> PathBuf {
+    PathBuf::from(".")
+}
+
+impl LanguageManifest {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let manifest: Self =
+            toml::from_str(contents).map_err(|error| error.message().to_owned())?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.extension.is_empty()
+            || !self
+                .extension
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric())
+        {
+            return Err(format!(
+                "extension '{}' must be ASCII alphanumeric",
+                self.extension
+            ));
+        }
+        for (field, path) in [
+            ("entrypoint", &self.entrypoint),
+            ("active_solution", &self.active_solution),
+            ("lib_dir", &self.lib_dir),
+        ] {
+            if path.as_os_str().is_empty()
+                || !path
+                    .components()
+                    .all(|part| matches!(part, Component::Normal(_) | Component::CurDir))
+            {
+                return Err(format!(
+                    "{field} '{}' must be a path inside the language directory",
+                    path.display()
+                ));
+            }
+        }
+        if self.entrypoint == self.active_solution {
+            return Err("entrypoint and active_solution must be different files".to_owned());
+        }
+        for (field, command) in [
+            ("run", Some(&self.run)),
+            ("compile", self.compile.as_ref()),
+            ("packages.add", self.packages.add.as_ref()),
+            ("packages.remove", self.packages.remove.as_ref()),
+            ("packages.list", self.packages.list.as_ref()),
+        ] {
+            if command.is_some_and(|command| command.is_empty()) {
+                return Err(format!("{field} must name a program"));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct CustomRunner<'executor> {
+    name: CustomLanguage,
+    root_dir: PathBuf,
+    manifest: LanguageManifest,
+    executor: &'executor dyn CommandExecutor,
+}
+
+impl<'executor> CustomRunner<'executor> {
+    /// Loads the runner from the language's manifest in `manifest_dir`.
+    pub fn load(
+        name: CustomLanguage,
+        root_dir: PathBuf,
+        manifest_dir: &Path,
+        executor: &'executor dyn CommandExecutor,
+    ) -> AocLanguageResult<Self> {
+        let path = manifest_dir.join(name.manifest_file_name());
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Err(AocLanguageError::LangNotFound(format!(
+                    "{name} (no manifest at '{}')",
+                    path.display()
+                )));
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let manifest = LanguageManifest::parse(&contents)
+            .map_err(|message| AocLanguageError::Manifest { path, message })?;
+        Ok(Self {
+            name,
+            root_dir,
+            manifest,
+            executor,
+        })
+    }
+
+    /// Builds a request for a manifest command, filling in placeholders with `fill`.
+    ///
+    /// An argument for which `fill` returns `None` is left out.
+    fn command(&self, command: &[String], fill: impl Fn(&str) -> Option<String>) -> CommandRequest {
+        let (program, args) = command
+            .split_first()
+            .expect("manifest commands are non-empty");
+        // Relative programs such as `./solver` are resolved against the language directory.
+        let program =
+            if Path::new(program).components().count() > 1 && Path::new(program).is_relative() {
+                self.root_dir.join(program).into_os_string()
+            } else {
+                program.into()
+            };
+        CommandRequest::new(program)
+            .args(args.iter().filter_map(|arg| fill(arg)))
+            .current_dir(&self.root_dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use aocsuite_storage::Workspace;
+    use aocsuite_utils::{LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear};
+
+    use crate::{
+        test_support::{successful_output, test_root, ScriptedExecutor, SYSTEM_EXECUTOR},
+        AocLanguageError, Language, LanguageSettings, RunOptions,
+    };
+
+    const TINYGO_MANIFEST: &str = r#"
+extension = "go"
+entrypoint = "main.go"
+active_solution = "solution/solution.go"
+lib_dir = "lib"
+harness = "package main\n"
+template = "package solution\n"
+compile = ["go", "build", "-o", "solver", "."]
+run = ["./solver", "--input={input}", "{output}", "{part}", "{bench}"]
+reserved = ["package"]
+
+[packages]
+add = ["go", "get", "{package}"]
+list = ["go", "list", "-m", "{package}"]
+"#;
+
+    #[test]
+    fn custom_languages_run_the_commands_from_their_manifest() {
+        let root = test_root("custom-manifest");
+        let workspace = Workspace::new(root.clone());
+        let tinygo: LanguageId = "tinygo".parse().expect("custom language name");
+        let project = workspace.language_project_dir(tinygo);
+        let input = root.join("input.txt");
+        let settings = LanguageSettings {
+            manifest_dir: root.join("languages"),
+            ..LanguageSettings::default()
+        };
+        fs::create_dir_all(&settings.manifest_dir).expect("create manifest directory");
+        fs::write(settings.manifest_dir.join("tinygo.toml"), TINYGO_MANIFEST)
+            .expect("write manifest");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::new(|request| {
+            if PathBuf::from(&request.program).ends_with("solver") {
+                fs::write(
+                    &request.args[1],
+                    r#"{"part1":null,"part2":{"answer":"42","runtime_ms":3}}"#,
+                )?;
+            }
+            Ok(successful_output())
+        });
+        let language =
+            Language::new(tinygo, &workspace, &settings, &executor).expect("load language");
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+
+        let output = language
+            .execute(puzzle, PartSelection::Two, &input, &RunOptions::default())
+            .expect("run custom solver");
+
+        assert_eq!(
+            output
+                .run
+                .result
+                .part(PuzzlePart::Two)
+                .map(|part| part.answer()),
+            Some("42")
+        );
+        let requests = executor.requests();
+        let [compile, run] = requests.as_slice() else {
+            panic!("expected a compile and a run request, got {requests:?}");
+        };
+        assert_eq!(compile.program, "go");
+        assert_eq!(compile.current_dir.as_deref(), Some(project.as_path()));
+        assert_eq!(PathBuf::from(&run.program), project.join("solver"));
+        assert_eq!(run.args[0], format!("--input={}", input.display()).as_str());
+        assert_eq!(run.args[2], "2");
+        assert_eq!(run.args.len(), 3);
+        assert_eq!(
+            fs::read_to_string(project.join("main.go")).expect("read harness"),
+            "package main\n"
+        );
+        assert_eq!(
+            fs::read_to_string(project.join("solution/solution.go")).expect("read solution"),
+            "package solution\n"
+        );
+        assert!(language.ensure_lib_path("package").is_err());
+        assert_eq!(
+            language.ensure_lib_path("grid").expect("library path"),
+            project.join("lib/grid.go")
+        );
+        drop(requests);
+
+        language.list_packages().expect("list packages");
+        let requests = executor.requests();
+        assert_eq!(
+            requests.last().expect("list request").args,
+            ["list", "-m"],
+            "the unfilled package argument is left out"
+        );
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    #[test]
+    fn custom_languages_require_a_valid_manifest() {
+        let root = test_root("custom-manifest-errors");
+        let workspace = Workspace::new(root.clone());
+        let tinygo: LanguageId = "tinygo".parse().expect("custom language name");
+        let settings = LanguageSettings {
+            manifest_dir: root.join("languages"),
+            ..LanguageSettings::default()
+        };
+        let manifest = settings.manifest_dir.join("tinygo.toml");
+
+        assert!(matches!(
+            Language::new(tinygo, &workspace, &settings, &SYSTEM_EXECUTOR),
+            Err(AocLanguageError::LangNotFound(_))
+        ));
+
+        fs::create_dir_all(&settings.manifest_dir).expect("create manifest directory");
+        for invalid in [
+            TINYGO_MANIFEST.replace("run = [", "run = [] #"),
+            TINYGO_MANIFEST.replace("\"solution/solution.go\"", "\"../solution.go\""),
+            TINYGO_MANIFEST.replace("extension = \"go\"", "extension = \".go\""),
+            format!("{TINYGO_MANIFEST}\nunknown = true\n"),
+        ] {
+            fs::write(&manifest, &invalid).expect("write manifest");
+            assert!(
+                matches!(
+                    Language::new(tinygo, &workspace, &settings, &SYSTEM_EXECUTOR),
+                    Err(AocLanguageError::Manifest { .. })
+                ),
+                "{invalid}"
+            );
+        }
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+}
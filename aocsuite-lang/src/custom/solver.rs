@@ -0,0 +1,98 @@
+use std::process::Output;
+
+use crate::{
+    template::named_template_path,
+    traits::Solver,
+    utils::{AocLanguageResult, RunOptions, SolverFile},
+};
+use aocsuite_utils::{atomic_write, execute_command};
+
+use super::CustomRunner;
+
+impl Solver for CustomRunner<'_> {
+    fn compile(&self, options: &RunOptions) -> AocLanguageResult<Option<Output>> {
+        let Some(compile) = &self.manifest.compile else {
+            return Ok(None);
+        };
+        Ok(Some(execute_command(
+            self.executor,
+            options.supervise_build(self.command(compile, |arg| Some(arg.to_owned()))),
+        )?))
+    }
+
+    fn run(
+        &self,
+        part: aocsuite_utils::PartSelection,
+        input: &std::path::Path,
+        output: &std::path::Path,
+        options: &RunOptions,
+    ) -> AocLanguageResult<Output> {
+        let input = input.to_string_lossy();
+        let output = output.to_string_lossy();
+        let part = part.to_string();
+        let bench = options.bench.map(|iterations| iterations.to_string());
+        let request = self.command(&self.manifest.run, |arg| {
+            if arg == "{bench}" && bench.is_none() {
+                return None;
+            }
+            Some(
+                arg.replace("{input}", &input)
+                    .replace("{output}", &output)
+                    .replace("{part}", &part)
+                    .replace("{bench}", bench.as_deref().unwrap_or_default()),
+            )
+        });
+
+        Ok(execute_command(self.executor, options.supervise(request))?)
+    }
+
+    fn clean_runtime(&self) -> AocLanguageResult<()> {
+        crate::runtime::clean_runtime(
+            &self.root_dir,
+            &[self.solver_file_path(&SolverFile::Entrypoint)],
+            &self.root_dir.join(&self.manifest.active_solution),
+        )
+    }
+
+    /// Writes the harness whenever it differs from the manifest, which owns its contents.
+    fn migrate_runtime(&self) -> AocLanguageResult<()> {
+        let entrypoint = self.solver_file_path(&SolverFile::Entrypoint);
+        let harness = self.main_contents();
+        let current = match std::fs::read_to_string(&entrypoint) {
+            Ok(contents) => Some(contents),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+            Err(error) => return Err(error.into()),
+        };
+        if current.as_deref() != Some(harness.as_str()) {
+            std::fs::create_dir_all(entrypoint.parent().expect("entrypoint is not root"))?;
+            atomic_write(&entrypoint, harness.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn solver_file_path(&self, file: &SolverFile) -> std::path::PathBuf {
+        let extension = &self.manifest.extension;
+        match file {
+            SolverFile::Entrypoint => self.root_dir.join(&self.manifest.entrypoint),
+            SolverFile::SolutionTemplate(template) if template.is_default() => {
+                self.root_dir.join(format!("template.{extension}"))
+            }
+            SolverFile::SolutionTemplate(template) => {
+                named_template_path(&self.root_dir, template, extension)
+            }
+            SolverFile::ActiveSolution(_) => self.root_dir.join(&self.manifest.active_solution),
+            SolverFile::PuzzleSolution(solution) => {
+                self.solutions_dir().join(format!("{solution}.{extension}"))
+            }
+        }
+    }
+    fn solutions_dir(&self) -> std::path::PathBuf {
+        self.root_dir.join("solutions")
+    }
+    fn template_contents(&self) -> String {
+        self.manifest.template.clone()
+    }
+    fn main_contents(&self) -> String {
+        self.manifest.harness.clone()
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use crate::traits::LibManager;
+
+use super::CustomRunner;
+
+impl LibManager for CustomRunner<'_> {
+    fn get_lib_path(&self, lib_name: &str) -> PathBuf {
+        self.lib_dir()
+            .join(format!("{}.{}", lib_name, self.file_extention()))
+    }
+    fn lib_dir(&self) -> PathBuf {
+        self.root_dir.join(&self.manifest.lib_dir)
+    }
+    fn reserved_names(&self) -> Vec<&str> {
+        let file_stems = [&self.manifest.entrypoint, &self.manifest.active_solution]
+            .into_iter()
+            .filter_map(|path| path.file_stem()?.to_str());
+        RESERVED_NAMES
+            .iter()
+            .copied()
+            .chain(file_stems)
+            .chain(self.manifest.reserved.iter().map(String::as_str))
+            .collect()
+    }
+    fn file_extention(&self) -> String {
+        self.manifest.extension.clone()
+    }
+}
+
+const RESERVED_NAMES: &[&str] = &["main", "solution", "template"];
@@ -0,0 +1,91 @@
+use std::process::Output;
+
+use crate::{
+    traits::{DepManager, Solver},
+    AocLanguageResult,
+};
+
+use super::GoRunner;
+use aocsuite_utils::{execute_command, CommandRequest};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GoMod {
+    #[serde(default)]
+    require: Vec<GoRequire>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GoRequire {
+    path: String,
+}
+
+impl DepManager for GoRunner<'_> {
+    fn setup_env(&self) -> AocLanguageResult<Option<Output>> {
+        self.migrate_runtime()?;
+        Ok(None)
+    }
+    fn add_package(&self, package: &str) -> AocLanguageResult<()> {
+        execute_command(
+            self.executor,
+            CommandRequest::new("go")
+                .arg("get")
+                .arg(package)
+                .current_dir(&self.root_dir),
+        )?;
+        Ok(())
+    }
+    fn clean_env(&self) -> AocLanguageResult<()> {
+        match std::fs::remove_file(self.binary_path()) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn list_packages(&self) -> AocLanguageResult<Vec<String>> {
+        if !self.root_dir.join("go.mod").exists() {
+            return Ok(Vec::new());
+        }
+
+        // `go mod edit -json` reads go.mod without resolving the module graph over the network.
+        // Indirect requirements are listed too: `go get` marks a package no solution imports yet
+        // as indirect, and it only becomes direct once imported.
+        let output = execute_command(
+            self.executor,
+            CommandRequest::new("go")
+                .arg("mod")
+                .arg("edit")
+                .arg("-json")
+                .current_dir(&self.root_dir),
+        )?;
+        let go_mod: GoMod = serde_json::from_slice(&output.stdout)?;
+
+        Ok(go_mod
+            .require
+            .into_iter()
+            .map(|require| require.path)
+            .collect())
+    }
+    fn remove_packages(&self, package: &str) -> AocLanguageResult<()> {
+        execute_command(
+            self.executor,
+            CommandRequest::new("go")
+                .arg("get")
+                .arg(format!("{package}@none"))
+                .current_dir(&self.root_dir),
+        )?;
+        // Drop the checksums and indirect requirements only the removed package needed. This also
+        // drops added packages that no solution imports yet.
+        execute_command(
+            self.executor,
+            CommandRequest::new("go")
+                .arg("mod")
+                .arg("tidy")
+                .current_dir(&self.root_dir),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+mod dependencies;
+mod solver;
+mod user_library;
+use std::path::PathBuf;
+
+use aocsuite_utils::CommandExecutor;
+
+pub struct GoRunner<'executor> {
+    root_dir: PathBuf,
+    executor: &'executor dyn CommandExecutor,
+}
+
+impl<'executor> GoRunner<'executor> {
+    pub fn new(root_dir: PathBuf, executor: &'executor dyn CommandExecutor) -> Self {
+        Self { root_dir, executor }
+    }
+    fn binary_path(&self) -> PathBuf {
+        self.root_dir.join("bin").join(format!(
+            "aocsuite-solution-go{}",
+            std::env::consts::EXE_SUFFIX
+        ))
+    }
+}
+
+/// Module path of the generated project; user libraries are imported as `aocsuite/lib`.
+const MODULE_PATH: &str = "aocsuite";
+
+fn go_mod_contents() -> String {
+    format!("module {MODULE_PATH}\n\ngo 1.21\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aocsuite_storage::Workspace;
+    use aocsuite_utils::{CommandExecutor, LanguageId, TemplateName};
+
+    use crate::{
+        test_support::{successful_output, test_root, ScriptedExecutor, SYSTEM_EXECUTOR},
+        Language, LanguageSettings,
+    };
+
+    #[test]
+    fn go_packages_are_the_requirements_of_go_mod() {
+        let executor = ScriptedExecutor::new(|request| {
+            assert_eq!(request.args, ["mod", "edit", "-json"]);
+            let mut output = successful_output();
+            output.stdout = br#"{
+                "Module": {"Path": "aocsuite"},
+                "Go": "1.21",
+                "Require": [
+                    {"Path": "github.com/samber/lo", "Version": "v1.47.0"},
+                    {"Path": "golang.org/x/exp", "Version": "v0.0.0", "Indirect": true}
+                ]
+            }"#
+            .to_vec();
+            Ok(output)
+        });
+        let root = test_root("go-packages");
+        let workspace = Workspace::new(root.clone());
+        let language = Language::new(
+            LanguageId::Go,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        assert!(language.list_packages().unwrap().is_empty());
+
+        language
+            .ensure_solver_file(&crate::SolverFile::SolutionTemplate(TemplateName::default()))
+            .expect("prepare runtime");
+        assert_eq!(
+            language.list_packages().unwrap(),
+            ["github.com/samber/lo", "golang.org/x/exp"]
+        );
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    #[test]
+    fn added_go_packages_are_listed_before_a_solution_imports_them() {
+        if !crate::utils::on_path("go") {
+            eprintln!("skipping: go is not installed");
+            return;
+        }
+        // Runs `go` without a module proxy or toolchain downloads, so only the local replacement
+        // can be fetched.
+        let executor = ScriptedExecutor::new(|request| {
+            SYSTEM_EXECUTOR.execute(
+                &request
+                    .clone()
+                    .env("GOPROXY", "off")
+                    .env("GOTOOLCHAIN", "local"),
+            )
+        });
+        let root = test_root("go-add-list");
+        let workspace = Workspace::new(root.clone());
+        let language = Language::new(
+            LanguageId::Go,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        language
+            .ensure_solver_file(&crate::SolverFile::SolutionTemplate(TemplateName::default()))
+            .expect("prepare runtime");
+        let project = workspace.language_project_dir(LanguageId::Go);
+        let helper = root.join("helper");
+        fs::create_dir_all(&helper).expect("create helper module");
+        fs::write(
+            helper.join("go.mod"),
+            "module example.com/helper\n\ngo 1.21\n",
+        )
+        .expect("write helper go.mod");
+        fs::write(helper.join("helper.go"), "package helper\n").expect("write helper package");
+        let go_mod = fs::read_to_string(project.join("go.mod")).expect("read go.mod");
+        fs::write(
+            project.join("go.mod"),
+            format!(
+                "{go_mod}\nreplace example.com/helper => {}\n",
+                helper.display()
+            ),
+        )
+        .expect("replace helper module");
+
+        language
+            .add_package("example.com/helper")
+            .expect("add package");
+        assert_eq!(language.list_packages().unwrap(), ["example.com/helper"]);
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+}
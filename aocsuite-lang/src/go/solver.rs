@@ -0,0 +1,272 @@
+use std::process::Output;
+
+use crate::{
+    template::named_template_path,
+    traits::Solver,
+    utils::{AocLanguageResult, RunOptions, SolverFile},
+};
+use aocsuite_utils::{atomic_write, execute_command, CommandRequest};
+
+use super::{go_mod_contents, GoRunner};
+
+impl Solver for GoRunner<'_> {
+    fn compile(&self, options: &RunOptions) -> AocLanguageResult<Option<Output>> {
+        Ok(Some(execute_command(
+            self.executor,
+            options.supervise_build(
+                CommandRequest::new("go")
+                    .arg("build")
+                    .arg("-o")
+                    .arg(self.binary_path())
+                    .arg(".")
+                    .current_dir(&self.root_dir),
+            ),
+        )?))
+    }
+
+    fn run(
+        &self,
+        part: aocsuite_utils::PartSelection,
+        input: &std::path::Path,
+        output: &std::path::Path,
+        options: &RunOptions,
+    ) -> AocLanguageResult<Output> {
+        Ok(execute_command(
+            self.executor,
+            options.supervise(
+                CommandRequest::new(self.binary_path())
+                    .arg(input)
+                    .arg(output)
+                    .arg(part.to_string())
+                    .args(options.bench.map(|iterations| iterations.to_string()))
+                    .current_dir(&self.root_dir),
+            ),
+        )?)
+    }
+
+    fn migrate_runtime(&self) -> AocLanguageResult<()> {
+        std::fs::create_dir_all(&self.root_dir)?;
+        let go_mod_path = self.root_dir.join("go.mod");
+        if !go_mod_path.exists() {
+            atomic_write(&go_mod_path, go_mod_contents().as_bytes())?;
+        }
+
+        crate::runtime::migrate_runtime(
+            &self.root_dir,
+            vec![(
+                self.solver_file_path(&SolverFile::Entrypoint),
+                self.main_contents(),
+            )],
+        )
+    }
+    fn clean_runtime(&self) -> AocLanguageResult<()> {
+        crate::runtime::clean_runtime(
+            &self.root_dir,
+            &[self.solver_file_path(&SolverFile::Entrypoint)],
+            &self.root_dir.join("solution.go"),
+        )
+    }
+
+    fn solver_file_path(&self, file: &SolverFile) -> std::path::PathBuf {
+        match file {
+            SolverFile::Entrypoint => self.root_dir.join("main.go"),
+            // Go compiles every file of the main package, so the template lives in its own
+            // directory where it cannot clash with the active solution.
+            SolverFile::SolutionTemplate(template) if template.is_default() => {
+                self.root_dir.join("template").join("template.go")
+            }
+            SolverFile::SolutionTemplate(template) => {
+                named_template_path(&self.root_dir, template, "go")
+            }
+            SolverFile::ActiveSolution(_) => self.root_dir.join("solution.go"),
+            SolverFile::PuzzleSolution(solution) => {
+                self.solutions_dir().join(format!("{solution}.go"))
+            }
+        }
+    }
+    fn solutions_dir(&self) -> std::path::PathBuf {
+        self.root_dir.join("solutions")
+    }
+    fn template_contents(&self) -> String {
+        r#"package main
+
+// Implement your solution here. Shared code in lib/ is imported as "aocsuite/lib".
+
+// part1 solves part 1 of the puzzle.
+func part1(input string) string {
+	panic("part 1 not implemented")
+}
+
+// part2 solves part 2 of the puzzle.
+func part2(input string) string {
+	panic("part 2 not implemented")
+}
+"#
+        .to_string()
+    }
+    fn main_contents(&self) -> String {
+        r#"package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"math"
+	"os"
+	"path/filepath"
+	"slices"
+	"strconv"
+	"time"
+)
+
+type benchStats struct {
+	Iterations uint32 `json:"iterations"`
+	MinNs      uint64 `json:"min_ns"`
+	MeanNs     uint64 `json:"mean_ns"`
+	MedianNs   uint64 `json:"median_ns"`
+	StddevNs   uint64 `json:"stddev_ns"`
+}
+
+type partResult struct {
+	Answer    string      `json:"answer"`
+	RuntimeMs uint64      `json:"runtime_ms"`
+	Bench     *benchStats `json:"bench,omitempty"`
+}
+
+type outputJSON struct {
+	Part1 *partResult `json:"part1"`
+	Part2 *partResult `json:"part2"`
+}
+
+// sink keeps benchmarked answers alive so the compiler cannot drop the calls.
+var sink string
+
+func runPart(partFn func(string) string, input string) *partResult {
+	start := time.Now()
+	answer := partFn(input)
+	runtimeMs := uint64(time.Since(start).Milliseconds())
+
+	return &partResult{Answer: answer, RuntimeMs: runtimeMs}
+}
+
+// warmupRuns is the number of untimed runs before benchmarking: a tenth of the iterations,
+// between one and ten.
+func warmupRuns(iterations uint32) uint32 {
+	return min(max(iterations/10, 1), 10)
+}
+
+func benchPart(partFn func(string) string, input string, iterations uint32) *partResult {
+	// The first warmup run provides the reported answer.
+	answer := partFn(input)
+	for i := uint32(1); i < warmupRuns(iterations); i++ {
+		sink = partFn(input)
+	}
+
+	samples := make([]uint64, iterations)
+	for i := range samples {
+		start := time.Now()
+		sink = partFn(input)
+		samples[i] = uint64(time.Since(start).Nanoseconds())
+	}
+	slices.Sort(samples)
+
+	count := float64(len(samples))
+	sum := 0.0
+	for _, sample := range samples {
+		sum += float64(sample)
+	}
+	mean := sum / count
+	variance := 0.0
+	for _, sample := range samples {
+		variance += math.Pow(float64(sample)-mean, 2)
+	}
+	variance /= count
+	middle := len(samples) / 2
+	medianNs := samples[middle]
+	if len(samples)%2 == 0 {
+		medianNs = samples[middle-1] + (samples[middle]-samples[middle-1])/2
+	}
+
+	return &partResult{
+		Answer:    answer,
+		RuntimeMs: medianNs / 1_000_000,
+		Bench: &benchStats{
+			Iterations: iterations,
+			MinNs:      samples[0],
+			MeanNs:     uint64(math.Round(mean)),
+			MedianNs:   medianNs,
+			StddevNs:   uint64(math.Round(math.Sqrt(variance))),
+		},
+	}
+}
+
+func fail(format string, args ...any) {
+	fmt.Fprintf(os.Stderr, format+"\n", args...)
+	os.Exit(1)
+}
+
+func main() {
+	args := os.Args
+	if len(args) < 3 || len(args) > 5 {
+		fail("Usage: %s <input_file> <output_file> [1|2|both] [bench_iterations]", args[0])
+	}
+
+	inputFile := args[1]
+	outputFile := args[2]
+	part := "both"
+	if len(args) > 3 {
+		part = args[3]
+	}
+	if part != "1" && part != "2" && part != "both" {
+		fail("Invalid part '%s'. Use '1', '2', or 'both'", part)
+	}
+	var iterations uint32
+	if len(args) > 4 {
+		parsed, err := strconv.ParseUint(args[4], 10, 32)
+		if err != nil || parsed == 0 {
+			fail("Invalid bench iterations '%s'. Use a positive number", args[4])
+		}
+		iterations = uint32(parsed)
+	}
+
+	contents, err := os.ReadFile(inputFile)
+	if err != nil {
+		fail("Failed to read file '%s': %v", inputFile, err)
+	}
+	input := string(contents)
+
+	if err := os.MkdirAll(filepath.Dir(outputFile), 0o755); err != nil {
+		fail("Failed to create output directory: %v", err)
+	}
+
+	measure := func(partFn func(string) string) *partResult {
+		if iterations > 0 {
+			return benchPart(partFn, input, iterations)
+		}
+		return runPart(partFn, input)
+	}
+	var output outputJSON
+	if part == "1" || part == "both" {
+		output.Part1 = measure(part1)
+	}
+	if part == "2" || part == "both" {
+		output.Part2 = measure(part2)
+	}
+
+	// Publish a complete result so readers never observe a partial JSON document.
+	encoded, err := json.MarshalIndent(output, "", "  ")
+	if err != nil {
+		fail("Failed to serialize JSON: %v", err)
+	}
+	temporaryOutputFile := outputFile + ".tmp"
+	if err := os.WriteFile(temporaryOutputFile, encoded, 0o644); err != nil {
+		fail("Failed to write output file '%s': %v", outputFile, err)
+	}
+	if err := os.Rename(temporaryOutputFile, outputFile); err != nil {
+		os.Remove(temporaryOutputFile)
+		fail("Failed to publish output file '%s': %v", outputFile, err)
+	}
+}
+"#
+        .to_string()
+    }
+}
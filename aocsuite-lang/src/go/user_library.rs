@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use crate::traits::LibManager;
+
+use super::GoRunner;
+
+impl LibManager for GoRunner<'_> {
+    fn get_lib_path(&self, lib_name: &str) -> PathBuf {
+        self.lib_dir()
+            .join(format!("{}.{}", lib_name, self.file_extention()))
+    }
+    // Every library file belongs to the single `lib` package.
+    fn lib_dir(&self) -> PathBuf {
+        self.root_dir.join("lib")
+    }
+    fn reserved_names(&self) -> Vec<&str> {
+        RESERVED_NAMES.to_vec()
+    }
+    fn file_extention(&self) -> String {
+        "go".to_string()
+    }
+}
+
+const RESERVED_NAMES: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+    "main",
+    "solution",
+    "template",
+];
@@ -2,15 +2,52 @@ use std::path::PathBuf;
 
 use aocsuite_utils::{CommandExecutor, LanguageId};
 
-use crate::{python::PythonRunner, rust::RustRunner, utils::LanguageRunner};
+use crate::{
+    cpp::CppRunner,
+    custom::CustomRunner,
+    go::GoRunner,
+    python::PythonRunner,
+    rust::RustRunner,
+    typescript::TypeScriptRunner,
+    utils::{AocLanguageResult, LanguageRunner},
+    LanguageSettings,
+};
 
 pub(crate) fn to_runner<'executor>(
     language: LanguageId,
     project_dir: PathBuf,
+    settings: &LanguageSettings,
     executor: &'executor dyn CommandExecutor,
-) -> LanguageRunner<'executor> {
-    match language {
-        LanguageId::Rust => Box::new(RustRunner::new(project_dir, executor)),
-        LanguageId::Python => Box::new(PythonRunner::new(project_dir, executor)),
-    }
+) -> AocLanguageResult<LanguageRunner<'executor>> {
+    Ok(match language {
+        LanguageId::Rust => Box::new(RustRunner::new(
+            project_dir,
+            settings.rust.clone(),
+            executor,
+        )),
+        LanguageId::Python => Box::new(PythonRunner::new(
+            project_dir,
+            settings.python_backend,
+            settings.python_version.clone(),
+            executor,
+        )?),
+        LanguageId::Go => Box::new(GoRunner::new(project_dir, executor)),
+        LanguageId::Cpp => Box::new(CppRunner::new(
+            project_dir,
+            settings.cpp_compiler.clone(),
+            settings.cpp_flags.clone(),
+            executor,
+        )),
+        LanguageId::TypeScript => Box::new(TypeScriptRunner::new(
+            project_dir,
+            settings.typescript_runtime,
+            executor,
+        )),
+        LanguageId::Custom(name) => Box::new(CustomRunner::load(
+            name,
+            project_dir,
+            &settings.manifest_dir,
+            executor,
+        )?),
+    })
 }
@@ -1,18 +1,31 @@
+mod cpp;
+mod custom;
+mod go;
 mod languages;
 mod python;
 mod runtime;
 mod rust;
+mod settings;
+mod template;
+#[cfg(test)]
+mod test_support;
 mod traits;
+mod typescript;
 mod utils;
 
 use std::path::{Path, PathBuf};
 
 use aocsuite_storage::Workspace;
-use aocsuite_utils::{atomic_write, CommandExecutor, LanguageId, PartSelection, PuzzleId};
+use aocsuite_utils::{
+    atomic_write, CommandExecutor, LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzleYear,
+    SolutionId, TemplateName, WatchList, WatchedDir,
+};
+pub use settings::{LanguageSettings, RustSettings};
+pub use template::TemplateVariables;
 use utils::{read_result, with_result_file, LanguageRunner};
 pub use utils::{
-    AocLanguageError, AocLanguageResult, CompileOutput, PartResult, PuzzleResult, RunOutput,
-    SolverFile,
+    AocLanguageError, AocLanguageResult, BenchStats, CompileOutput, PartResult, PuzzleResult,
+    RunOptions, RunOutput, SolverFile,
 };
 
 #[derive(Debug)]
@@ -38,46 +51,84 @@ pub struct Language<'workspace, 'executor> {
     workspace: &'workspace Workspace,
 }
 
+/// Selects `selected` again when dropped, so a run over other solutions that returns early or
+/// panics does not leave one of them behind the shared active solution link.
+pub struct SelectionGuard<'language, 'workspace, 'executor> {
+    language: &'language Language<'workspace, 'executor>,
+    selected: Option<SolutionId>,
+}
+
+impl SelectionGuard<'_, '_, '_> {
+    /// Restores the selection, reporting the error that dropping the guard would ignore.
+    pub fn restore(self) -> AocLanguageResult<()> {
+        let selection = std::mem::ManuallyDrop::new(self);
+        if let Some(selected) = selection.selected {
+            selection.language.select_solution(selected)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SelectionGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        if let Some(selected) = self.selected {
+            let _ = self.language.select_solution(selected);
+        }
+    }
+}
+
 impl<'workspace, 'executor> Language<'workspace, 'executor> {
     pub fn new(
         language: LanguageId,
         workspace: &'workspace Workspace,
+        settings: &LanguageSettings,
         executor: &'executor dyn CommandExecutor,
-    ) -> Self {
+    ) -> AocLanguageResult<Self> {
         let project_dir = workspace.language_project_dir(language);
-        Self {
+        Ok(Self {
             language_type: language,
-            runner: languages::to_runner(language, project_dir.clone(), executor),
+            runner: languages::to_runner(language, project_dir.clone(), settings, executor)?,
             project_dir,
             workspace,
-        }
+        })
     }
 
+    /// Runs the puzzle's selected solution, see [`Language::select_solution`].
     pub fn execute(
         &self,
         puzzle: PuzzleId,
         part: PartSelection,
         input: &Path,
+        options: &RunOptions,
     ) -> AocLanguageResult<LanguageRunOutput> {
         self.setup_solution(puzzle)?;
+        let compile = self.compile(options)?;
+        if options.is_cancelled() {
+            return Err(AocLanguageError::Cancelled);
+        }
         Ok(LanguageRunOutput {
-            compile: self.compile()?,
-            run: self.run_active(part, input)?,
+            compile,
+            run: self.run_active(part, input, options)?,
         })
     }
 
-    fn run_active(&self, part: PartSelection, input: &Path) -> AocLanguageResult<RunOutput> {
+    fn run_active(
+        &self,
+        part: PartSelection,
+        input: &Path,
+        options: &RunOptions,
+    ) -> AocLanguageResult<RunOutput> {
         let output_file = self.workspace.allocate_run_result_file()?;
         with_result_file(&output_file, |output_file| {
-            let output = self.runner.run(part, input, output_file)?;
+            let output = self.runner.run(part, input, output_file, options)?;
             Ok(RunOutput::from_output(read_result(output_file)?, output))
         })
     }
 
-    fn compile(&self) -> AocLanguageResult<CompileOutput> {
+    fn compile(&self, options: &RunOptions) -> AocLanguageResult<CompileOutput> {
         Ok(self
             .runner
-            .compile()?
+            .compile(options)?
             .map(CompileOutput::from_output)
             .unwrap_or_default())
     }
@@ -87,13 +138,190 @@ impl<'workspace, 'executor> Language<'workspace, 'executor> {
         self.runner.ensure_solver_file(file)
     }
 
-    pub fn reset_template(&self, _: ConfirmedTemplateReset) -> AocLanguageResult<PathBuf> {
+    /// Restores the built-in template contents of the given template.
+    pub fn reset_template(
+        &self,
+        template: &TemplateName,
+        _: ConfirmedTemplateReset,
+    ) -> AocLanguageResult<PathBuf> {
         self.runner.migrate_runtime()?;
-        let path = self.runner.solver_file_path(&SolverFile::SolutionTemplate);
+        let path = self
+            .runner
+            .solver_file_path(&SolverFile::SolutionTemplate(template.clone()));
+        std::fs::create_dir_all(path.parent().expect("template path is not root"))?;
         atomic_write(&path, self.runner.template_contents().as_bytes())?;
         Ok(path)
     }
 
+    /// Lists the templates of this language, `default` first and then the named ones by name.
+    pub fn templates(&self) -> AocLanguageResult<Vec<TemplateName>> {
+        let mut templates = vec![TemplateName::default()];
+        let dir = template::templates_dir(&self.project_dir);
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(templates),
+            Err(error) => return Err(error.into()),
+        };
+        let mut named = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(template) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<TemplateName>().ok())
+            else {
+                continue;
+            };
+            if !template.is_default()
+                && path.is_file()
+                && path
+                    == self
+                        .runner
+                        .solver_file_path(&SolverFile::SolutionTemplate(template.clone()))
+            {
+                named.push(template);
+            }
+        }
+        named.sort();
+        templates.extend(named);
+        Ok(templates)
+    }
+
+    /// Selects the solution, first creating it from `template` with its placeholders expanded
+    /// when it does not exist. Without a template a variant is copied from the main solution.
+    /// A template for a solution that already exists is refused rather than ignored.
+    /// Returns the active solution path.
+    pub fn prepare_solution(
+        &self,
+        solution: SolutionId,
+        template: Option<&TemplateName>,
+        variables: &TemplateVariables,
+    ) -> AocLanguageResult<PathBuf> {
+        self.runner.migrate_runtime()?;
+        let exists = self
+            .runner
+            .solver_file_path(&SolverFile::PuzzleSolution(solution))
+            .exists();
+        if !exists {
+            self.runner
+                .create_solution(&solution, template, variables)?;
+        } else if let Some(template) = template {
+            return Err(AocLanguageError::SolutionExists {
+                solution,
+                template: template.clone(),
+            });
+        }
+        self.runner
+            .ensure_solver_file(&SolverFile::ActiveSolution(solution))
+    }
+
+    /// Lists the puzzles with a stored solution file, oldest first, optionally for one year.
+    pub fn puzzle_solutions(&self, year: Option<PuzzleYear>) -> AocLanguageResult<Vec<PuzzleId>> {
+        let mut puzzles: Vec<PuzzleId> = self
+            .stored_solutions()?
+            .into_iter()
+            .map(|solution| solution.puzzle)
+            .filter(|puzzle| year.is_none_or(|year| puzzle.year == year))
+            .collect();
+        puzzles.sort_by_key(|puzzle| (puzzle.year, puzzle.day));
+        puzzles.dedup();
+        Ok(puzzles)
+    }
+
+    /// Lists the stored solutions of a puzzle, its main solution first and then its variants
+    /// by name.
+    pub fn solution_variants(&self, puzzle: PuzzleId) -> AocLanguageResult<Vec<SolutionId>> {
+        let mut solutions: Vec<SolutionId> = self
+            .stored_solutions()?
+            .into_iter()
+            .filter(|solution| solution.puzzle == puzzle)
+            .collect();
+        solutions.sort_by_key(|solution| solution.variant);
+        Ok(solutions)
+    }
+
+    /// The solution the active link points at when it belongs to this puzzle, otherwise the
+    /// puzzle's main solution.
+    pub fn active_solution(&self, puzzle: PuzzleId) -> SolutionId {
+        std::fs::read_link(self.active_solution_path())
+            .ok()
+            .and_then(|target| self.solution_at(&target))
+            .filter(|solution| solution.puzzle == puzzle)
+            .unwrap_or_else(|| puzzle.into())
+    }
+
+    /// Guards the solution the shared active solution link points at, whatever its puzzle, while
+    /// other puzzles are run. Nothing is restored when the link does not exist yet.
+    pub fn keep_selection(&self) -> SelectionGuard<'_, 'workspace, 'executor> {
+        SelectionGuard {
+            language: self,
+            selected: std::fs::read_link(self.active_solution_path())
+                .ok()
+                .and_then(|target| self.solution_at(&target)),
+        }
+    }
+
+    /// Points the active solution at the given solution, creating a variant from the main
+    /// solution if it does not exist yet. Returns the solution's file.
+    pub fn select_solution(&self, solution: SolutionId) -> AocLanguageResult<PathBuf> {
+        self.ensure_solver_file(&SolverFile::ActiveSolution(solution))?;
+        Ok(self
+            .runner
+            .solver_file_path(&SolverFile::PuzzleSolution(solution)))
+    }
+
+    /// Runs every stored solution of the puzzle on the same input. The selected solution is
+    /// restored afterwards; a failing solution does not stop the others.
+    pub fn compare(
+        &self,
+        puzzle: PuzzleId,
+        part: PartSelection,
+        input: &Path,
+        options: &RunOptions,
+    ) -> AocLanguageResult<Vec<(SolutionId, AocLanguageResult<LanguageRunOutput>)>> {
+        let selection = SelectionGuard {
+            language: self,
+            selected: Some(self.active_solution(puzzle)),
+        };
+        let mut outputs = Vec::new();
+        for solution in self.solution_variants(puzzle)? {
+            if options.is_cancelled() {
+                break;
+            }
+            let output = self
+                .select_solution(solution)
+                .and_then(|_| self.execute(puzzle, part, input, options));
+            outputs.push((solution, output));
+        }
+        selection.restore()?;
+        Ok(outputs)
+    }
+
+    /// Lists what a run of the puzzle depends on: its selected solution, the active solution
+    /// link, which changes when another variant is selected, and the user library directory,
+    /// so libraries added later are picked up too.
+    pub fn watch_list(&self, puzzle: PuzzleId) -> AocLanguageResult<WatchList> {
+        let solution = self.active_solution(puzzle);
+        let lib_dir = self.runner.lib_dir();
+        std::fs::create_dir_all(&lib_dir)?;
+        Ok(WatchList {
+            files: vec![
+                self.ensure_solver_file(&SolverFile::PuzzleSolution(solution))?,
+                self.active_solution_path(),
+            ],
+            dirs: vec![WatchedDir {
+                path: lib_dir,
+                extension: self.runner.file_extention(),
+                excluded: self
+                    .runner
+                    .reserved_names()
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+            }],
+        })
+    }
+
     pub fn project_dir(&self) -> &Path {
         &self.project_dir
     }
@@ -128,7 +356,7 @@ impl<'workspace, 'executor> Language<'workspace, 'executor> {
     }
 
     fn lib_path(&self, lib_name: &str) -> AocLanguageResult<PathBuf> {
-        validate_user_lib(lib_name, &self.language_type)?;
+        validate_user_lib(lib_name, &self.runner.reserved_names())?;
         let lib_path = self.runner.get_lib_path(lib_name);
         ensure_no_case_collision(
             &self.runner.lib_dir(),
@@ -167,9 +395,10 @@ impl<'workspace, 'executor> Language<'workspace, 'executor> {
         let file_extention = self.runner.file_extention();
         let dir = self.runner.lib_dir();
         let files = scan_lib_directory(&dir, &file_extention)?;
+        let reserved_names = self.runner.reserved_names();
         Ok(files
             .into_iter()
-            .filter(|file| validate_user_lib(file, &self.language_type).is_ok())
+            .filter(|file| validate_user_lib(file, &reserved_names).is_ok())
             .collect())
     }
 
@@ -181,16 +410,56 @@ impl<'workspace, 'executor> Language<'workspace, 'executor> {
         self.runner.clean_env()
     }
 
+    /// The active solution link, which every puzzle of the language shares.
+    fn active_solution_path(&self) -> PathBuf {
+        let any_puzzle = PuzzleId::new(
+            PuzzleDay::new(u32::from(PuzzleDay::MIN)).expect("first day is valid"),
+            PuzzleYear::new(PuzzleYear::MIN).expect("first year is valid"),
+        );
+        self.runner
+            .solver_file_path(&SolverFile::ActiveSolution(any_puzzle.into()))
+    }
+
     fn setup_solution(&self, puzzle: PuzzleId) -> AocLanguageResult<()> {
         self.runner.migrate_runtime()?;
         self.runner
-            .ensure_solver_file(&SolverFile::ActiveSolution(puzzle))?;
+            .ensure_solver_file(&SolverFile::ActiveSolution(self.active_solution(puzzle)))?;
         self.runner.setup_env()?;
         Ok(())
     }
+
+    fn stored_solutions(&self) -> AocLanguageResult<Vec<SolutionId>> {
+        let entries = match std::fs::read_dir(self.runner.solutions_dir()) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+        let mut solutions = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if let Some(solution) = self.solution_at(&path) {
+                solutions.push(solution);
+            }
+        }
+        Ok(solutions)
+    }
+
+    /// The solution stored at `path`, if it is an existing solution file of this language.
+    fn solution_at(&self, path: &Path) -> Option<SolutionId> {
+        let solution = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<SolutionId>().ok())?;
+        (path.is_file()
+            && path
+                == self
+                    .runner
+                    .solver_file_path(&SolverFile::PuzzleSolution(solution)))
+        .then_some(solution)
+    }
 }
 
-fn validate_user_lib(lib_name: &str, language: &LanguageId) -> AocLanguageResult<()> {
+fn validate_user_lib(lib_name: &str, reserved_names: &[&str]) -> AocLanguageResult<()> {
     if lib_name.is_empty() {
         return Err(AocLanguageError::LibInvalid(
             "Library name cannot be empty".to_string(),
@@ -207,10 +476,6 @@ fn validate_user_lib(lib_name: &str, language: &LanguageId) -> AocLanguageResult
         ));
     }
 
-    let reserved_names = match language {
-        LanguageId::Rust => RUST_RESERVED_NAMES,
-        LanguageId::Python => PYTHON_RESERVED_NAMES,
-    };
     if reserved_names
         .iter()
         .any(|reserved| reserved.eq_ignore_ascii_case(lib_name))
@@ -239,21 +504,6 @@ fn ensure_no_case_collision(dir: &Path, extension: &str, lib_name: &str) -> AocL
     Ok(())
 }
 
-const RUST_RESERVED_NAMES: &[&str] = &[
-    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
-    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
-    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
-    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
-    "typeof", "union", "unsafe", "use", "virtual", "where", "while", "yield", "main", "solution",
-    "template",
-];
-
-const PYTHON_RESERVED_NAMES: &[&str] = &[
-    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
-    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
-    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
-    "with", "yield", "match", "case", "type", "main", "solution", "template", "venv",
-];
 fn scan_lib_directory(dir: &Path, file_extention: &str) -> crate::AocLanguageResult<Vec<String>> {
     let mut lib_files = Vec::new();
     let entries = match std::fs::read_dir(dir) {
@@ -290,39 +540,23 @@ fn scan_lib_directory(dir: &Path, file_extention: &str) -> crate::AocLanguageRes
 
 #[cfg(test)]
 mod tests {
-    use std::{
-        fs,
-        path::PathBuf,
-        process,
-        sync::Mutex,
-        time::{SystemTime, UNIX_EPOCH},
-    };
+    use std::{fs, path::PathBuf};
 
-    use super::{ensure_no_case_collision, validate_user_lib, Language};
+    use super::{
+        ensure_no_case_collision, validate_user_lib, AocLanguageError, BenchStats, Language,
+        LanguageSettings, RunOptions, TemplateVariables,
+    };
     use crate::{
-        rust::RustRunner,
-        traits::Solver,
+        languages,
+        test_support::{successful_output, test_root, ScriptedExecutor, SYSTEM_EXECUTOR},
         utils::{read_result, with_result_file},
     };
     use aocsuite_storage::Workspace;
     use aocsuite_utils::{
-        CommandExecutor, CommandRequest, LanguageId, PartSelection, PuzzleDay, PuzzleId,
-        PuzzlePart, PuzzleYear, SystemCommandExecutor,
+        BenchIterations, LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear,
+        SolutionId, TemplateName,
     };
 
-    static SYSTEM_EXECUTOR: SystemCommandExecutor = SystemCommandExecutor;
-
-    fn test_root(language: &str) -> PathBuf {
-        let unique = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system time is after the Unix epoch")
-            .as_nanos();
-        std::env::temp_dir().join(format!(
-            "aocsuite-lang-{language}-{}-{unique}",
-            process::id()
-        ))
-    }
-
     #[test]
     fn library_names_follow_language_identifier_rules() {
         let cases = [
@@ -340,11 +574,25 @@ mod tests {
             (LanguageId::Python, "two-words", false),
             (LanguageId::Python, "2fast", false),
             (LanguageId::Python, "café", false),
+            (LanguageId::Go, "grid", true),
+            (LanguageId::Go, "func", false),
+            (LanguageId::Go, "Solution", false),
+            (LanguageId::Cpp, "grid", true),
+            (LanguageId::Cpp, "vector", false),
+            (LanguageId::TypeScript, "grid", true),
+            (LanguageId::TypeScript, "function", false),
         ];
 
         for (language, name, valid) in cases {
+            let runner = languages::to_runner(
+                language,
+                PathBuf::new(),
+                &LanguageSettings::default(),
+                &SYSTEM_EXECUTOR,
+            )
+            .expect("built-in runner");
             assert_eq!(
-                validate_user_lib(name, &language).is_ok(),
+                validate_user_lib(name, &runner.reserved_names()).is_ok(),
                 valid,
                 "{language:?} name {name}"
             );
@@ -363,15 +611,67 @@ mod tests {
         std::fs::remove_dir_all(dir).expect("remove library directory");
     }
 
+    #[test]
+    fn puzzle_solutions_list_canonical_solution_files_by_year() {
+        let root = test_root("puzzle-solutions");
+        let workspace = Workspace::new(root.clone());
+        let language = Language::new(
+            LanguageId::Python,
+            &workspace,
+            &LanguageSettings::default(),
+            &SYSTEM_EXECUTOR,
+        )
+        .expect("load language");
+        assert!(language.puzzle_solutions(None).unwrap().is_empty());
+
+        let solutions = workspace
+            .language_project_dir(LanguageId::Python)
+            .join("solutions");
+        fs::create_dir_all(&solutions).expect("create solutions directory");
+        for file in [
+            "year2024_day10.py",
+            "year2023_day1.py",
+            "year2024_day2.py",
+            "year2024_day02.py",
+            "year2024_day3.rs",
+            "notes.py",
+        ] {
+            fs::write(solutions.join(file), "").expect("write solution file");
+        }
+        let puzzle =
+            |day, year| PuzzleId::new(PuzzleDay::new(day).unwrap(), PuzzleYear::new(year).unwrap());
+
+        assert_eq!(
+            language.puzzle_solutions(None).unwrap(),
+            [puzzle(1, 2023), puzzle(2, 2024), puzzle(10, 2024)]
+        );
+        assert_eq!(
+            language
+                .puzzle_solutions(Some(PuzzleYear::new(2023).unwrap()))
+                .unwrap(),
+            [puzzle(1, 2023)]
+        );
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
     #[test]
     fn runtime_cleanup_removes_only_generated_runtime_files() {
         for (language_id, entrypoint, active_solution) in [
             (LanguageId::Rust, "src/main.rs", "src/solution.rs"),
             (LanguageId::Python, "main.py", "solution.py"),
+            (LanguageId::Go, "main.go", "solution.go"),
+            (LanguageId::Cpp, "main.cpp", "solution.cpp"),
+            (LanguageId::TypeScript, "main.ts", "solution.ts"),
         ] {
             let root = test_root("runtime-cleanup");
             let workspace = Workspace::new(root.join("workspace"));
-            let language = Language::new(language_id, &workspace, &SYSTEM_EXECUTOR);
+            let language = Language::new(
+                language_id,
+                &workspace,
+                &LanguageSettings::default(),
+                &SYSTEM_EXECUTOR,
+            )
+            .expect("load language");
             let project = workspace.language_project_dir(language_id);
             let entrypoint = project.join(entrypoint);
             let active_solution = project.join(active_solution);
@@ -400,7 +700,13 @@ mod tests {
     fn runtime_cleanup_removes_active_solution_links() {
         let root = test_root("runtime-active-link-cleanup");
         let workspace = Workspace::new(root.join("workspace"));
-        let language = Language::new(LanguageId::Rust, &workspace, &SYSTEM_EXECUTOR);
+        let language = Language::new(
+            LanguageId::Rust,
+            &workspace,
+            &LanguageSettings::default(),
+            &SYSTEM_EXECUTOR,
+        )
+        .expect("load language");
         let project = workspace.language_project_dir(LanguageId::Rust);
         let source = project.join("solutions/source.rs");
         let active = project.join("src/solution.rs");
@@ -419,42 +725,11 @@ mod tests {
 
     #[test]
     fn language_execute_prepares_runtime_before_running_solver() {
-        struct ScriptedExecutor {
-            requests: Mutex<Vec<CommandRequest>>,
-            project_dir: PathBuf,
-            active_solution: PathBuf,
-            entrypoint: PathBuf,
-        }
-
-        impl CommandExecutor for ScriptedExecutor {
-            fn execute(&self, request: &CommandRequest) -> std::io::Result<std::process::Output> {
-                self.requests.lock().unwrap().push(request.clone());
-                if request
-                    .args
-                    .last()
-                    .is_some_and(|argument| argument == "both")
-                {
-                    assert!(self.project_dir.join(".aocsuite-runtime.json").is_file());
-                    assert!(self.entrypoint.is_file());
-                    assert!(self.active_solution.exists());
-
-                    let output_file = if request.args.len() == 4 {
-                        &request.args[2]
-                    } else {
-                        &request.args[1]
-                    };
-                    std::fs::write(
-                        std::path::PathBuf::from(output_file),
-                        r#"{"part1":{"answer":"example","runtime_ms":3},"part2":{"answer":"8","runtime_ms":4}}"#,
-                    )?;
-                }
-                Ok(successful_output())
-            }
-        }
-
         for (language_id, entrypoint, active_solution) in [
             (LanguageId::Rust, "src/main.rs", "src/solution.rs"),
             (LanguageId::Python, "main.py", "solution.py"),
+            (LanguageId::Go, "main.go", "solution.go"),
+            (LanguageId::TypeScript, "main.ts", "solution.ts"),
         ] {
             let root = test_root(&format!("{language_id}-execution"));
             let workspace = Workspace::new(root.clone());
@@ -462,19 +737,40 @@ mod tests {
             let project_dir = workspace.language_project_dir(language_id);
             fs::create_dir_all(&root).expect("create test workspace");
             fs::write(&input, "example\n").expect("write input");
-            let executor = ScriptedExecutor {
-                requests: Mutex::new(Vec::new()),
-                active_solution: project_dir.join(active_solution),
-                entrypoint: project_dir.join(entrypoint),
-                project_dir,
-            };
-            let language = Language::new(language_id, &workspace, &executor);
+            let active_solution = project_dir.join(active_solution);
+            let entrypoint = project_dir.join(entrypoint);
+            let executor = ScriptedExecutor::new(|request| {
+                if request
+                    .args
+                    .last()
+                    .is_some_and(|argument| argument == "both")
+                {
+                    assert!(project_dir.join(".aocsuite-runtime.json").is_file());
+                    assert!(entrypoint.is_file());
+                    assert!(active_solution.exists());
+
+                    let output_file = &request.args[request.args.len() - 2];
+                    fs::write(
+                        output_file,
+                        r#"{"part1":{"answer":"example","runtime_ms":3},"part2":{"answer":"8","runtime_ms":4}}"#,
+                    )?;
+                }
+                Ok(successful_output())
+            });
+            let language = Language::new(
+                language_id,
+                &workspace,
+                &LanguageSettings::default(),
+                &executor,
+            )
+            .expect("load language");
 
             let result = language
                 .execute(
                     PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap()),
                     PartSelection::Both,
                     &input,
+                    &RunOptions::default(),
                 )
                 .expect("run solution");
 
@@ -502,73 +798,368 @@ mod tests {
         }
     }
 
-    #[cfg(unix)]
-    fn successful_output() -> std::process::Output {
-        use std::os::unix::process::ExitStatusExt;
+    #[test]
+    fn result_files_are_cleaned_after_failures() {
+        let root = test_root("results");
+        let runs_dir = root.join(".aocsuite-runs");
+        fs::create_dir_all(&runs_dir).expect("create runs directory");
+        let malformed_result = runs_dir.join("malformed.json");
+        fs::write(&malformed_result, "not JSON").expect("write malformed result");
+        assert!(with_result_file(&malformed_result, read_result).is_err());
+        assert!(!malformed_result.exists());
 
-        std::process::Output {
-            status: std::process::ExitStatus::from_raw(0),
-            stdout: b"command output".to_vec(),
-            stderr: Vec::new(),
-        }
+        fs::remove_dir_all(root).expect("remove test runtime");
     }
 
-    #[cfg(windows)]
-    fn successful_output() -> std::process::Output {
-        use std::os::windows::process::ExitStatusExt;
+    #[test]
+    fn bench_runs_pass_iterations_and_read_nanosecond_statistics() {
+        let root = test_root("rust-bench");
+        let workspace = Workspace::new(root.clone());
+        let input = root.join("input.txt");
+        fs::create_dir_all(&root).expect("create test workspace");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::new(|request| {
+            if request.args.last().is_some_and(|argument| argument == "25") {
+                fs::write(
+                    &request.args[1],
+                    r#"{"part1":{"answer":"7","runtime_ms":0,"bench":{"iterations":25,"min_ns":900,"mean_ns":1100,"median_ns":1000,"stddev_ns":80}},"part2":null}"#,
+                )?;
+            }
+            Ok(successful_output())
+        });
+        let language = Language::new(
+            LanguageId::Rust,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+
+        let result = language
+            .execute(
+                PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap()),
+                PartSelection::One,
+                &input,
+                &RunOptions {
+                    bench: Some(BenchIterations::new(25).unwrap()),
+                    ..RunOptions::default()
+                },
+            )
+            .expect("run benchmark");
+
+        let run = executor.requests().last().cloned().unwrap();
+        assert_eq!(run.args[2..], ["1", "25"].map(std::ffi::OsString::from));
+        let part = result.run.result.part(PuzzlePart::One).expect("part one");
+        assert_eq!(
+            part.bench(),
+            Some(&BenchStats {
+                iterations: 25,
+                min_ns: 900,
+                mean_ns: 1100,
+                median_ns: 1000,
+                stddev_ns: 80,
+            })
+        );
 
-        std::process::Output {
-            status: std::process::ExitStatus::from_raw(0),
-            stdout: b"command output".to_vec(),
-            stderr: Vec::new(),
-        }
+        fs::remove_dir_all(root).expect("remove test runtime");
     }
 
     #[test]
-    fn rust_runtime_migration_replaces_only_owned_files() {
-        let root = test_root("rust-migration");
-        let runner = RustRunner::new(root.clone(), &SYSTEM_EXECUTOR);
-        let main = root.join("src/main.rs");
-        let cargo = root.join("Cargo.toml");
-        let solution = root.join("src/solution.rs");
-        let library = root.join("src/helpers.rs");
-        let template = root.join("template.rs");
-        let puzzle = root.join("solutions/year2024_day1.rs");
-        fs::create_dir_all(main.parent().expect("main parent")).expect("create source directory");
-        fs::create_dir_all(puzzle.parent().expect("puzzle parent"))
-            .expect("create puzzle directory");
-        for path in [&main, &cargo, &solution, &library, &template, &puzzle] {
-            fs::write(path, "legacy or user content").expect("write legacy fixture");
-        }
+    fn runs_report_timeouts_and_stop_when_cancelled_before_running() {
+        let root = test_root("python-timeout");
+        let workspace = Workspace::new(root.clone());
+        let input = root.join("input.txt");
+        fs::create_dir_all(&root).expect("create test workspace");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::new(|request| {
+            if request.timeout.is_some() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "command timed out",
+                ));
+            }
+            Ok(successful_output())
+        });
+        let language = Language::new(
+            LanguageId::Python,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+        let timeout = std::time::Duration::from_secs(30);
+
+        let error = language
+            .execute(
+                puzzle,
+                PartSelection::Both,
+                &input,
+                &RunOptions {
+                    timeout: Some(timeout),
+                    ..RunOptions::default()
+                },
+            )
+            .expect_err("run times out");
+        assert!(matches!(error, AocLanguageError::TimedOut(limit) if limit == timeout));
+        assert_eq!(error.to_string(), "timed out after 30s");
+
+        let cancellation = aocsuite_utils::CancellationToken::new();
+        cancellation.cancel();
+        assert!(matches!(
+            language.execute(
+                puzzle,
+                PartSelection::Both,
+                &input,
+                &RunOptions {
+                    cancellation: Some(cancellation),
+                    ..RunOptions::default()
+                },
+            ),
+            Err(AocLanguageError::Cancelled)
+        ));
+        assert!(!executor
+            .requests()
+            .iter()
+            .any(|request| request.cancellation.is_some()));
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    #[test]
+    fn builds_are_supervised_and_benchmarks_get_a_capped_timeout_per_solve() {
+        let root = test_root("go-supervised");
+        let workspace = Workspace::new(root.clone());
+        let input = root.join("input.txt");
+        fs::create_dir_all(&root).expect("create test workspace");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::recording();
+        let language = Language::new(
+            LanguageId::Go,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        let timeout = std::time::Duration::from_secs(2);
+        let cancellation = aocsuite_utils::CancellationToken::new();
+
+        // The executor writes no result file, so only the recorded requests are checked.
+        let _ = language.execute(
+            PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap()),
+            PartSelection::Both,
+            &input,
+            &RunOptions {
+                bench: Some(BenchIterations::new(100).unwrap()),
+                timeout: Some(timeout),
+                cancellation: Some(cancellation),
+                ..RunOptions::default()
+            },
+        );
+
+        let requests = executor.requests();
+        let build = requests
+            .iter()
+            .find(|request| request.program == "go")
+            .expect("solution is built");
+        assert_eq!(build.timeout, Some(timeout));
+        assert!(build.cancellation.is_some());
+        let run = requests
+            .iter()
+            .find(|request| request.args.first() == Some(&input.clone().into_os_string()))
+            .expect("solution is run");
+        // 100 timed iterations and 10 warmup runs, capped at ten solves.
+        assert_eq!(run.timeout, Some(timeout * 10));
+        assert!(run.cancellation.is_some());
+        drop(requests);
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
 
-        runner
-            .migrate_runtime()
-            .expect("migrate legacy Rust runtime");
+    #[test]
+    fn watch_lists_cover_the_solution_its_link_and_the_library_directory() {
+        let root = test_root("python-watch");
+        let workspace = Workspace::new(root.clone());
+        let executor = ScriptedExecutor::recording();
+        let language = Language::new(
+            LanguageId::Python,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+
+        let watched = language.watch_list(puzzle).expect("list watched files");
 
         assert_eq!(
-            fs::read_to_string(&main).expect("read main"),
-            runner.main_contents()
+            watched.files,
+            [
+                language
+                    .runner
+                    .solver_file_path(&crate::SolverFile::PuzzleSolution(puzzle.into())),
+                language.active_solution_path(),
+            ]
         );
-        for path in [&cargo, &solution, &library, &template, &puzzle] {
-            assert_eq!(
-                fs::read_to_string(path).expect("read preserved user file"),
-                "legacy or user content"
-            );
-        }
-        assert!(root.join(".aocsuite-runtime.json").exists());
+        assert!(watched.files[0].is_file());
+        assert_eq!(watched.dirs.len(), 1);
+        assert_eq!(watched.dirs[0].path, language.runner.lib_dir());
+        assert_eq!(watched.dirs[0].extension, "py");
+        assert!(watched.dirs[0]
+            .excluded
+            .iter()
+            .any(|name| name == "solution"));
 
         fs::remove_dir_all(root).expect("remove test runtime");
     }
 
     #[test]
-    fn result_files_are_cleaned_after_failures() {
-        let root = test_root("results");
-        let runs_dir = root.join(".aocsuite-runs");
-        fs::create_dir_all(&runs_dir).expect("create runs directory");
-        let malformed_result = runs_dir.join("malformed.json");
-        fs::write(&malformed_result, "not JSON").expect("write malformed result");
-        assert!(with_result_file(&malformed_result, read_result).is_err());
-        assert!(!malformed_result.exists());
+    fn solution_variants_are_selected_and_compared_on_the_same_input() {
+        let root = test_root("python-variants");
+        let workspace = Workspace::new(root.clone());
+        let input = root.join("input.txt");
+        fs::create_dir_all(&root).expect("create test workspace");
+        fs::write(&input, "example\n").expect("write input");
+        let crash_on_brute = std::sync::atomic::AtomicBool::new(false);
+        let executor = ScriptedExecutor::new(|request| {
+            // The harness answers with the contents of the linked solution.
+            if let [entrypoint, _, output, ..] = request.args.as_slice() {
+                if !entrypoint.to_string_lossy().ends_with("main.py") {
+                    return Ok(successful_output());
+                }
+                let solution =
+                    fs::read_to_string(PathBuf::from(entrypoint).with_file_name("solution.py"))?;
+                if solution == "brute" && crash_on_brute.load(std::sync::atomic::Ordering::Relaxed)
+                {
+                    panic!("executor crashed");
+                }
+                fs::write(
+                    output,
+                    format!(
+                        r#"{{"part1":{{"answer":"{}","runtime_ms":1}},"part2":null}}"#,
+                        solution.trim()
+                    ),
+                )?;
+            }
+            Ok(successful_output())
+        });
+        let language = Language::new(
+            LanguageId::Python,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        let puzzle = PuzzleId::new(PuzzleDay::new(5).unwrap(), PuzzleYear::new(2024).unwrap());
+        let fast: SolutionId = "year2024_day5.fast".parse().unwrap();
+        let brute: SolutionId = "year2024_day5.brute".parse().unwrap();
+
+        let main_path = language
+            .select_solution(puzzle.into())
+            .expect("main solution");
+        fs::write(&main_path, "main").expect("write main solution");
+        let fast_path = language.select_solution(fast).expect("create fast variant");
+        assert_eq!(fs::read_to_string(&fast_path).unwrap(), "main");
+        fs::write(&fast_path, "fast").expect("write fast variant");
+        fs::write(fast_path.with_file_name("year2024_day5.brute.py"), "brute")
+            .expect("write brute variant");
+
+        assert_eq!(language.active_solution(puzzle), fast);
+        assert_eq!(
+            language.solution_variants(puzzle).expect("list variants"),
+            [puzzle.into(), brute, fast]
+        );
+        assert_eq!(language.puzzle_solutions(None).unwrap(), [puzzle]);
+        assert_eq!(language.watch_list(puzzle).unwrap().files[0], fast_path);
+
+        let compared = language
+            .compare(puzzle, PartSelection::One, &input, &RunOptions::default())
+            .expect("compare variants");
+        let answers = compared
+            .iter()
+            .map(|(solution, output)| {
+                let output = output.as_ref().expect("variant run");
+                let answer = output.run.result.part(PuzzlePart::One).unwrap().answer();
+                (*solution, answer.to_owned())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            answers,
+            [
+                (puzzle.into(), "main".to_owned()),
+                (brute, "brute".to_owned()),
+                (fast, "fast".to_owned()),
+            ]
+        );
+        assert_eq!(language.active_solution(puzzle), fast);
+
+        crash_on_brute.store(true, std::sync::atomic::Ordering::Relaxed);
+        let crashed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            language.compare(puzzle, PartSelection::One, &input, &RunOptions::default())
+        }));
+        assert!(crashed.is_err());
+        assert_eq!(language.active_solution(puzzle), fast);
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    #[test]
+    fn new_solutions_expand_the_chosen_template() {
+        let root = test_root("python-templates");
+        let workspace = Workspace::new(root.clone());
+        let language = Language::new(
+            LanguageId::Python,
+            &workspace,
+            &LanguageSettings::default(),
+            &SYSTEM_EXECUTOR,
+        )
+        .expect("load language");
+        let puzzle = PuzzleId::new(PuzzleDay::new(5).unwrap(), PuzzleYear::new(2024).unwrap());
+        let variables = TemplateVariables::new(puzzle).with_title("Print Queue");
+        let grid = TemplateName::new("grid").unwrap();
+
+        assert!(matches!(
+            language.prepare_solution(puzzle.into(), Some(&grid), &variables),
+            Err(AocLanguageError::TemplateNotFound(_))
+        ));
+        let grid_path = language
+            .ensure_solver_file(&crate::SolverFile::SolutionTemplate(grid.clone()))
+            .expect("create grid template");
+        assert_eq!(
+            fs::read_to_string(&grid_path).unwrap(),
+            language.runner.template_contents()
+        );
+        fs::write(
+            &grid_path,
+            "# {{title}} ({{year}} day {{day}})\n# {{url}}\n",
+        )
+        .expect("write grid template");
+        assert_eq!(
+            language.templates().unwrap(),
+            [TemplateName::default(), grid.clone()]
+        );
+
+        let active = language
+            .prepare_solution(puzzle.into(), Some(&grid), &variables)
+            .expect("create solution from grid");
+        let expected = "# Print Queue (2024 day 5)\n# https://adventofcode.com/2024/day/5\n";
+        assert_eq!(fs::read_to_string(&active).unwrap(), expected);
+
+        // Existing solutions are kept, and variants copy the expanded main solution.
+        fs::write(&grid_path, "changed").expect("change grid template");
+        assert!(matches!(
+            language.prepare_solution(puzzle.into(), Some(&grid), &variables),
+            Err(AocLanguageError::SolutionExists { .. })
+        ));
+        language
+            .prepare_solution(puzzle.into(), None, &variables)
+            .expect("select existing solution");
+        assert_eq!(fs::read_to_string(&active).unwrap(), expected);
+        let fast = SolutionId::new(puzzle, Some("fast".parse().unwrap()));
+        let variant = language
+            .prepare_solution(fast, None, &variables)
+            .expect("create variant");
+        assert_eq!(fs::read_to_string(&variant).unwrap(), expected);
 
         fs::remove_dir_all(root).expect("remove test runtime");
     }
@@ -1,30 +1,52 @@
 use crate::{
     traits::{DepManager, Solver},
-    AocLanguageResult,
+    AocLanguageError, AocLanguageResult,
+};
+use std::{
+    path::{Path, PathBuf},
+    process::Output,
 };
-use std::{path::PathBuf, process::Output};
 
 use super::PythonRunner;
-use aocsuite_utils::{atomic_write, execute_command, CommandRequest};
+use aocsuite_utils::{atomic_write, execute_command, CommandRequest, PythonBackend};
 
 impl DepManager for PythonRunner<'_> {
     fn setup_env(&self) -> AocLanguageResult<Option<Output>> {
         self.migrate_runtime()?;
         let venv_path = self.root_dir.join("venv");
 
-        if !venv_path.exists() {
-            // Create virtual environment
-            return Ok(Some(execute_command(
-                self.executor,
-                CommandRequest::new("python3")
-                    .arg("-m")
-                    .arg("venv")
-                    .arg("venv")
-                    .current_dir(&self.root_dir),
-            )?));
+        if venv_path.exists() {
+            // Never rebuilt here: the environment may hold packages missing from the lockfile.
+            self.check_venv()?;
+            return Ok(None);
         }
 
-        Ok(None)
+        let output = execute_command(self.executor, self.create_venv())?;
+        std::fs::create_dir_all(&venv_path)?;
+        atomic_write(
+            &venv_path.join(BACKEND_MARKER),
+            self.backend.to_string().as_bytes(),
+        )?;
+        let requirements = self.root_dir.join("requirements.txt");
+        let pinned = match std::fs::read_to_string(&requirements) {
+            Ok(pinned) => pinned.lines().any(|line| !line.trim().is_empty()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => false,
+            Err(error) => return Err(error.into()),
+        };
+        let install = match self.backend {
+            // Adopt the pins of an environment that pip managed before.
+            PythonBackend::Uv if pinned && !self.root_dir.join("uv.lock").exists() => {
+                self.uv_project("add").arg("-r").arg(requirements)
+            }
+            PythonBackend::Uv => self.uv_project("sync"),
+            _ if pinned => CommandRequest::new(self.get_pip_path())
+                .arg("install")
+                .arg("-r")
+                .arg(requirements)
+                .current_dir(&self.root_dir),
+            _ => return Ok(Some(output)),
+        };
+        Ok(Some(execute_command(self.executor, install)?))
     }
     fn clean_env(&self) -> AocLanguageResult<()> {
         match std::fs::remove_dir_all(self.root_dir.join("venv")) {
@@ -36,78 +58,191 @@ impl DepManager for PythonRunner<'_> {
     }
 
     fn add_package(&self, package: &str) -> AocLanguageResult<()> {
-        let pip_path = self.get_pip_path();
-
-        execute_command(
-            self.executor,
-            CommandRequest::new(pip_path)
-                .arg("install")
-                .arg(package)
-                .current_dir(&self.root_dir),
-        )?;
-        self.persist_requirements()?;
+        match self.backend {
+            PythonBackend::Uv => {
+                execute_command(self.executor, self.uv_project("add").arg(package))?;
+            }
+            _ => {
+                execute_command(
+                    self.executor,
+                    CommandRequest::new(self.get_pip_path())
+                        .arg("install")
+                        .arg(package)
+                        .current_dir(&self.root_dir),
+                )?;
+                self.persist_requirements()?;
+            }
+        }
         Ok(())
     }
 
+    /// Installed packages pinned as `name==version`.
     fn list_packages(&self) -> AocLanguageResult<Vec<String>> {
         let venv_path = self.root_dir.join("venv");
         if !venv_path.exists() {
             return Ok(Vec::new());
         }
 
-        let pip_path = self.get_pip_path();
-
-        let diagnostic = execute_command(
-            self.executor,
-            CommandRequest::new(pip_path)
-                .arg("list")
-                .arg("--format=freeze")
-                .current_dir(&self.root_dir),
-        )?;
+        let diagnostic = execute_command(self.executor, self.freeze())?;
 
         let stdout = String::from_utf8_lossy(&diagnostic.stdout);
         let packages: Vec<String> = stdout
             .lines()
-            .filter_map(|line| {
-                if line.trim().is_empty() || line.starts_with('#') {
-                    None
-                } else {
-                    line.split("==").next().map(|name| name.to_string())
-                }
-            })
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
             .collect();
 
         Ok(packages)
     }
 
     fn remove_packages(&self, package: &str) -> AocLanguageResult<()> {
-        let pip_path = self.get_pip_path();
-
-        execute_command(
-            self.executor,
-            CommandRequest::new(pip_path)
-                .arg("uninstall")
-                .arg("-y")
-                .arg(package)
-                .current_dir(&self.root_dir),
-        )?;
-        self.persist_requirements()?;
+        // Accept the pinned form returned by `list_packages`.
+        let name = requirement_name(package);
+        match self.backend {
+            PythonBackend::Uv => {
+                execute_command(self.executor, self.uv_project("remove").arg(name))?;
+            }
+            _ => {
+                execute_command(
+                    self.executor,
+                    CommandRequest::new(self.get_pip_path())
+                        .arg("uninstall")
+                        .arg("-y")
+                        .arg(name)
+                        .current_dir(&self.root_dir),
+                )?;
+                self.persist_requirements()?;
+            }
+        }
         Ok(())
     }
 }
 
+/// File in `venv/` naming the backend that built it, so `Auto` keeps using that backend.
+const BACKEND_MARKER: &str = "aocsuite-backend";
+
+/// Backend that built the virtual environment in `venv`, from its marker file or, for
+/// environments created before the marker existed, its `pyvenv.cfg`.
+pub(super) fn recorded_backend(venv: &Path) -> AocLanguageResult<Option<PythonBackend>> {
+    match std::fs::read_to_string(venv.join(BACKEND_MARKER)) {
+        Ok(backend) => return Ok(backend.trim().parse().ok()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error.into()),
+    }
+    Ok(read_pyvenv_cfg(venv)?.map(|config| {
+        if pyvenv_value(&config, "uv").is_some() {
+            PythonBackend::Uv
+        } else {
+            PythonBackend::Pip
+        }
+    }))
+}
+
+fn read_pyvenv_cfg(venv: &Path) -> AocLanguageResult<Option<String>> {
+    match std::fs::read_to_string(venv.join("pyvenv.cfg")) {
+        Ok(config) => Ok(Some(config)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn pyvenv_value(config: &str, key: &str) -> Option<String> {
+    config.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == key).then(|| value.trim().to_string())
+    })
+}
+
 impl PythonRunner<'_> {
     fn persist_requirements(&self) -> AocLanguageResult<()> {
-        let output = execute_command(
-            self.executor,
-            CommandRequest::new(self.get_pip_path())
-                .arg("freeze")
-                .current_dir(&self.root_dir),
-        )?;
+        let output = execute_command(self.executor, self.freeze())?;
         atomic_write(&self.root_dir.join("requirements.txt"), &output.stdout)?;
         Ok(())
     }
 
+    fn create_venv(&self) -> CommandRequest {
+        match self.backend {
+            PythonBackend::Uv => CommandRequest::new("uv")
+                .arg("venv")
+                .arg("venv")
+                .args(
+                    self.version
+                        .iter()
+                        .flat_map(|version| ["--python", version.as_str()]),
+                )
+                .current_dir(&self.root_dir),
+            _ => CommandRequest::new(match &self.version {
+                Some(version) => format!("python{version}"),
+                None => "python3".to_string(),
+            })
+            .arg("-m")
+            .arg("venv")
+            .arg("venv")
+            .current_dir(&self.root_dir),
+        }
+    }
+
+    fn freeze(&self) -> CommandRequest {
+        match self.backend {
+            PythonBackend::Uv => self.uv_pip("freeze"),
+            _ => CommandRequest::new(self.get_pip_path())
+                .arg("freeze")
+                .current_dir(&self.root_dir),
+        }
+    }
+
+    fn uv_pip(&self, command: &str) -> CommandRequest {
+        CommandRequest::new("uv")
+            .arg("pip")
+            .arg(command)
+            .arg("--python")
+            .arg(self.get_python_path())
+            .current_dir(&self.root_dir)
+    }
+
+    /// uv project command on `pyproject.toml`, locked in `uv.lock` and synced into `venv`.
+    fn uv_project(&self, command: &str) -> CommandRequest {
+        CommandRequest::new("uv")
+            .arg(command)
+            .env("UV_PROJECT_ENVIRONMENT", self.root_dir.join("venv"))
+            .current_dir(&self.root_dir)
+    }
+
+    /// Fails unless `venv` was created by the configured backend and interpreter version. uv
+    /// stores the version as `version_info` in `pyvenv.cfg`.
+    fn check_venv(&self) -> AocLanguageResult<()> {
+        let venv = self.root_dir.join("venv");
+        let mismatch = |found: String, configured: String| AocLanguageError::PythonEnvMismatch {
+            venv: venv.clone(),
+            found,
+            configured,
+        };
+        if let Some(built_by) = recorded_backend(&venv)? {
+            if built_by != self.backend {
+                return Err(mismatch(built_by.to_string(), self.backend.to_string()));
+            }
+        }
+        let (Some(requested), Some(config)) = (&self.version, read_pyvenv_cfg(&venv)?) else {
+            return Ok(());
+        };
+        // Only plain versions can be compared; names like `pypy3.10` are trusted as-is.
+        if !requested.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return Ok(());
+        }
+        match pyvenv_value(&config, "version_info").or_else(|| pyvenv_value(&config, "version")) {
+            Some(version)
+                if version != *requested && !version.starts_with(&format!("{requested}.")) =>
+            {
+                Err(mismatch(
+                    format!("Python {version}"),
+                    format!("Python {requested}"),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn get_pip_path(&self) -> PathBuf {
         if cfg!(windows) {
             self.root_dir.join("venv").join("Scripts").join("pip.exe")
@@ -127,3 +262,11 @@ impl PythonRunner<'_> {
         }
     }
 }
+
+/// Project name of a requirement such as `numpy==2.1.0` or `requests[socks]>=2`.
+fn requirement_name(requirement: &str) -> &str {
+    let end = requirement
+        .find(['=', '<', '>', '!', '~', ';', '[', '@', ' '])
+        .unwrap_or(requirement.len());
+    &requirement[..end]
+}
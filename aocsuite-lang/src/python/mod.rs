@@ -3,14 +3,216 @@ mod solver;
 mod user_library;
 use std::path::PathBuf;
 
-use aocsuite_utils::CommandExecutor;
+use aocsuite_utils::{CommandExecutor, PythonBackend};
+
+use crate::{utils::on_path, AocLanguageResult};
 
 pub struct PythonRunner<'executor> {
     root_dir: PathBuf,
+    /// `Pip` or `Uv`; `Auto` is resolved when the runner is created, to the backend that built
+    /// the existing environment if there is one.
+    backend: PythonBackend,
+    version: Option<String>,
     executor: &'executor dyn CommandExecutor,
 }
 impl<'executor> PythonRunner<'executor> {
-    pub fn new(root_dir: PathBuf, executor: &'executor dyn CommandExecutor) -> Self {
-        Self { root_dir, executor }
+    pub fn new(
+        root_dir: PathBuf,
+        backend: PythonBackend,
+        version: Option<String>,
+        executor: &'executor dyn CommandExecutor,
+    ) -> AocLanguageResult<Self> {
+        let backend = match backend {
+            PythonBackend::Auto => match dependencies::recorded_backend(&root_dir.join("venv"))? {
+                Some(backend) => backend,
+                None if on_path("uv") => PythonBackend::Uv,
+                None => PythonBackend::Pip,
+            },
+            backend => backend,
+        };
+        Ok(Self {
+            root_dir,
+            backend,
+            version,
+            executor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use aocsuite_storage::Workspace;
+    use aocsuite_utils::{
+        LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzleYear, PythonBackend,
+    };
+
+    use crate::{
+        test_support::{successful_output, test_root, ScriptedExecutor},
+        AocLanguageError, Language, LanguageSettings, RunOptions,
+    };
+
+    #[test]
+    fn streamed_python_runs_are_unbuffered() {
+        let root = test_root("python-stream");
+        let workspace = Workspace::new(root.clone());
+        let input = root.join("input.txt");
+        fs::create_dir_all(&root).expect("create test workspace");
+        fs::write(&input, "example\n").expect("write input");
+        let executor = ScriptedExecutor::new(|request| {
+            if request.output_sink.is_some() {
+                fs::write(
+                    &request.args[2],
+                    r#"{"part1":{"answer":"7","runtime_ms":1},"part2":null}"#,
+                )?;
+            }
+            Ok(successful_output())
+        });
+        let language = Language::new(
+            LanguageId::Python,
+            &workspace,
+            &LanguageSettings::default(),
+            &executor,
+        )
+        .expect("load language");
+        let sink = aocsuite_utils::OutputSink::new(|_| {});
+
+        language
+            .execute(
+                PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap()),
+                PartSelection::One,
+                &input,
+                &RunOptions {
+                    output: Some(sink.clone()),
+                    ..RunOptions::default()
+                },
+            )
+            .expect("run streamed solver");
+
+        let requests = executor.requests();
+        let streamed = requests
+            .iter()
+            .filter(|request| request.output_sink.is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(streamed.len(), 1);
+        assert_eq!(streamed[0].output_sink, Some(sink));
+        assert!(streamed[0]
+            .environment
+            .contains(&("PYTHONUNBUFFERED".into(), "1".into())));
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    // The virtual environment's interpreter lives in `Scripts` on Windows.
+    #[cfg(unix)]
+    #[test]
+    fn uv_environments_are_locked_and_never_rebuilt_by_another_backend() {
+        let root = test_root("python-uv");
+        let workspace = Workspace::new(root.clone());
+        let project = workspace.language_project_dir(LanguageId::Python);
+        // Pins left behind by pip are adopted into the uv project.
+        let requirements = project.join("requirements.txt");
+        fs::create_dir_all(&project).expect("create project");
+        fs::write(&requirements, "numpy==2.1.0\n").expect("write requirements");
+        let executor = ScriptedExecutor::new(|request| {
+            assert_eq!(request.program, "uv");
+            let mut output = successful_output();
+            match request.args[0].to_str().unwrap() {
+                "venv" => {
+                    let venv = request.current_dir.as_ref().unwrap().join("venv");
+                    fs::create_dir_all(&venv)?;
+                    fs::write(
+                        venv.join("pyvenv.cfg"),
+                        "home = /usr/bin\nuv = 0.5.0\nversion_info = 3.12.7\n",
+                    )?;
+                }
+                "add" | "remove" => {
+                    let environment = &request.environment[0];
+                    assert_eq!(environment.0, "UV_PROJECT_ENVIRONMENT");
+                    assert!(PathBuf::from(&environment.1).ends_with("venv"));
+                }
+                _ if request.args[1] == "freeze" => {
+                    output.stdout = b"numpy==2.1.0\nsympy==1.13.3\n".to_vec();
+                }
+                _ => {}
+            }
+            Ok(output)
+        });
+        let settings = LanguageSettings {
+            python_backend: PythonBackend::Uv,
+            python_version: Some("3.12".to_owned()),
+            ..LanguageSettings::default()
+        };
+        let language = Language::new(LanguageId::Python, &workspace, &settings, &executor)
+            .expect("load language");
+        let commands = || {
+            executor
+                .requests()
+                .drain(..)
+                .map(|request| {
+                    request
+                        .args
+                        .iter()
+                        .map(|arg| arg.to_string_lossy().into_owned())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>()
+        };
+        let python = project.join("venv/bin/python");
+        let python = python.display();
+
+        language.add_package("sympy").expect("add package");
+        assert_eq!(
+            commands(),
+            [
+                "venv venv --python 3.12".to_owned(),
+                format!("add -r {}", requirements.display()),
+                "add sympy".to_owned(),
+            ]
+        );
+        assert!(fs::read_to_string(project.join("pyproject.toml"))
+            .unwrap()
+            .contains("[tool.uv]"));
+        assert_eq!(
+            language.list_packages().unwrap(),
+            ["numpy==2.1.0", "sympy==1.13.3"]
+        );
+        assert_eq!(commands(), [format!("pip freeze --python {python}")]);
+
+        // `Auto` keeps the backend that built the environment, whatever is on `PATH`.
+        let auto = LanguageSettings {
+            python_backend: PythonBackend::Auto,
+            ..settings.clone()
+        };
+        let language =
+            Language::new(LanguageId::Python, &workspace, &auto, &executor).expect("load language");
+        language
+            .remove_package("numpy==2.1.0")
+            .expect("remove package");
+        assert_eq!(commands(), ["remove numpy"]);
+
+        for settings in [
+            LanguageSettings {
+                python_backend: PythonBackend::Pip,
+                ..settings.clone()
+            },
+            LanguageSettings {
+                python_version: Some("3.13".to_owned()),
+                ..settings.clone()
+            },
+        ] {
+            let language = Language::new(LanguageId::Python, &workspace, &settings, &executor)
+                .expect("load language");
+            assert!(matches!(
+                language.add_package("sympy"),
+                Err(AocLanguageError::PythonEnvMismatch { .. })
+            ));
+        }
+        assert!(commands().is_empty());
+        assert!(project.join("venv/pyvenv.cfg").is_file());
+
+        fs::remove_dir_all(root).expect("remove test runtime");
     }
 }
@@ -1,15 +1,26 @@
 use std::process::Output;
 
 use crate::{
+    template::named_template_path,
     traits::Solver,
-    utils::{AocLanguageResult, SolverFile},
+    utils::{AocLanguageResult, RunOptions, SolverFile},
 };
-use aocsuite_utils::{atomic_write, execute_command, CommandRequest};
+use aocsuite_utils::{atomic_write, execute_command, CommandRequest, PythonBackend};
 
 use super::PythonRunner;
 
+/// Project that uv locks the solution dependencies for; it is never built or installed itself.
+const PYPROJECT: &str = r#"[project]
+name = "aocsuite-solutions"
+version = "0.1.0"
+dependencies = []
+
+[tool.uv]
+package = false
+"#;
+
 impl Solver for PythonRunner<'_> {
-    fn compile(&self) -> AocLanguageResult<Option<Output>> {
+    fn compile(&self, _: &RunOptions) -> AocLanguageResult<Option<Output>> {
         Ok(None)
     }
 
@@ -18,18 +29,22 @@ impl Solver for PythonRunner<'_> {
         part: aocsuite_utils::PartSelection,
         input: &std::path::Path,
         output: &std::path::Path,
+        options: &RunOptions,
     ) -> AocLanguageResult<Output> {
         let python_path = self.get_python_path();
+        let mut request = CommandRequest::new(python_path)
+            .arg(self.solver_file_path(&SolverFile::Entrypoint))
+            .arg(input)
+            .arg(output)
+            .arg(part.to_string())
+            .args(options.bench.map(|iterations| iterations.to_string()))
+            .current_dir(&self.root_dir);
+        if options.output.is_some() {
+            // Python block-buffers piped stdout, which would hold prints back until exit.
+            request = request.env("PYTHONUNBUFFERED", "1");
+        }
 
-        Ok(execute_command(
-            self.executor,
-            CommandRequest::new(python_path)
-                .arg(self.solver_file_path(&SolverFile::Entrypoint))
-                .arg(input)
-                .arg(output)
-                .arg(part.to_string())
-                .current_dir(&self.root_dir),
-        )?)
+        Ok(execute_command(self.executor, options.supervise(request))?)
     }
     fn clean_runtime(&self) -> AocLanguageResult<()> {
         crate::runtime::clean_runtime(
@@ -41,9 +56,13 @@ impl Solver for PythonRunner<'_> {
 
     fn migrate_runtime(&self) -> AocLanguageResult<()> {
         std::fs::create_dir_all(&self.root_dir)?;
-        let requirements_path = self.root_dir.join("requirements.txt");
-        if !requirements_path.exists() {
-            atomic_write(&requirements_path, b"")?;
+        let (project_file, contents) = match self.backend {
+            PythonBackend::Uv => ("pyproject.toml", PYPROJECT),
+            _ => ("requirements.txt", ""),
+        };
+        let project_path = self.root_dir.join(project_file);
+        if !project_path.exists() {
+            atomic_write(&project_path, contents.as_bytes())?;
         }
 
         crate::runtime::migrate_runtime(
@@ -58,13 +77,21 @@ impl Solver for PythonRunner<'_> {
     fn solver_file_path(&self, file: &SolverFile) -> std::path::PathBuf {
         match file {
             SolverFile::Entrypoint => self.root_dir.join("main.py"),
-            SolverFile::SolutionTemplate => self.root_dir.join("template.py"),
+            SolverFile::SolutionTemplate(template) if template.is_default() => {
+                self.root_dir.join("template.py")
+            }
+            SolverFile::SolutionTemplate(template) => {
+                named_template_path(&self.root_dir, template, "py")
+            }
             SolverFile::ActiveSolution(_) => self.root_dir.join("solution.py"),
-            SolverFile::PuzzleSolution(puzzle) => {
-                self.root_dir.join("solutions").join(format!("{puzzle}.py"))
+            SolverFile::PuzzleSolution(solution) => {
+                self.solutions_dir().join(format!("{solution}.py"))
             }
         }
     }
+    fn solutions_dir(&self) -> std::path::PathBuf {
+        self.root_dir.join("solutions")
+    }
     fn template_contents(&self) -> String {
         r#""""Implement your solution here"""
 
@@ -83,6 +110,7 @@ def part2(input: str) -> str:
         r#"import sys
 import json
 import os
+import statistics
 import time
 from pathlib import Path
 
@@ -100,9 +128,39 @@ def run_part(part_fn, input_data):
         "runtime_ms": runtime_ms
     }
 
+def warmup_runs(iterations):
+    """Untimed runs before benchmarking: a tenth of the iterations, between one and ten."""
+    return min(max(iterations // 10, 1), 10)
+
+def bench_part(part_fn, input_data, iterations):
+    """Run a part function repeatedly and report nanosecond statistics."""
+    # The first warmup run provides the reported answer.
+    answer = part_fn(input_data)
+    for _ in range(1, warmup_runs(iterations)):
+        part_fn(input_data)
+
+    samples = []
+    for _ in range(iterations):
+        start = time.perf_counter_ns()
+        part_fn(input_data)
+        samples.append(time.perf_counter_ns() - start)
+    median_ns = int(statistics.median(samples))
+
+    return {
+        "answer": str(answer),
+        "runtime_ms": median_ns // 1_000_000,
+        "bench": {
+            "iterations": iterations,
+            "min_ns": min(samples),
+            "mean_ns": round(statistics.fmean(samples)),
+            "median_ns": median_ns,
+            "stddev_ns": round(statistics.pstdev(samples)),
+        }
+    }
+
 def main():
-    if len(sys.argv) < 3 or len(sys.argv) > 4:
-        print(f"Usage: {sys.argv[0]} <input_file> <output_file> [1|2|both]", file=sys.stderr)
+    if len(sys.argv) < 3 or len(sys.argv) > 5:
+        print(f"Usage: {sys.argv[0]} <input_file> <output_file> [1|2|both] [bench_iterations]", file=sys.stderr)
         sys.exit(1)
     
     input_file = sys.argv[1]
@@ -113,6 +171,18 @@ def main():
     if part not in ["1", "2", "both"]:
         print(f"Invalid part '{part}'. Use '1', '2', or 'both'", file=sys.stderr)
         sys.exit(1)
+
+    iterations = None
+    if len(sys.argv) > 4:
+        if not sys.argv[4].isdigit() or int(sys.argv[4]) == 0:
+            print(f"Invalid bench iterations '{sys.argv[4]}'. Use a positive number", file=sys.stderr)
+            sys.exit(1)
+        iterations = int(sys.argv[4])
+
+    def measure(part_fn):
+        if iterations is None:
+            return run_part(part_fn, input_data)
+        return bench_part(part_fn, input_data, iterations)
     
     # Read input file
     try:
@@ -134,12 +204,12 @@ def main():
     
     # Run requested parts
     if part == "1":
-        output["part1"] = run_part(part1, input_data)
+        output["part1"] = measure(part1)
     elif part == "2":
-        output["part2"] = run_part(part2, input_data)
+        output["part2"] = measure(part2)
     else:  # both
-        output["part1"] = run_part(part1, input_data)
-        output["part2"] = run_part(part2, input_data)
+        output["part1"] = measure(part1)
+        output["part2"] = measure(part2)
     
     # Publish a complete result so readers never observe a partial JSON document.
     try:
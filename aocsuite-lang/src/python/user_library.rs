@@ -12,7 +12,17 @@ impl LibManager for PythonRunner<'_> {
     fn lib_dir(&self) -> PathBuf {
         self.root_dir.clone()
     }
+    fn reserved_names(&self) -> Vec<&str> {
+        RESERVED_NAMES.to_vec()
+    }
     fn file_extention(&self) -> String {
         "py".to_string()
     }
 }
+
+const RESERVED_NAMES: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield", "match", "case", "type", "main", "solution", "template", "venv",
+];
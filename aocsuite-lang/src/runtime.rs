@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{AocLanguageError, AocLanguageResult};
 
-const RUNTIME_VERSION: u32 = 1;
+const RUNTIME_VERSION: u32 = 2;
 const MANIFEST_NAME: &str = ".aocsuite-runtime.json";
 
 #[derive(Deserialize, Serialize)]
@@ -1,33 +1,465 @@
 mod dependencies;
 mod solver;
 mod user_library;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use aocsuite_utils::CommandExecutor;
+use aocsuite_utils::{atomic_write, CommandExecutor, RustProfile};
+use serde::{Deserialize, Serialize};
+use toml_edit::{value, Array, DocumentMut, Item, Table, Value};
+
+use crate::{AocLanguageError, AocLanguageResult, RustSettings};
 
 pub struct RustRunner<'executor> {
     root_dir: PathBuf,
+    settings: RustSettings,
     executor: &'executor dyn CommandExecutor,
 }
 
 impl<'executor> RustRunner<'executor> {
-    pub fn new(root_dir: PathBuf, executor: &'executor dyn CommandExecutor) -> Self {
-        Self { root_dir, executor }
+    pub fn new(
+        root_dir: PathBuf,
+        settings: RustSettings,
+        executor: &'executor dyn CommandExecutor,
+    ) -> Self {
+        Self {
+            root_dir,
+            settings,
+            executor,
+        }
     }
     fn src_dir(&self) -> PathBuf {
         self.root_dir.join("src")
     }
+
+    fn binary_path(&self) -> PathBuf {
+        let profile_dir = match self.settings.profile {
+            RustProfile::Release => "release",
+            RustProfile::Debug => "debug",
+        };
+        self.root_dir.join("target").join(profile_dir).join(format!(
+            "aocsuite-solution-rust{}",
+            std::env::consts::EXE_SUFFIX
+        ))
+    }
+
+    /// Writes the configured settings into the crate's files, keeping everything else in them,
+    /// including the user's own `[profile.release]` keys. Values written by an earlier run are
+    /// removed again once their setting is unset, unless they were edited since.
+    fn apply_settings(&self) -> AocLanguageResult<()> {
+        let applied_path = self.root_dir.join(APPLIED_SETTINGS);
+        let previous = match std::fs::read(&applied_path) {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                AppliedSettings::default()
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let release = &self.settings.release;
+        let current = AppliedSettings {
+            toolchain: self.settings.toolchain.clone(),
+            lto: release.lto().map(str::to_owned),
+            codegen_units: release.codegen_units(),
+            target_cpu_native: release.target_cpu_native(),
+        };
+
+        // Files without anything to write or take back are not parsed at all.
+        let cargo_path = self.root_dir.join("Cargo.toml");
+        let release_keys = [&previous, &current]
+            .iter()
+            .any(|applied| applied.lto.is_some() || applied.codegen_units.is_some());
+        if self.settings.edition.is_some() || release_keys {
+            edit_toml(&cargo_path, |manifest| {
+                if let Some(edition) = &self.settings.edition {
+                    manifest
+                        .get_mut("package")
+                        .and_then(Item::as_table_like_mut)
+                        .ok_or_else(|| not_a_table(&cargo_path, "package"))?
+                        .insert("edition", value(edition.as_str()));
+                }
+                let lto = |lto: &str| match lto {
+                    "true" => Value::from(true),
+                    "false" => Value::from(false),
+                    lto => Value::from(lto),
+                };
+                sync_key(
+                    &cargo_path,
+                    manifest,
+                    &["profile", "release"],
+                    "lto",
+                    previous.lto.as_deref().map(lto),
+                    current.lto.as_deref().map(lto),
+                )?;
+                let codegen_units = |units: u32| Value::from(i64::from(units));
+                sync_key(
+                    &cargo_path,
+                    manifest,
+                    &["profile", "release"],
+                    "codegen-units",
+                    previous.codegen_units.map(codegen_units),
+                    current.codegen_units.map(codegen_units),
+                )
+            })?;
+        }
+
+        let config_path = self.root_dir.join(".cargo").join("config.toml");
+        if current.target_cpu_native {
+            edit_toml(&config_path, |config| {
+                let build = implicit_table(config.as_table_mut(), "build", &config_path)?;
+                let rustflags = build
+                    .entry("rustflags")
+                    .or_insert_with(|| value(Array::new()));
+                if let Some(rustflags) = rustflags.as_array_mut() {
+                    if native_flag_positions(rustflags).is_empty() {
+                        rustflags.push("-C");
+                        rustflags.push("target-cpu=native");
+                    }
+                }
+                Ok(())
+            })?;
+        } else if previous.target_cpu_native {
+            edit_toml(&config_path, |config| {
+                let Some(build) = config.get_mut("build").and_then(Item::as_table_mut) else {
+                    return Ok(());
+                };
+                if let Some(rustflags) = build.get_mut("rustflags").and_then(Item::as_array_mut) {
+                    let positions = native_flag_positions(rustflags);
+                    if let Some(&first) = positions.first() {
+                        // The next flag takes the removed flag's place, including its spacing.
+                        let prefix = rustflags.get(first).and_then(|flag| flag.decor().prefix());
+                        let prefix = prefix.cloned();
+                        for position in positions.into_iter().rev() {
+                            rustflags.remove(position);
+                        }
+                        if let (Some(flag), Some(prefix)) = (rustflags.get_mut(first), prefix) {
+                            flag.decor_mut().set_prefix(prefix);
+                        }
+                    }
+                    if rustflags.is_empty() {
+                        build.remove("rustflags");
+                    }
+                }
+                if build.is_empty() {
+                    config.remove("build");
+                }
+                Ok(())
+            })?;
+        }
+
+        let toolchain_path = self.root_dir.join("rust-toolchain.toml");
+        if previous.toolchain.is_some() || current.toolchain.is_some() {
+            edit_toml(&toolchain_path, |file| {
+                sync_key(
+                    &toolchain_path,
+                    file,
+                    &["toolchain"],
+                    "channel",
+                    previous.toolchain.as_deref().map(Value::from),
+                    current.toolchain.as_deref().map(Value::from),
+                )
+            })?;
+        }
+
+        if current != previous {
+            atomic_write(&applied_path, &serde_json::to_vec(&current)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// File in the crate recording the settings last written into its files.
+const APPLIED_SETTINGS: &str = ".aocsuite-rust-settings.json";
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+struct AppliedSettings {
+    toolchain: Option<String>,
+    lto: Option<String>,
+    codegen_units: Option<u32>,
+    target_cpu_native: bool,
+}
+
+/// Indices in `rustflags` of the `-C target-cpu=native` flag, written as one or two items.
+fn native_flag_positions(rustflags: &Array) -> Vec<usize> {
+    let flags: Vec<_> = rustflags.iter().map(Value::as_str).collect();
+    for (index, flag) in flags.iter().enumerate() {
+        match flag {
+            Some("-Ctarget-cpu=native") => return vec![index],
+            Some("-C") if flags.get(index + 1) == Some(&Some("target-cpu=native")) => {
+                return vec![index, index + 1];
+            }
+            _ => {}
+        }
+    }
+    Vec::new()
+}
+
+/// Sets `key` in the table reached through `tables` to `desired`. Once `desired` is unset, the
+/// `previous` value is removed if the file still holds it, along with tables left empty.
+fn sync_key(
+    path: &Path,
+    document: &mut DocumentMut,
+    tables: &[&str],
+    key: &str,
+    previous: Option<Value>,
+    desired: Option<Value>,
+) -> AocLanguageResult<()> {
+    match (desired, previous) {
+        (Some(desired), _) => {
+            let mut table = document.as_table_mut();
+            for name in tables {
+                table = implicit_table(table, name, path)?;
+            }
+            table[key] = Item::Value(desired);
+        }
+        (None, Some(previous)) => {
+            remove_unchanged(document.as_table_mut(), tables, key, &previous);
+        }
+        (None, None) => {}
+    }
+    Ok(())
+}
+
+/// Removes `key` when it still holds `previous`, returning whether it did.
+fn remove_unchanged(table: &mut Table, tables: &[&str], key: &str, previous: &Value) -> bool {
+    let Some((name, rest)) = tables.split_first() else {
+        let unchanged = table
+            .get(key)
+            .and_then(Item::as_value)
+            .is_some_and(|current| same_value(current, previous));
+        if unchanged {
+            table.remove(key);
+        }
+        return unchanged;
+    };
+    let Some(child) = table.get_mut(name).and_then(Item::as_table_mut) else {
+        return false;
+    };
+    let removed = remove_unchanged(child, rest, key, previous);
+    if removed && child.is_empty() {
+        table.remove(name);
+    }
+    removed
+}
+
+/// Compares values ignoring their formatting and comments.
+fn same_value(left: &Value, right: &Value) -> bool {
+    left.clone().decorated("", "").to_string() == right.clone().decorated("", "").to_string()
+}
+
+fn not_a_table(path: &Path, key: &str) -> AocLanguageError {
+    AocLanguageError::ProjectFile {
+        path: path.to_path_buf(),
+        message: format!("`{key}` is not a table"),
+    }
+}
+
+/// The table under `key`, created without a header of its own when only its subtables or
+/// keys are written, e.g. `[profile.release]` without an empty `[profile]`.
+fn implicit_table<'table>(
+    table: &'table mut Table,
+    key: &str,
+    path: &Path,
+) -> AocLanguageResult<&'table mut Table> {
+    table
+        .entry(key)
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .ok_or_else(|| not_a_table(path, key))
 }
 
-fn cargo_contents() -> String {
-    r#"[package]
+/// Applies `edit` to a TOML file, preserving its formatting and comments, and writes it back
+/// only when something changed. A missing file is edited as an empty document, and a file left
+/// empty is removed.
+fn edit_toml(
+    path: &Path,
+    edit: impl FnOnce(&mut DocumentMut) -> AocLanguageResult<()>,
+) -> AocLanguageResult<()> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+    let mut document: DocumentMut =
+        contents.parse().map_err(
+            |error: toml_edit::TomlError| AocLanguageError::ProjectFile {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            },
+        )?;
+    edit(&mut document)?;
+
+    let updated = document.to_string();
+    if updated == contents {
+        return Ok(());
+    }
+    if updated.trim().is_empty() {
+        std::fs::remove_file(path)?;
+    } else {
+        std::fs::create_dir_all(path.parent().expect("project file has a parent"))?;
+        atomic_write(path, updated.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn cargo_contents(edition: &str) -> String {
+    format!(
+        r#"[package]
 name = "aocsuite-solution-rust"
 version = "0.1.0"
-edition = "2024"
+edition = "{edition}"
 
 [dependencies]
 serde_json="1.0.140"
-serde = { version = "1.0.219", features = ["derive"]}
+serde = {{ version = "1.0.219", features = ["derive"]}}
 "#
-    .to_string()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use aocsuite_utils::PartSelection;
+
+    use super::RustRunner;
+    use crate::{
+        test_support::{test_root, ScriptedExecutor, SYSTEM_EXECUTOR},
+        traits::Solver,
+        AocLanguageError, RunOptions, RustSettings,
+    };
+
+    #[test]
+    fn rust_runtime_migration_replaces_only_owned_files() {
+        let root = test_root("rust-migration");
+        let runner = RustRunner::new(root.clone(), RustSettings::default(), &SYSTEM_EXECUTOR);
+        let main = root.join("src/main.rs");
+        let cargo = root.join("Cargo.toml");
+        let solution = root.join("src/solution.rs");
+        let library = root.join("src/helpers.rs");
+        let template = root.join("template.rs");
+        let puzzle = root.join("solutions/year2024_day1.rs");
+        fs::create_dir_all(main.parent().expect("main parent")).expect("create source directory");
+        fs::create_dir_all(puzzle.parent().expect("puzzle parent"))
+            .expect("create puzzle directory");
+        for path in [&main, &cargo, &solution, &library, &template, &puzzle] {
+            fs::write(path, "legacy or user content").expect("write legacy fixture");
+        }
+
+        runner
+            .migrate_runtime()
+            .expect("migrate legacy Rust runtime");
+
+        assert_eq!(
+            fs::read_to_string(&main).expect("read main"),
+            runner.main_contents()
+        );
+        for path in [&cargo, &solution, &library, &template, &puzzle] {
+            assert_eq!(
+                fs::read_to_string(path).expect("read preserved user file"),
+                "legacy or user content"
+            );
+        }
+        assert!(root.join(".aocsuite-runtime.json").exists());
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
+
+    #[test]
+    fn rust_settings_are_merged_into_the_crate_files() {
+        let root = test_root("rust-settings");
+        let cargo = root.join("Cargo.toml");
+        fs::create_dir_all(&root).expect("create crate directory");
+        fs::write(
+            &cargo,
+            "[package]\nname = \"aocsuite-solution-rust\"\nedition = \"2024\"\n\n\
+             [profile.release]\ndebug = true # keep symbols\n",
+        )
+        .expect("write user manifest");
+        let executor = ScriptedExecutor::recording();
+        let runner = RustRunner::new(
+            root.clone(),
+            RustSettings {
+                toolchain: Some("nightly".to_owned()),
+                edition: Some("2021".to_owned()),
+                profile: aocsuite_utils::RustProfile::Debug,
+                release: "lto=fat codegen-units=1 target-cpu=native"
+                    .parse()
+                    .expect("release options"),
+            },
+            &executor,
+        );
+
+        runner.migrate_runtime().expect("apply settings");
+        runner.migrate_runtime().expect("apply settings again");
+
+        assert_eq!(
+            fs::read_to_string(&cargo).unwrap(),
+            "[package]\nname = \"aocsuite-solution-rust\"\nedition = \"2021\"\n\n\
+             [profile.release]\ndebug = true # keep symbols\nlto = \"fat\"\ncodegen-units = 1\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("rust-toolchain.toml")).unwrap(),
+            "[toolchain]\nchannel = \"nightly\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(".cargo/config.toml")).unwrap(),
+            "[build]\nrustflags = [\"-C\", \"target-cpu=native\"]\n"
+        );
+
+        runner.compile(&RunOptions::default()).expect("build");
+        let input = root.join("input.txt");
+        runner
+            .run(
+                PartSelection::One,
+                &input,
+                &root.join("result.json"),
+                &RunOptions::default(),
+            )
+            .expect("run");
+        let requests = executor.requests();
+        assert_eq!(
+            requests[0].args,
+            ["build", "--config", "profile.dev.overflow-checks=true"]
+        );
+        assert!(PathBuf::from(&requests[1].program).starts_with(root.join("target/debug")));
+
+        // Unset settings take back what they wrote, but not values the user has changed since.
+        fs::write(
+            root.join(".cargo/config.toml"),
+            "[build]\nrustflags = [\"-C\", \"target-cpu=native\", \"-Dwarnings\"]\n",
+        )
+        .expect("edit cargo config");
+        let manifest = fs::read_to_string(&cargo)
+            .unwrap()
+            .replace("\"fat\"", "\"thin\"");
+        fs::write(&cargo, manifest).expect("edit manifest");
+        RustRunner::new(root.clone(), RustSettings::default(), &executor)
+            .migrate_runtime()
+            .expect("unset settings");
+        assert_eq!(
+            fs::read_to_string(&cargo).unwrap(),
+            "[package]\nname = \"aocsuite-solution-rust\"\nedition = \"2021\"\n\n\
+             [profile.release]\ndebug = true # keep symbols\nlto = \"thin\"\n"
+        );
+        assert!(!root.join("rust-toolchain.toml").exists());
+        assert_eq!(
+            fs::read_to_string(root.join(".cargo/config.toml")).unwrap(),
+            "[build]\nrustflags = [\"-Dwarnings\"]\n"
+        );
+
+        fs::write(&cargo, "package = \"aocsuite-solution-rust\"\n").expect("break manifest");
+        let edition = RustSettings {
+            edition: Some("2024".to_owned()),
+            ..RustSettings::default()
+        };
+        assert!(matches!(
+            RustRunner::new(root.clone(), edition, &executor).migrate_runtime(),
+            Err(AocLanguageError::ProjectFile { .. })
+        ));
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
 }
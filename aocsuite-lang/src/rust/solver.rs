@@ -1,17 +1,24 @@
+use crate::template::named_template_path;
 use crate::traits::Solver;
-use crate::utils::{AocLanguageResult, SolverFile};
-use aocsuite_utils::{atomic_write, execute_command, CommandRequest};
+use crate::utils::{AocLanguageResult, RunOptions, SolverFile};
+use aocsuite_utils::{atomic_write, execute_command, CommandRequest, RustProfile};
 
 use super::{cargo_contents, RustRunner};
 
 impl Solver for RustRunner<'_> {
-    fn compile(&self) -> AocLanguageResult<Option<std::process::Output>> {
+    fn compile(&self, options: &RunOptions) -> AocLanguageResult<Option<std::process::Output>> {
+        let request = CommandRequest::new("cargo").arg("build");
+        let request = match self.settings.profile {
+            RustProfile::Release => request.arg("--release"),
+            // Keep overflow checks even if the crate's dev profile turned them off.
+            RustProfile::Debug => request
+                .arg("--config")
+                .arg("profile.dev.overflow-checks=true"),
+        };
+
         Ok(Some(execute_command(
             self.executor,
-            CommandRequest::new("cargo")
-                .arg("build")
-                .arg("--release")
-                .current_dir(&self.root_dir),
+            options.supervise_build(request.current_dir(&self.root_dir)),
         )?))
     }
 
@@ -20,16 +27,20 @@ impl Solver for RustRunner<'_> {
         part: aocsuite_utils::PartSelection,
         input: &std::path::Path,
         output: &std::path::Path,
+        options: &RunOptions,
     ) -> AocLanguageResult<std::process::Output> {
-        let binary_path = release_binary_path(&self.root_dir);
+        let binary_path = self.binary_path();
 
         Ok(execute_command(
             self.executor,
-            CommandRequest::new(binary_path)
-                .arg(input)
-                .arg(output)
-                .arg(part.to_string())
-                .current_dir(&self.root_dir),
+            options.supervise(
+                CommandRequest::new(binary_path)
+                    .arg(input)
+                    .arg(output)
+                    .arg(part.to_string())
+                    .args(options.bench.map(|iterations| iterations.to_string()))
+                    .current_dir(&self.root_dir),
+            ),
         )?)
     }
 
@@ -37,8 +48,10 @@ impl Solver for RustRunner<'_> {
         std::fs::create_dir_all(&self.root_dir)?;
         let cargo_path = self.root_dir.join("Cargo.toml");
         if !cargo_path.exists() {
-            atomic_write(&cargo_path, cargo_contents().as_bytes())?;
+            let edition = self.settings.edition.as_deref().unwrap_or("2024");
+            atomic_write(&cargo_path, cargo_contents(edition).as_bytes())?;
         }
+        self.apply_settings()?;
 
         crate::runtime::migrate_runtime(
             &self.root_dir,
@@ -59,13 +72,21 @@ impl Solver for RustRunner<'_> {
     fn solver_file_path(&self, file: &SolverFile) -> std::path::PathBuf {
         match file {
             SolverFile::Entrypoint => self.src_dir().join("main.rs"),
-            SolverFile::SolutionTemplate => self.root_dir.join("template.rs"),
+            SolverFile::SolutionTemplate(template) if template.is_default() => {
+                self.root_dir.join("template.rs")
+            }
+            SolverFile::SolutionTemplate(template) => {
+                named_template_path(&self.root_dir, template, "rs")
+            }
             SolverFile::ActiveSolution(_) => self.src_dir().join("solution.rs"),
-            SolverFile::PuzzleSolution(puzzle) => {
-                self.root_dir.join("solutions").join(format!("{puzzle}.rs"))
+            SolverFile::PuzzleSolution(solution) => {
+                self.solutions_dir().join(format!("{solution}.rs"))
             }
         }
     }
+    fn solutions_dir(&self) -> std::path::PathBuf {
+        self.root_dir.join("solutions")
+    }
     fn template_contents(&self) -> String {
         "/// Implement your solution here
 
@@ -82,7 +103,7 @@ pub fn part2(input: &str) -> String {
     }
     fn main_contents(&self) -> String {
         r#"use serde::{Deserialize, Serialize};
-use std::{env, fs, path::Path, process, time::Instant};
+use std::{env, fs, hint, path::Path, process, time::Instant};
 mod solution;
 use solution::{part1, part2};
 
@@ -104,6 +125,17 @@ fn release_binary_path(root_dir: &std::path::Path) -> std::path::PathBuf {
 struct PartResult {
     answer: String,
     runtime_ms: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bench: Option<BenchStats>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BenchStats {
+    iterations: u32,
+    min_ns: u64,
+    mean_ns: u64,
+    median_ns: u64,
+    stddev_ns: u64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -112,26 +144,78 @@ struct OutputJson {
     part2: Option<PartResult>,
 }
 
-fn run_part<F>(part_fn: F, input: &str) -> PartResult
-where
-    F: FnOnce(&str) -> String,
-{
+fn run_part(part_fn: &dyn Fn(&str) -> String, input: &str) -> PartResult {
     let start = Instant::now();
     let answer = part_fn(input);
     let runtime_ms = start.elapsed().as_millis();
 
-    PartResult { answer, runtime_ms }
+    PartResult {
+        answer,
+        runtime_ms,
+        bench: None,
+    }
+}
+
+/// Untimed runs before benchmarking: a tenth of the iterations, between one and ten.
+fn warmup_runs(iterations: u32) -> u32 {
+    (iterations / 10).clamp(1, 10)
+}
+
+fn bench_part(part_fn: &dyn Fn(&str) -> String, input: &str, iterations: u32) -> PartResult {
+    // The first warmup run provides the reported answer.
+    let answer = part_fn(input);
+    for _ in 1..warmup_runs(iterations) {
+        hint::black_box(part_fn(hint::black_box(input)));
+    }
+
+    let mut samples: Vec<u64> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            hint::black_box(part_fn(hint::black_box(input)));
+            start.elapsed().as_nanos() as u64
+        })
+        .collect();
+    samples.sort_unstable();
+
+    let count = samples.len() as f64;
+    let mean = samples.iter().map(|&sample| sample as f64).sum::<f64>() / count;
+    let variance = samples
+        .iter()
+        .map(|&sample| (sample as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    let middle = samples.len() / 2;
+    let median_ns = if samples.len() % 2 == 0 {
+        samples[middle - 1] + (samples[middle] - samples[middle - 1]) / 2
+    } else {
+        samples[middle]
+    };
+
+    PartResult {
+        answer,
+        runtime_ms: u128::from(median_ns) / 1_000_000,
+        bench: Some(BenchStats {
+            iterations,
+            min_ns: samples[0],
+            mean_ns: mean.round() as u64,
+            median_ns,
+            stddev_ns: variance.sqrt().round() as u64,
+        }),
+    }
 }
 
 fn run<F1, F2>(part1_fn: F1, part2_fn: F2)
 where
-    F1: FnOnce(&str) -> String,
-    F2: FnOnce(&str) -> String,
+    F1: Fn(&str) -> String,
+    F2: Fn(&str) -> String,
 {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() < 3 || args.len() > 4 {
-        eprintln!("Usage: {} <input_file> <output_file> [1|2|both]", args[0]);
+    if args.len() < 3 || args.len() > 5 {
+        eprintln!(
+            "Usage: {} <input_file> <output_file> [1|2|both] [bench_iterations]",
+            args[0]
+        );
         process::exit(1);
     }
 
@@ -146,6 +230,14 @@ where
             process::exit(1);
         }
     };
+    let iterations = match args.get(4).map(|s| s.parse::<u32>()) {
+        None => None,
+        Some(Ok(iterations)) if iterations > 0 => Some(iterations),
+        Some(_) => {
+            eprintln!("Invalid bench iterations '{}'. Use a positive number", args[4]);
+            process::exit(1);
+        }
+    };
 
     // Read input file
     let input = fs::read_to_string(input_file).unwrap_or_else(|e| {
@@ -166,12 +258,16 @@ where
         part2: None,
     };
 
+    let measure = |part_fn: &dyn Fn(&str) -> String| match iterations {
+        Some(iterations) => bench_part(part_fn, &input, iterations),
+        None => run_part(part_fn, &input),
+    };
     match part {
-        Part::One => output.part1 = Some(run_part(part1_fn, &input)),
-        Part::Two => output.part2 = Some(run_part(part2_fn, &input)),
+        Part::One => output.part1 = Some(measure(&part1_fn)),
+        Part::Two => output.part2 = Some(measure(&part2_fn)),
         Part::Both => {
-            output.part1 = Some(run_part(part1_fn, &input));
-            output.part2 = Some(run_part(part2_fn, &input));
+            output.part1 = Some(measure(&part1_fn));
+            output.part2 = Some(measure(&part2_fn));
         }
     }
 
@@ -198,10 +294,3 @@ fn main() {
         .to_string()
     }
 }
-
-fn release_binary_path(root_dir: &std::path::Path) -> std::path::PathBuf {
-    root_dir.join("target").join("release").join(format!(
-        "aocsuite-solution-rust{}",
-        std::env::consts::EXE_SUFFIX
-    ))
-}
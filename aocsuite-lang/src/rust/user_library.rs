@@ -11,7 +11,19 @@ impl LibManager for RustRunner<'_> {
     fn lib_dir(&self) -> PathBuf {
         self.src_dir()
     }
+    fn reserved_names(&self) -> Vec<&str> {
+        RESERVED_NAMES.to_vec()
+    }
     fn file_extention(&self) -> String {
         "rs".to_string()
     }
 }
+
+const RESERVED_NAMES: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "union", "unsafe", "use", "virtual", "where", "while", "yield", "main", "solution",
+    "template",
+];
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use aocsuite_config::{AocConfigError, AocConfigResult, ConfigKey, ConfigValue, Configuration};
+use aocsuite_utils::{
+    CppFlags, PythonBackend, RustProfile, RustReleaseOptions, TypeScriptRuntime,
+    DEFAULT_CPP_COMPILER,
+};
+
+/// Toolchain choices of the built-in runners, read from the configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageSettings {
+    pub cpp_compiler: String,
+    pub cpp_flags: CppFlags,
+    pub typescript_runtime: TypeScriptRuntime,
+    pub python_backend: PythonBackend,
+    /// Interpreter version for new virtual environments; `None` uses the default interpreter.
+    pub python_version: Option<String>,
+    pub rust: RustSettings,
+    /// Directory of custom language manifests, see [`Configuration::manifest_dir`].
+    pub manifest_dir: PathBuf,
+}
+
+/// How the Rust solution crate is built. Configured values are written into its `Cargo.toml`,
+/// `rust-toolchain.toml` and `.cargo/config.toml`; anything else in those files is left alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RustSettings {
+    /// Toolchain channel pinned in `rust-toolchain.toml`; `None` leaves the file untouched.
+    pub toolchain: Option<String>,
+    /// Edition set in `Cargo.toml`; `None` keeps the crate's edition.
+    pub edition: Option<String>,
+    pub profile: RustProfile,
+    pub release: RustReleaseOptions,
+}
+
+impl LanguageSettings {
+    pub fn from_config(config: &Configuration) -> AocConfigResult<Self> {
+        Ok(Self {
+            cpp_compiler: config.get(ConfigKey::CppCompiler)?,
+            cpp_flags: config.get(ConfigKey::CppFlags)?,
+            typescript_runtime: config.get(ConfigKey::TypeScriptRuntime)?,
+            python_backend: config.get(ConfigKey::PythonBackend)?,
+            python_version: optional(config, ConfigKey::PythonVersion)?,
+            rust: RustSettings {
+                toolchain: optional(config, ConfigKey::RustToolchain)?,
+                edition: optional(config, ConfigKey::RustEdition)?,
+                profile: config.get(ConfigKey::RustProfile)?,
+                release: config.get(ConfigKey::RustReleaseOptions)?,
+            },
+            manifest_dir: config.manifest_dir().to_path_buf(),
+        })
+    }
+}
+
+fn optional<T>(config: &Configuration, key: ConfigKey) -> AocConfigResult<Option<T>>
+where
+    T: TryFrom<ConfigValue, Error = AocConfigError>,
+{
+    match config.get(key) {
+        Ok(value) => Ok(Some(value)),
+        Err(AocConfigError::NotFound { .. }) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+impl Default for LanguageSettings {
+    fn default() -> Self {
+        Self {
+            cpp_compiler: DEFAULT_CPP_COMPILER.to_owned(),
+            cpp_flags: CppFlags::default(),
+            typescript_runtime: TypeScriptRuntime::default(),
+            python_backend: PythonBackend::default(),
+            python_version: None,
+            rust: RustSettings::default(),
+            manifest_dir: PathBuf::new(),
+        }
+    }
+}
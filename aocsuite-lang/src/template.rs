@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use aocsuite_utils::{PuzzleId, TemplateName, AOC_BASE_URL};
+
+/// Values of the `{{day}}`, `{{year}}`, `{{title}}` and `{{url}}` placeholders, expanded when a
+/// puzzle solution is first created from a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateVariables {
+    puzzle: PuzzleId,
+    title: Option<String>,
+}
+
+impl TemplateVariables {
+    pub fn new(puzzle: PuzzleId) -> Self {
+        Self {
+            puzzle,
+            title: None,
+        }
+    }
+
+    /// Sets the puzzle title; without one `{{title}}` expands to `Day N`.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub(crate) fn expand(&self, template: &str) -> String {
+        let title = self
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Day {}", self.puzzle.day));
+        let url = format!(
+            "{AOC_BASE_URL}/{}/day/{}",
+            self.puzzle.year, self.puzzle.day
+        );
+        template
+            .replace("{{day}}", &self.puzzle.day.to_string())
+            .replace("{{year}}", &self.puzzle.year.to_string())
+            .replace("{{title}}", &title)
+            .replace("{{url}}", &url)
+    }
+}
+
+/// Named templates live in `templates/` so that no runtime builds them; the default template
+/// keeps the runtime's own path.
+pub(crate) fn templates_dir(root: &Path) -> PathBuf {
+    root.join("templates")
+}
+
+pub(crate) fn named_template_path(
+    root: &Path,
+    template: &TemplateName,
+    extension: &str,
+) -> PathBuf {
+    templates_dir(root).join(format!("{template}.{extension}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aocsuite_utils::{PuzzleDay, PuzzleId, PuzzleYear};
+
+    use super::TemplateVariables;
+
+    #[test]
+    fn placeholders_expand_to_the_puzzle_details() {
+        let puzzle = PuzzleId::new(PuzzleDay::new(5).unwrap(), PuzzleYear::new(2024).unwrap());
+        let template = "# {{year}} day {{day}}: {{title}}\n# {{url}}\n{{unknown}}\n";
+
+        assert_eq!(
+            TemplateVariables::new(puzzle)
+                .with_title("Print Queue")
+                .expand(template),
+            "# 2024 day 5: Print Queue\n# https://adventofcode.com/2024/day/5\n{{unknown}}\n"
+        );
+        assert!(TemplateVariables::new(puzzle)
+            .expand(template)
+            .starts_with("# 2024 day 5: Day 5\n"));
+    }
+}
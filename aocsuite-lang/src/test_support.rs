@@ -0,0 +1,85 @@
+//! Fakes shared by the language tests, so solvers run without their toolchains.
+
+use std::{
+    io,
+    path::PathBuf,
+    process::{self, Output},
+    sync::{Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aocsuite_utils::{CommandExecutor, CommandRequest, SystemCommandExecutor};
+
+pub(crate) static SYSTEM_EXECUTOR: SystemCommandExecutor = SystemCommandExecutor;
+
+pub(crate) fn test_root(language: &str) -> PathBuf {
+    let unique = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is after the Unix epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!(
+        "aocsuite-lang-{language}-{}-{unique}",
+        process::id()
+    ))
+}
+
+/// Records every request and answers it with `respond` instead of running it.
+pub(crate) struct ScriptedExecutor<F> {
+    requests: Mutex<Vec<CommandRequest>>,
+    respond: F,
+}
+
+impl<F> ScriptedExecutor<F>
+where
+    F: Fn(&CommandRequest) -> io::Result<Output> + Send + Sync,
+{
+    pub(crate) fn new(respond: F) -> Self {
+        Self {
+            requests: Mutex::new(Vec::new()),
+            respond,
+        }
+    }
+
+    pub(crate) fn requests(&self) -> MutexGuard<'_, Vec<CommandRequest>> {
+        self.requests.lock().unwrap()
+    }
+}
+
+impl ScriptedExecutor<fn(&CommandRequest) -> io::Result<Output>> {
+    /// Answers every request with [`successful_output`].
+    pub(crate) fn recording() -> Self {
+        Self::new(|_| Ok(successful_output()))
+    }
+}
+
+impl<F> CommandExecutor for ScriptedExecutor<F>
+where
+    F: Fn(&CommandRequest) -> io::Result<Output> + Send + Sync,
+{
+    fn execute(&self, request: &CommandRequest) -> io::Result<Output> {
+        self.requests().push(request.clone());
+        (self.respond)(request)
+    }
+}
+
+#[cfg(unix)]
+pub(crate) fn successful_output() -> Output {
+    use std::os::unix::process::ExitStatusExt;
+
+    Output {
+        status: std::process::ExitStatus::from_raw(0),
+        stdout: b"command output".to_vec(),
+        stderr: Vec::new(),
+    }
+}
+
+#[cfg(windows)]
+pub(crate) fn successful_output() -> Output {
+    use std::os::windows::process::ExitStatusExt;
+
+    Output {
+        status: std::process::ExitStatus::from_raw(0),
+        stdout: b"command output".to_vec(),
+        stderr: Vec::new(),
+    }
+}
@@ -3,23 +3,31 @@ use std::{
     process::Output,
 };
 
-use aocsuite_utils::PartSelection;
+use aocsuite_utils::{PartSelection, SolutionId, TemplateName};
 
-use crate::utils::{symlink_file, AocLanguageResult, SolverFile};
+use crate::{
+    template::TemplateVariables,
+    utils::{symlink_file, AocLanguageError, AocLanguageResult, RunOptions, SolverFile},
+};
 
 pub trait LanguageHandler: Solver + DepManager + LibManager {}
 impl<T> LanguageHandler for T where T: Solver + DepManager + LibManager {}
 
 pub trait Solver {
-    fn compile(&self) -> AocLanguageResult<Option<Output>>;
+    /// Builds the active solution, if the language needs a build step.
+    fn compile(&self, options: &RunOptions) -> AocLanguageResult<Option<Output>>;
+    /// Runs the active solution, timing each part `options.bench` times after a warmup when
+    /// given.
     fn run(
         &self,
         part: PartSelection,
         input: &Path,
         output: &std::path::Path,
+        options: &RunOptions,
     ) -> AocLanguageResult<Output>;
 
     fn solver_file_path(&self, file: &SolverFile) -> PathBuf;
+    fn solutions_dir(&self) -> PathBuf;
     fn migrate_runtime(&self) -> AocLanguageResult<()>;
     fn main_contents(&self) -> String;
     fn template_contents(&self) -> String;
@@ -28,11 +36,9 @@ pub trait Solver {
     fn ensure_solver_file(&self, file: &SolverFile) -> AocLanguageResult<PathBuf> {
         let path = self.solver_file_path(file);
         match file {
-            SolverFile::PuzzleSolution(_) => {
+            SolverFile::PuzzleSolution(solution) => {
                 if !path.exists() {
-                    std::fs::create_dir_all(path.parent().expect("solve file is not root"))?;
-                    let template_path = self.ensure_solver_file(&SolverFile::SolutionTemplate)?;
-                    std::fs::copy(template_path, &path)?;
+                    self.create_solution(solution, None, &TemplateVariables::new(solution.puzzle))?;
                 }
             }
             SolverFile::Entrypoint => {
@@ -41,25 +47,76 @@ pub trait Solver {
                     std::fs::write(&path, self.main_contents())?;
                 }
             }
-            SolverFile::SolutionTemplate => {
+            SolverFile::SolutionTemplate(template) => {
                 if !path.exists() {
                     std::fs::create_dir_all(path.parent().expect("solve file is not root"))?;
-                    std::fs::write(&path, self.template_contents())?;
+                    if template.is_default() {
+                        std::fs::write(&path, self.template_contents())?;
+                    } else {
+                        // A new named template starts as a copy of the default one.
+                        let default_path = self.ensure_solver_file(
+                            &SolverFile::SolutionTemplate(TemplateName::default()),
+                        )?;
+                        std::fs::copy(default_path, &path)?;
+                    }
                 }
             }
-            SolverFile::ActiveSolution(puzzle) => {
-                let linked_path = self.ensure_solver_file(&SolverFile::PuzzleSolution(*puzzle))?;
+            SolverFile::ActiveSolution(solution) => {
+                let linked_path =
+                    self.ensure_solver_file(&SolverFile::PuzzleSolution(*solution))?;
                 std::fs::create_dir_all(path.parent().expect("solve file is not root"))?;
                 symlink_file(&linked_path, &path)?;
             }
         }
         Ok(path)
     }
+
+    /// Writes a new solution file, replacing any existing one. A variant starts as a copy of the
+    /// puzzle's main solution unless a template is given; otherwise the template is expanded.
+    fn create_solution(
+        &self,
+        solution: &SolutionId,
+        template: Option<&TemplateName>,
+        variables: &TemplateVariables,
+    ) -> AocLanguageResult<PathBuf> {
+        let path = self.solver_file_path(&SolverFile::PuzzleSolution(*solution));
+        std::fs::create_dir_all(path.parent().expect("solve file is not root"))?;
+        let main_path = self.solver_file_path(&SolverFile::PuzzleSolution(SolutionId::from(
+            solution.puzzle,
+        )));
+        if template.is_none() && solution.variant.is_some() && main_path.is_file() {
+            std::fs::copy(main_path, &path)?;
+            return Ok(path);
+        }
+
+        let template = template.cloned().unwrap_or_default();
+        let template_file = SolverFile::SolutionTemplate(template.clone());
+        // Only the default template is created on demand; a named one must exist.
+        let template_path = if template.is_default() {
+            self.ensure_solver_file(&template_file)?
+        } else {
+            let template_path = self.solver_file_path(&template_file);
+            if !template_path.is_file() {
+                return Err(AocLanguageError::TemplateNotFound(template));
+            }
+            template_path
+        };
+        let contents = std::fs::read_to_string(&template_path).map_err(|source| {
+            AocLanguageError::TemplateRead {
+                source,
+                path: template_path.display().to_string(),
+            }
+        })?;
+        std::fs::write(&path, variables.expand(&contents))?;
+        Ok(path)
+    }
 }
 
 pub trait LibManager {
     fn get_lib_path(&self, lib_name: &str) -> PathBuf;
     fn lib_dir(&self) -> PathBuf;
+    /// Names a user library may not take, compared case-insensitively.
+    fn reserved_names(&self) -> Vec<&str>;
     fn file_extention(&self) -> String;
 }
 
@@ -0,0 +1,70 @@
+use std::{collections::BTreeMap, process::Output};
+
+use crate::{
+    traits::{DepManager, Solver},
+    AocLanguageResult,
+};
+
+use super::TypeScriptRunner;
+use aocsuite_utils::{execute_command, TypeScriptRuntime};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageJson {
+    #[serde(default)]
+    dependencies: BTreeMap<String, String>,
+}
+
+impl DepManager for TypeScriptRunner<'_> {
+    fn setup_env(&self) -> AocLanguageResult<Option<Output>> {
+        self.migrate_runtime()?;
+        if self.root_dir.join("node_modules").exists() {
+            return Ok(None);
+        }
+
+        let output = execute_command(self.executor, self.package_manager().arg("install"))?;
+        Ok(Some(output))
+    }
+    fn add_package(&self, package: &str) -> AocLanguageResult<()> {
+        let request = match self.runtime {
+            TypeScriptRuntime::Node => self.package_manager().arg("install").arg(package),
+            TypeScriptRuntime::Bun => self.package_manager().arg("add").arg(package),
+            TypeScriptRuntime::Deno => self
+                .package_manager()
+                .arg("add")
+                .arg(format!("npm:{package}")),
+        };
+        execute_command(self.executor, request)?;
+        Ok(())
+    }
+    fn clean_env(&self) -> AocLanguageResult<()> {
+        match std::fs::remove_dir_all(self.root_dir.join("node_modules")) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    // The runtime's own tooling lives in `devDependencies`; only user packages are listed.
+    fn list_packages(&self) -> AocLanguageResult<Vec<String>> {
+        let contents = match std::fs::read(self.root_dir.join("package.json")) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+        let package_json: PackageJson = serde_json::from_slice(&contents)?;
+
+        Ok(package_json.dependencies.into_keys().collect())
+    }
+    fn remove_packages(&self, package: &str) -> AocLanguageResult<()> {
+        let request = match self.runtime {
+            TypeScriptRuntime::Node => self.package_manager().arg("uninstall").arg(package),
+            TypeScriptRuntime::Bun | TypeScriptRuntime::Deno => {
+                self.package_manager().arg("remove").arg(package)
+            }
+        };
+        execute_command(self.executor, request)?;
+        Ok(())
+    }
+}
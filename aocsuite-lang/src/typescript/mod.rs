@@ -0,0 +1,168 @@
+mod dependencies;
+mod solver;
+mod user_library;
+use std::path::PathBuf;
+
+use aocsuite_utils::{CommandExecutor, CommandRequest, TypeScriptRuntime};
+
+pub struct TypeScriptRunner<'executor> {
+    root_dir: PathBuf,
+    runtime: TypeScriptRuntime,
+    executor: &'executor dyn CommandExecutor,
+}
+
+impl<'executor> TypeScriptRunner<'executor> {
+    pub fn new(
+        root_dir: PathBuf,
+        runtime: TypeScriptRuntime,
+        executor: &'executor dyn CommandExecutor,
+    ) -> Self {
+        Self {
+            root_dir,
+            runtime,
+            executor,
+        }
+    }
+
+    /// The package manager paired with the runtime. Deno uses its own, which edits
+    /// `package.json` like npm does.
+    fn package_manager(&self) -> CommandRequest {
+        let program = match self.runtime {
+            TypeScriptRuntime::Node => windows_shim("npm"),
+            TypeScriptRuntime::Bun => "bun".to_string(),
+            TypeScriptRuntime::Deno => "deno".to_string(),
+        };
+        CommandRequest::new(program).current_dir(&self.root_dir)
+    }
+}
+
+/// npm and npx are batch scripts on Windows, which are only found with their extension.
+fn windows_shim(program: &str) -> String {
+    if cfg!(windows) {
+        format!("{program}.cmd")
+    } else {
+        program.to_string()
+    }
+}
+
+fn package_json_contents() -> String {
+    r##"{
+  "name": "aocsuite-solution-typescript",
+  "private": true,
+  "type": "module",
+  "imports": {
+    "#lib/*": "./lib/*"
+  },
+  "devDependencies": {
+    "@types/node": "^22.0.0",
+    "tsx": "^4.19.0",
+    "typescript": "^5.6.0"
+  }
+}
+"##
+    .to_string()
+}
+
+fn tsconfig_contents() -> String {
+    r#"{
+  "compilerOptions": {
+    "target": "ES2022",
+    "module": "NodeNext",
+    "moduleResolution": "NodeNext",
+    "allowImportingTsExtensions": true,
+    "noEmit": true,
+    "strict": true,
+    "types": ["node"]
+  },
+  "exclude": ["node_modules", "solutions"]
+}
+"#
+    .to_string()
+}
+
+// npm and npx are `.cmd` shims on Windows.
+#[cfg(all(test, unix))]
+mod tests {
+    use std::fs;
+
+    use aocsuite_storage::Workspace;
+    use aocsuite_utils::{
+        CommandRequest, LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzleYear,
+        TypeScriptRuntime,
+    };
+
+    use crate::{
+        test_support::{successful_output, test_root, ScriptedExecutor},
+        Language, LanguageSettings, RunOptions,
+    };
+
+    #[test]
+    fn typescript_runtimes_use_their_own_commands() {
+        for (runtime, run, add) in [
+            (
+                TypeScriptRuntime::Node,
+                ["npx", "--no-install"],
+                ["npm", "install", "lodash"],
+            ),
+            (
+                TypeScriptRuntime::Bun,
+                ["bun", "run"],
+                ["bun", "add", "lodash"],
+            ),
+            (
+                TypeScriptRuntime::Deno,
+                ["deno", "run"],
+                ["deno", "add", "npm:lodash"],
+            ),
+        ] {
+            let root = test_root(&format!("typescript-{runtime}"));
+            let workspace = Workspace::new(root.clone());
+            let input = root.join("input.txt");
+            fs::create_dir_all(&root).expect("create test workspace");
+            fs::write(&input, "example\n").expect("write input");
+            let executor = ScriptedExecutor::new(|request| {
+                if request.args == ["install"] {
+                    let project_dir = request.current_dir.as_ref().expect("project directory");
+                    fs::create_dir_all(project_dir.join("node_modules"))?;
+                } else if request.args.last().is_some_and(|argument| argument == "1") {
+                    fs::write(
+                        &request.args[request.args.len() - 2],
+                        r#"{"part1":{"answer":"1","runtime_ms":0},"part2":null}"#,
+                    )?;
+                }
+                Ok(successful_output())
+            });
+            let settings = LanguageSettings {
+                typescript_runtime: runtime,
+                ..LanguageSettings::default()
+            };
+            let language = Language::new(LanguageId::TypeScript, &workspace, &settings, &executor)
+                .expect("load language");
+
+            language
+                .execute(
+                    PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap()),
+                    PartSelection::One,
+                    &input,
+                    &RunOptions::default(),
+                )
+                .expect("run solution");
+            language.add_package("lodash").expect("add package");
+
+            let requests = executor.requests();
+            let command = |request: &CommandRequest, words| {
+                std::iter::once(request.program.clone())
+                    .chain(request.args.iter().cloned())
+                    .take(words)
+                    .map(|part| part.to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(requests.len(), 3, "{runtime}: install, run and add");
+            assert_eq!(command(&requests[0], 2)[1], "install");
+            assert_eq!(command(&requests[1], 2), run);
+            assert_eq!(command(&requests[2], 3), add);
+
+            fs::remove_dir_all(root).expect("remove test runtime");
+        }
+    }
+}
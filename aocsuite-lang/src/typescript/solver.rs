@@ -0,0 +1,249 @@
+use std::process::Output;
+
+use crate::{
+    template::named_template_path,
+    traits::Solver,
+    utils::{AocLanguageResult, RunOptions, SolverFile},
+};
+use aocsuite_utils::{atomic_write, execute_command, CommandRequest, TypeScriptRuntime};
+
+use super::{package_json_contents, tsconfig_contents, windows_shim, TypeScriptRunner};
+
+impl Solver for TypeScriptRunner<'_> {
+    fn compile(&self, _: &RunOptions) -> AocLanguageResult<Option<Output>> {
+        Ok(None)
+    }
+
+    fn run(
+        &self,
+        part: aocsuite_utils::PartSelection,
+        input: &std::path::Path,
+        output: &std::path::Path,
+        options: &RunOptions,
+    ) -> AocLanguageResult<Output> {
+        let request = match self.runtime {
+            TypeScriptRuntime::Node => CommandRequest::new(windows_shim("npx"))
+                .arg("--no-install")
+                .arg("tsx"),
+            TypeScriptRuntime::Bun => CommandRequest::new("bun").arg("run"),
+            TypeScriptRuntime::Deno => CommandRequest::new("deno")
+                .arg("run")
+                .arg("--allow-read")
+                .arg("--allow-write"),
+        };
+        let request = request
+            .arg(self.solver_file_path(&SolverFile::Entrypoint))
+            .arg(input)
+            .arg(output)
+            .arg(part.to_string())
+            .args(options.bench.map(|iterations| iterations.to_string()))
+            .current_dir(&self.root_dir);
+
+        Ok(execute_command(self.executor, options.supervise(request))?)
+    }
+
+    fn migrate_runtime(&self) -> AocLanguageResult<()> {
+        std::fs::create_dir_all(&self.root_dir)?;
+        for (name, contents) in [
+            ("package.json", package_json_contents()),
+            ("tsconfig.json", tsconfig_contents()),
+        ] {
+            let path = self.root_dir.join(name);
+            if !path.exists() {
+                atomic_write(&path, contents.as_bytes())?;
+            }
+        }
+
+        crate::runtime::migrate_runtime(
+            &self.root_dir,
+            vec![(
+                self.solver_file_path(&SolverFile::Entrypoint),
+                self.main_contents(),
+            )],
+        )
+    }
+    fn clean_runtime(&self) -> AocLanguageResult<()> {
+        crate::runtime::clean_runtime(
+            &self.root_dir,
+            &[self.solver_file_path(&SolverFile::Entrypoint)],
+            &self.root_dir.join("solution.ts"),
+        )
+    }
+
+    fn solver_file_path(&self, file: &SolverFile) -> std::path::PathBuf {
+        match file {
+            SolverFile::Entrypoint => self.root_dir.join("main.ts"),
+            SolverFile::SolutionTemplate(template) if template.is_default() => {
+                self.root_dir.join("template.ts")
+            }
+            SolverFile::SolutionTemplate(template) => {
+                named_template_path(&self.root_dir, template, "ts")
+            }
+            SolverFile::ActiveSolution(_) => self.root_dir.join("solution.ts"),
+            SolverFile::PuzzleSolution(solution) => {
+                self.solutions_dir().join(format!("{solution}.ts"))
+            }
+        }
+    }
+    fn solutions_dir(&self) -> std::path::PathBuf {
+        self.root_dir.join("solutions")
+    }
+    fn template_contents(&self) -> String {
+        r##"// Implement your solution here. Libraries in lib/ are imported from "#lib/name.ts".
+
+// Solve part 1 of the puzzle
+export function part1(input: string): string {
+  return `Part 1 not implemented yet. Input length: ${input.length}`;
+}
+
+// Solve part 2 of the puzzle
+export function part2(input: string): string {
+  return `Part 2 not implemented yet. Input length: ${input.length}`;
+}
+"##
+        .to_string()
+    }
+    fn main_contents(&self) -> String {
+        r#"import { mkdirSync, readFileSync, renameSync, rmSync, writeFileSync } from "node:fs";
+import { dirname } from "node:path";
+import process from "node:process";
+
+import { part1, part2 } from "./solution.ts";
+
+type PartFn = (input: string) => unknown;
+
+interface BenchStats {
+  iterations: number;
+  min_ns: number;
+  mean_ns: number;
+  median_ns: number;
+  stddev_ns: number;
+}
+
+interface PartResult {
+  answer: string;
+  runtime_ms: number;
+  bench?: BenchStats;
+}
+
+// Keeps benchmarked answers alive so the calls cannot be optimized away.
+let sink: unknown;
+
+function fail(message: string): never {
+  console.error(message);
+  process.exit(1);
+}
+
+function elapsedNs(start: bigint): bigint {
+  return process.hrtime.bigint() - start;
+}
+
+function runPart(partFn: PartFn, input: string): PartResult {
+  const start = process.hrtime.bigint();
+  const answer = String(partFn(input));
+  const runtimeMs = Number(elapsedNs(start) / 1_000_000n);
+
+  return { answer, runtime_ms: runtimeMs };
+}
+
+// Untimed runs before benchmarking: a tenth of the iterations, between one and ten.
+function warmupRuns(iterations: number): number {
+  return Math.min(Math.max(Math.floor(iterations / 10), 1), 10);
+}
+
+function benchPart(partFn: PartFn, input: string, iterations: number): PartResult {
+  // The first warmup run provides the reported answer.
+  const answer = String(partFn(input));
+  for (let i = 1; i < warmupRuns(iterations); i++) {
+    sink = partFn(input);
+  }
+
+  const samples: bigint[] = [];
+  for (let i = 0; i < iterations; i++) {
+    const start = process.hrtime.bigint();
+    sink = partFn(input);
+    samples.push(elapsedNs(start));
+  }
+  samples.sort((left, right) => (left < right ? -1 : left > right ? 1 : 0));
+
+  const values = samples.map(Number);
+  const mean = values.reduce((sum, value) => sum + value, 0) / values.length;
+  const variance =
+    values.reduce((sum, value) => sum + (value - mean) ** 2, 0) / values.length;
+  const middle = Math.floor(samples.length / 2);
+  const medianNs =
+    samples.length % 2 === 0
+      ? samples[middle - 1] + (samples[middle] - samples[middle - 1]) / 2n
+      : samples[middle];
+
+  return {
+    answer,
+    runtime_ms: Number(medianNs / 1_000_000n),
+    bench: {
+      iterations,
+      min_ns: values[0],
+      mean_ns: Math.round(mean),
+      median_ns: Number(medianNs),
+      stddev_ns: Math.round(Math.sqrt(variance)),
+    },
+  };
+}
+
+function main(): void {
+  const args = process.argv.slice(2);
+  if (args.length < 2 || args.length > 4) {
+    fail(`Usage: main.ts <input_file> <output_file> [1|2|both] [bench_iterations]`);
+  }
+
+  const [inputFile, outputFile] = args;
+  const part = args[2] ?? "both";
+  if (!["1", "2", "both"].includes(part)) {
+    fail(`Invalid part '${part}'. Use '1', '2', or 'both'`);
+  }
+  let iterations: number | undefined;
+  if (args.length > 3) {
+    if (!/^\d+$/.test(args[3]) || Number(args[3]) === 0) {
+      fail(`Invalid bench iterations '${args[3]}'. Use a positive number`);
+    }
+    iterations = Number(args[3]);
+  }
+
+  let input: string;
+  try {
+    input = readFileSync(inputFile, "utf8");
+  } catch (error) {
+    fail(`Failed to read file '${inputFile}': ${error}`);
+  }
+
+  mkdirSync(dirname(outputFile), { recursive: true });
+
+  const measure = (partFn: PartFn): PartResult =>
+    iterations === undefined ? runPart(partFn, input) : benchPart(partFn, input, iterations);
+  const output: { part1: PartResult | null; part2: PartResult | null } = {
+    part1: null,
+    part2: null,
+  };
+  if (part === "1" || part === "both") {
+    output.part1 = measure(part1);
+  }
+  if (part === "2" || part === "both") {
+    output.part2 = measure(part2);
+  }
+
+  // Publish a complete result so readers never observe a partial JSON document.
+  const temporaryOutputFile = `${outputFile}.tmp`;
+  try {
+    writeFileSync(temporaryOutputFile, JSON.stringify(output, null, 2));
+    renameSync(temporaryOutputFile, outputFile);
+  } catch (error) {
+    rmSync(temporaryOutputFile, { force: true });
+    fail(`Failed to write output file '${outputFile}': ${error}`);
+  }
+  void sink;
+}
+
+main();
+"#
+        .to_string()
+    }
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use crate::traits::LibManager;
+
+use super::TypeScriptRunner;
+
+impl LibManager for TypeScriptRunner<'_> {
+    fn get_lib_path(&self, lib_name: &str) -> PathBuf {
+        self.lib_dir()
+            .join(format!("{}.{}", lib_name, self.file_extention()))
+    }
+    // Mapped to `#lib/*` in package.json, so imports resolve the same from `solutions/`.
+    fn lib_dir(&self) -> PathBuf {
+        self.root_dir.join("lib")
+    }
+    fn reserved_names(&self) -> Vec<&str> {
+        RESERVED_NAMES.to_vec()
+    }
+    fn file_extention(&self) -> String {
+        "ts".to_string()
+    }
+}
+
+const RESERVED_NAMES: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "index",
+    "main",
+    "solution",
+    "template",
+];
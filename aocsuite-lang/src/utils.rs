@@ -4,10 +4,14 @@ use std::{
     path::{Path, PathBuf},
     process::Output,
     sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
 };
 
 use aocsuite_storage::WorkspaceError;
-use aocsuite_utils::{CommandError, PuzzleId};
+use aocsuite_utils::{
+    BenchIterations, CancellationToken, CommandError, CommandRequest, OutputSink, SolutionId,
+    TemplateName,
+};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -15,16 +19,19 @@ use crate::traits::LanguageHandler;
 
 #[derive(Debug, Clone)]
 pub enum SolverFile {
-    PuzzleSolution(PuzzleId),
+    PuzzleSolution(SolutionId),
     Entrypoint,
-    ActiveSolution(PuzzleId),
-    SolutionTemplate,
+    /// The fixed path the runtime builds, linked to the given solution.
+    ActiveSolution(SolutionId),
+    SolutionTemplate(TemplateName),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PartResult {
     answer: String,
     runtime_ms: u128,
+    #[serde(default)]
+    bench: Option<BenchStats>,
 }
 
 impl PartResult {
@@ -35,6 +42,85 @@ impl PartResult {
     pub fn runtime_ms(&self) -> u128 {
         self.runtime_ms
     }
+
+    /// Timing statistics of a benchmark run, in nanoseconds.
+    pub fn bench(&self) -> Option<&BenchStats> {
+        self.bench.as_ref()
+    }
+}
+
+/// Most solves a benchmark's timeout is scaled by, so that a long benchmark still stops a
+/// runaway solver within minutes.
+const MAX_TIMEOUT_SOLVES: u32 = 10;
+
+/// How a solver run is executed.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Times each part this many times after a warmup instead of once.
+    pub bench: Option<BenchIterations>,
+    /// Limits the build and each solve; a benchmark gets it once per run it times or warms up,
+    /// but never more than ten times.
+    pub timeout: Option<Duration>,
+    pub cancellation: Option<CancellationToken>,
+    /// Receives the solver's stdout and stderr lines as they are printed.
+    pub output: Option<OutputSink>,
+}
+
+impl RunOptions {
+    pub(crate) fn supervise(&self, mut request: CommandRequest) -> CommandRequest {
+        if let Some(sink) = &self.output {
+            request = request.stream_output(sink.clone());
+        }
+        let timeout = self
+            .timeout
+            .map(|timeout| timeout * self.solves().min(MAX_TIMEOUT_SOLVES));
+        self.supervise_with(request, timeout)
+    }
+
+    /// Supervises a build with the unscaled timeout, without streaming its output as solver
+    /// output.
+    pub(crate) fn supervise_build(&self, request: CommandRequest) -> CommandRequest {
+        self.supervise_with(request, self.timeout)
+    }
+
+    fn supervise_with(
+        &self,
+        mut request: CommandRequest,
+        timeout: Option<Duration>,
+    ) -> CommandRequest {
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some(token) = &self.cancellation {
+            request = request.cancellation(token.clone());
+        }
+        request
+    }
+
+    /// Runs of each part: one, or a benchmark's timed iterations plus its warmup runs, which
+    /// are a tenth of the iterations, between one and ten.
+    fn solves(&self) -> u32 {
+        self.bench.map_or(1, |iterations| {
+            let iterations = iterations.get();
+            iterations.saturating_add((iterations / 10).clamp(1, 10))
+        })
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+}
+
+/// Nanosecond statistics over the timed iterations of one part, excluding warmup runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub iterations: u32,
+    pub min_ns: u64,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub stddev_ns: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -98,6 +184,13 @@ pub fn with_result_file<T>(
     }
 }
 
+/// Whether `program` is an executable file in a `PATH` directory.
+pub(crate) fn on_path(program: &str) -> bool {
+    let program = format!("{program}{}", std::env::consts::EXE_SUFFIX);
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join(&program).is_file()))
+}
+
 pub(crate) fn read_result(result_file: &Path) -> AocLanguageResult<PuzzleResult> {
     let reader = BufReader::new(File::open(result_file)?);
     Ok(serde_json::from_reader(reader)?)
@@ -127,6 +220,10 @@ fn symlink_file_with(
     create_link: impl FnOnce(&Path, &Path) -> std::io::Result<()>,
 ) -> AocLanguageResult<()> {
     let destination_exists = match fs::symlink_metadata(to) {
+        // Relinking the same solution would report a change to anyone watching the link.
+        Ok(metadata) if metadata.file_type().is_symlink() && fs::read_link(to)? == from => {
+            return Ok(())
+        }
         Ok(metadata) if metadata.file_type().is_symlink() => true,
         Ok(_) => return Err(AocLanguageError::ActiveSolutionNotLink(to.to_path_buf())),
         Err(error) if error.kind() == std::io::ErrorKind::NotFound => false,
@@ -211,10 +308,23 @@ fn replace_link(
 #[derive(Error, Debug)]
 pub enum AocLanguageError {
     #[error(transparent)]
-    Command(#[from] CommandError),
+    Command(CommandError),
+
+    #[error("timed out after {}s", .0.as_secs())]
+    TimedOut(Duration),
+
+    #[error("run cancelled")]
+    Cancelled,
 
     #[error("Language not found: {0}")]
     LangNotFound(String),
+
+    #[error("invalid language manifest '{}': {message}", path.display())]
+    Manifest { path: PathBuf, message: String },
+
+    #[error("failed to update '{}': {message}", path.display())]
+    ProjectFile { path: PathBuf, message: String },
+
     #[error("failed to read template '{path}': {source}")]
     TemplateRead {
         #[source]
@@ -222,6 +332,15 @@ pub enum AocLanguageError {
         path: String,
     },
 
+    #[error("no template named '{0}'")]
+    TemplateNotFound(TemplateName),
+
+    #[error("solution {solution} already exists, so template '{template}' was not applied")]
+    SolutionExists {
+        solution: SolutionId,
+        template: TemplateName,
+    },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -254,6 +373,16 @@ pub enum AocLanguageError {
     #[error("environment error: {0:?}")]
     Env(String),
 
+    #[error(
+        "the Python environment in '{}' was built with {found} but {configured} is configured; run `aocsuite-cli env clean` to rebuild it",
+        venv.display()
+    )]
+    PythonEnvMismatch {
+        venv: PathBuf,
+        found: String,
+        configured: String,
+    },
+
     #[error("Dependency {0:?} could not be added: {1:?}")]
     DepAdd(String, String),
 
@@ -270,6 +399,16 @@ pub enum AocLanguageError {
     Clean(String),
 }
 
+impl From<CommandError> for AocLanguageError {
+    fn from(error: CommandError) -> Self {
+        match error {
+            CommandError::TimedOut(timeout) => Self::TimedOut(timeout),
+            CommandError::Cancelled => Self::Cancelled,
+            error => Self::Command(error),
+        }
+    }
+}
+
 pub type AocLanguageResult<T> = Result<T, AocLanguageError>;
 pub type LanguageRunner<'executor> = Box<dyn LanguageHandler + 'executor>;
 
@@ -282,7 +421,9 @@ mod tests {
         time::{SystemTime, UNIX_EPOCH},
     };
 
-    use super::{symlink_file, symlink_file_with, AocLanguageError};
+    use aocsuite_utils::{BenchIterations, CommandRequest};
+
+    use super::{symlink_file, symlink_file_with, AocLanguageError, RunOptions};
 
     fn test_root() -> PathBuf {
         let unique = SystemTime::now()
@@ -292,6 +433,25 @@ mod tests {
         std::env::temp_dir().join(format!("aocsuite-links-{}-{unique}", process::id()))
     }
 
+    #[test]
+    fn benchmark_timeouts_grow_with_their_solves_up_to_a_cap() {
+        let timeout = std::time::Duration::from_secs(30);
+        let supervised_timeout = |bench: Option<u32>| {
+            RunOptions {
+                bench: bench.map(|iterations| BenchIterations::new(iterations).unwrap()),
+                timeout: Some(timeout),
+                ..RunOptions::default()
+            }
+            .supervise(CommandRequest::new("solver"))
+            .timeout
+        };
+
+        assert_eq!(supervised_timeout(None), Some(timeout));
+        // Three timed iterations and one warmup run.
+        assert_eq!(supervised_timeout(Some(3)), Some(timeout * 4));
+        assert_eq!(supervised_timeout(Some(1000)), Some(timeout * 10));
+    }
+
     #[cfg(unix)]
     #[test]
     fn active_solution_does_not_replace_a_regular_file() {
@@ -339,4 +499,22 @@ mod tests {
 
         fs::remove_dir_all(root).expect("remove test runtime");
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn relinking_the_same_solution_leaves_the_link_alone() {
+        let root = test_root();
+        fs::create_dir_all(&root).expect("create test runtime");
+        let source = root.join("source.rs");
+        let destination = root.join("solution.rs");
+        fs::write(&source, "source solution").expect("write source solution");
+        symlink_file(&source, &destination).expect("link active solution");
+
+        assert!(symlink_file_with(&source, &destination, |_, _| {
+            panic!("the link already points at the solution")
+        })
+        .is_ok());
+
+        fs::remove_dir_all(root).expect("remove test runtime");
+    }
 }
@@ -0,0 +1,113 @@
+use scraper::{ElementRef, Html, Selector};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExampleAnswerCandidates {
+    pub part1: Vec<String>,
+    pub part2: Vec<String>,
+}
+
+/// Extracts highlighted `<code><em>` values from each part's prose, most likely first.
+///
+/// Puzzle prose states the sample answer last, so candidates are ordered from the end of
+/// each article. Highlights inside `<pre>` sample blocks are ignored.
+pub fn parse_example_answers(html: &str) -> ExampleAnswerCandidates {
+    let document = Html::parse_document(html);
+    let article_selector = Selector::parse("main article").expect("valid article selector");
+    let answer_selector = Selector::parse("code > em, em > code").expect("valid answer selector");
+
+    let mut articles = document.select(&article_selector).map(|article| {
+        let mut candidates = Vec::new();
+        for answer in article.select(&answer_selector) {
+            if inside_preformatted(answer) {
+                continue;
+            }
+            let text = answer.text().collect::<String>().trim().to_owned();
+            if !text.is_empty() {
+                candidates.retain(|candidate| *candidate != text);
+                candidates.push(text);
+            }
+        }
+        candidates.reverse();
+        candidates
+    });
+    ExampleAnswerCandidates {
+        part1: articles.next().unwrap_or_default(),
+        part2: articles.next().unwrap_or_default(),
+    }
+}
+
+/// Extracts the answers AoC shows as "Your puzzle answer was ..." in part order.
+///
+/// Only solved parts carry the line, so a page with one star yields a single answer.
+pub fn parse_accepted_answers(html: &str) -> Vec<String> {
+    let document = Html::parse_document(html);
+    let paragraph_selector = Selector::parse("main p").expect("valid paragraph selector");
+    let code_selector = Selector::parse("code").expect("valid code selector");
+
+    document
+        .select(&paragraph_selector)
+        .filter(|paragraph| {
+            paragraph
+                .text()
+                .collect::<String>()
+                .trim_start()
+                .starts_with("Your puzzle answer was")
+        })
+        .filter_map(|paragraph| paragraph.select(&code_selector).next())
+        .map(|code| code.text().collect::<String>().trim().to_owned())
+        .filter(|answer| !answer.is_empty())
+        .collect()
+}
+
+fn inside_preformatted(element: ElementRef<'_>) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| ancestor.value().name() == "pre")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExampleAnswerCandidates, parse_accepted_answers, parse_example_answers};
+
+    #[test]
+    fn answer_parser_orders_candidates_from_the_end_of_each_part() {
+        let html = "<main><article>\
+            <p>For example:</p><pre><code>1\n<em>2</em>\n</code></pre>\
+            <p>The first pair adds up to <code><em>3</em></code>.</p>\
+            <p>In total, the sum is <code><em>11</em></code>.</p>\
+            </article><article>\
+            <p>Part two totals <em><code>31</code></em>.</p>\
+            </article></main>";
+
+        assert_eq!(
+            parse_example_answers(html),
+            ExampleAnswerCandidates {
+                part1: vec!["11".to_owned(), "3".to_owned()],
+                part2: vec!["31".to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn answer_parser_leaves_locked_parts_empty() {
+        let html = "<main><article><p>Answer <code><em>7</em></code></p></article></main>";
+
+        let candidates = parse_example_answers(html);
+
+        assert_eq!(candidates.part1, ["7"]);
+        assert!(candidates.part2.is_empty());
+    }
+
+    #[test]
+    fn accepted_answer_parser_reads_solved_parts_in_order() {
+        let html = "<main><article><p>Answer <code><em>7</em></code></p></article>\
+            <p>Your puzzle answer was <code>1234</code>.</p>\
+            <article><p>Part two</p></article>\
+            <p>Your puzzle answer was <code> 56 </code>.</p>\
+            <p>Both parts of this puzzle are complete!</p></main>";
+
+        assert_eq!(parse_accepted_answers(html), ["1234", "56"]);
+        assert!(parse_accepted_answers("<main><article></article></main>").is_empty());
+    }
+}
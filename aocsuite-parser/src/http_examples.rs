@@ -0,0 +1,77 @@
+use scraper::{ElementRef, Html, Selector};
+
+/// Extracts the `<pre><code>` blocks of a puzzle page, most likely sample input first.
+///
+/// The first block introduced by a paragraph mentioning an example, and not annotated
+/// with `<em>` highlights, is preferred. Remaining blocks follow in document order with
+/// duplicates and blank blocks removed.
+pub fn parse_puzzle_examples(html: &str) -> Vec<String> {
+    let document = Html::parse_document(html);
+    let code_selector = Selector::parse("main article pre > code").expect("valid code selector");
+    let emphasis_selector = Selector::parse("em").expect("valid emphasis selector");
+
+    let mut candidates: Vec<(String, bool)> = Vec::new();
+    for code in document.select(&code_selector) {
+        let text = code.text().collect::<String>();
+        if text.trim().is_empty() || candidates.iter().any(|(block, _)| *block == text) {
+            continue;
+        }
+        let likely = code.select(&emphasis_selector).next().is_none() && introduces_example(code);
+        candidates.push((text, likely));
+    }
+
+    if let Some(index) = candidates.iter().position(|(_, likely)| *likely) {
+        let primary = candidates.remove(index);
+        candidates.insert(0, primary);
+    }
+    candidates.into_iter().map(|(block, _)| block).collect()
+}
+
+fn introduces_example(code: ElementRef<'_>) -> bool {
+    code.parent()
+        .into_iter()
+        .flat_map(|pre| pre.prev_siblings())
+        .find_map(ElementRef::wrap)
+        .is_some_and(|paragraph| {
+            paragraph
+                .text()
+                .collect::<String>()
+                .to_ascii_lowercase()
+                .contains("example")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_puzzle_examples;
+
+    #[test]
+    fn example_parser_prefers_the_introduced_unannotated_block() {
+        let html = "<main><article>\
+            <p>The elves use <code>x</code> notation:</p>\
+            <pre><code>notation\n</code></pre>\
+            <p>For example:</p>\
+            <pre><code>1 &lt; 2\n3 4\n</code></pre>\
+            <p>This example yields:</p>\
+            <pre><code><em>7</em> total\n</code></pre>\
+            </article><article>\
+            <p>Using the same example:</p>\
+            <pre><code>1 &lt; 2\n3 4\n</code></pre>\
+            <pre><code>   </code></pre>\
+            </article></main>";
+
+        assert_eq!(
+            parse_puzzle_examples(html),
+            ["1 < 2\n3 4\n", "notation\n", "7 total\n"]
+        );
+    }
+
+    #[test]
+    fn example_parser_keeps_document_order_without_an_introduction() {
+        let html = "<main><article><pre><code>first\n</code></pre>\
+            <pre><code>second\n</code></pre></article></main>";
+
+        assert_eq!(parse_puzzle_examples(html), ["first\n", "second\n"]);
+        assert!(parse_puzzle_examples("<main><article></article></main>").is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::{BTreeMap, HashMap};
+
+use aocsuite_utils::{PuzzleDay, PuzzlePart};
+use serde::Deserialize;
+
+use crate::ParserResult;
+
+/// Days shown when AoC does not report the length of the event.
+pub const DEFAULT_EVENT_DAYS: u32 = 25;
+
+/// A private leaderboard as exported by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrivateLeaderboard {
+    pub owner_id: u64,
+    /// Number of puzzle days in the event, when AoC reports it.
+    pub num_days: Option<u32>,
+    /// Members ranked by local score.
+    pub members: Vec<LeaderboardMember>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardMember {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u32,
+    pub stars: u32,
+    /// Unix timestamp of the latest star, zero without stars.
+    pub last_star_ts: i64,
+    pub days: BTreeMap<PuzzleDay, DayCompletion>,
+}
+
+/// Unix timestamps at which each part of a day earned its star.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DayCompletion {
+    pub part1: Option<i64>,
+    pub part2: Option<i64>,
+}
+
+impl PrivateLeaderboard {
+    /// Days of the event, up to `last_released` while the event is still running.
+    pub fn days(&self, last_released: Option<PuzzleDay>) -> Vec<PuzzleDay> {
+        let mut last = self.num_days.unwrap_or(DEFAULT_EVENT_DAYS);
+        if let Some(released) = last_released {
+            last = last.min(u32::from(released));
+        }
+        (1..=last)
+            .filter_map(|day| PuzzleDay::new(day).ok())
+            .collect()
+    }
+
+    /// Members in rank order with their rank. Members tied on local score share the rank of the
+    /// first of them, which is only given to that first member; the others get `None`.
+    pub fn ranking(&self) -> Vec<(Option<usize>, &LeaderboardMember)> {
+        let mut previous_score = None;
+        self.members
+            .iter()
+            .enumerate()
+            .map(|(index, member)| {
+                let rank = (previous_score != Some(member.local_score)).then_some(index + 1);
+                previous_score = Some(member.local_score);
+                (rank, member)
+            })
+            .collect()
+    }
+}
+
+impl LeaderboardMember {
+    /// The member name, or the placeholder AoC shows for anonymous users.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn day(&self, day: PuzzleDay) -> DayCompletion {
+        self.days.get(&day).copied().unwrap_or_default()
+    }
+}
+
+impl DayCompletion {
+    pub fn part(&self, part: PuzzlePart) -> Option<i64> {
+        match part {
+            PuzzlePart::One => self.part1,
+            PuzzlePart::Two => self.part2,
+        }
+    }
+
+    pub fn stars(&self) -> u8 {
+        u8::from(self.part1.is_some()) + u8::from(self.part2.is_some())
+    }
+
+    /// Glyph of the day in a leaderboard table: `*` for both stars, `+` for the first star only
+    /// and `.` for none.
+    pub fn glyph(&self) -> char {
+        match self.stars() {
+            2 => '*',
+            1 => '+',
+            _ => '.',
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawLeaderboard {
+    owner_id: u64,
+    num_days: Option<u32>,
+    members: HashMap<String, RawMember>,
+}
+
+#[derive(Deserialize)]
+struct RawMember {
+    id: u64,
+    name: Option<String>,
+    local_score: u32,
+    stars: u32,
+    last_star_ts: i64,
+    #[serde(default)]
+    completion_day_level: HashMap<String, HashMap<String, RawStar>>,
+}
+
+#[derive(Deserialize)]
+struct RawStar {
+    get_star_ts: i64,
+}
+
+pub fn parse_private_leaderboard(json: &str) -> ParserResult<PrivateLeaderboard> {
+    let raw: RawLeaderboard = serde_json::from_str(json)?;
+    let mut members: Vec<LeaderboardMember> = raw
+        .members
+        .into_values()
+        .map(|member| {
+            let days = member
+                .completion_day_level
+                .into_iter()
+                .filter_map(|(day, parts)| {
+                    let day = day.parse::<PuzzleDay>().ok()?;
+                    let star = |part: &str| parts.get(part).map(|star| star.get_star_ts);
+                    Some((
+                        day,
+                        DayCompletion {
+                            part1: star("1"),
+                            part2: star("2"),
+                        },
+                    ))
+                })
+                .collect();
+            LeaderboardMember {
+                id: member.id,
+                name: member.name,
+                local_score: member.local_score,
+                stars: member.stars,
+                last_star_ts: member.last_star_ts,
+                days,
+            }
+        })
+        .collect();
+    // Ties go to whoever reached their score first.
+    members.sort_by(|left, right| {
+        right
+            .local_score
+            .cmp(&left.local_score)
+            .then(right.stars.cmp(&left.stars))
+            .then(left.last_star_ts.cmp(&right.last_star_ts))
+            .then(left.id.cmp(&right.id))
+    });
+    Ok(PrivateLeaderboard {
+        owner_id: raw.owner_id,
+        num_days: raw.num_days,
+        members,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aocsuite_utils::{PuzzleDay, PuzzlePart};
+
+    use super::{DEFAULT_EVENT_DAYS, DayCompletion, PrivateLeaderboard, parse_private_leaderboard};
+    use crate::ParserError;
+
+    const LEADERBOARD: &str = r#"{
+        "owner_id": 7,
+        "event": "2024",
+        "day1_ts": 1733029200,
+        "num_days": 25,
+        "members": {
+            "7": {
+                "id": 7, "name": "owner", "stars": 3, "local_score": 9, "global_score": 0,
+                "last_star_ts": 1733130000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029500, "star_index": 10},
+                        "2": {"get_star_ts": 1733029900, "star_index": 12}
+                    },
+                    "2": {"1": {"get_star_ts": 1733130000, "star_index": 40}}
+                }
+            },
+            "12": {
+                "id": 12, "name": null, "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            },
+            "9": {
+                "id": 9, "name": "rival", "stars": 3, "local_score": 9, "global_score": 0,
+                "last_star_ts": 1733140000,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1733029400, "star_index": 8}},
+                    "2": {
+                        "1": {"get_star_ts": 1733139000, "star_index": 50},
+                        "2": {"get_star_ts": 1733140000, "star_index": 52}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn private_leaderboard_members_are_ranked_with_their_star_times() {
+        let leaderboard = parse_private_leaderboard(LEADERBOARD).expect("parse leaderboard");
+        let day = |day| PuzzleDay::new(day).unwrap();
+
+        assert_eq!((leaderboard.owner_id, leaderboard.num_days), (7, Some(25)));
+        let names: Vec<_> = leaderboard
+            .members
+            .iter()
+            .map(|member| member.display_name())
+            .collect();
+        assert_eq!(names, ["owner", "rival", "(anonymous user #12)"]);
+
+        let owner = &leaderboard.members[0];
+        assert_eq!((owner.local_score, owner.stars), (9, 3));
+        assert_eq!(
+            owner.day(day(1)),
+            DayCompletion {
+                part1: Some(1733029500),
+                part2: Some(1733029900),
+            }
+        );
+        assert_eq!(owner.day(day(2)).stars(), 1);
+        assert_eq!(owner.day(day(2)).part(PuzzlePart::Two), None);
+        assert_eq!(owner.day(day(3)), DayCompletion::default());
+        let glyphs: String = [1, 2, 3]
+            .map(|day_number| owner.day(day(day_number)).glyph())
+            .iter()
+            .collect();
+        assert_eq!(glyphs, "*+.");
+    }
+
+    #[test]
+    fn tied_members_share_a_rank_and_days_stop_at_the_last_released_day() {
+        let leaderboard = parse_private_leaderboard(LEADERBOARD).expect("parse leaderboard");
+        let ranks: Vec<_> = leaderboard
+            .ranking()
+            .into_iter()
+            .map(|(rank, member)| (rank, member.id))
+            .collect();
+        assert_eq!(ranks, [(Some(1), 7), (None, 9), (Some(3), 12)]);
+
+        assert_eq!(leaderboard.days(None).len(), 25);
+        let released = leaderboard.days(PuzzleDay::new(3).ok());
+        assert_eq!(released, [1, 2, 3].map(|day| PuzzleDay::new(day).unwrap()));
+        let unknown_length = PrivateLeaderboard {
+            num_days: None,
+            ..leaderboard
+        };
+        assert_eq!(unknown_length.days(None).len() as u32, DEFAULT_EVENT_DAYS);
+    }
+
+    #[test]
+    fn malformed_leaderboards_are_rejected() {
+        assert!(matches!(
+            parse_private_leaderboard("<html>Please log in</html>"),
+            Err(ParserError::InvalidLeaderboard(_))
+        ));
+    }
+}
@@ -11,6 +11,20 @@ pub fn parse_puzzle_markdown(html: &str) -> ParserResult<String> {
     }
 }
 
+/// Extracts the puzzle title from its `--- Day 5: Print Queue ---` heading.
+pub fn parse_puzzle_title(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let heading_selector = Selector::parse("main article h2").expect("valid heading selector");
+    let heading = document
+        .select(&heading_selector)
+        .next()?
+        .text()
+        .collect::<String>();
+    let (_, title) = heading.trim().trim_matches('-').split_once(':')?;
+    let title = title.trim();
+    (!title.is_empty()).then(|| title.to_owned())
+}
+
 pub(crate) fn parse_article_markdown(html: &str) -> String {
     let document = Html::parse_document(html);
     let main_selector = Selector::parse("main").expect("valid main selector");
@@ -25,3 +39,17 @@ pub(crate) fn parse_article_markdown(html: &str) -> String {
 
     html2md::parse_html(&articles_html)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_puzzle_title;
+
+    #[test]
+    fn title_parser_reads_the_first_day_heading() {
+        let html = "<main><article><h2>--- Day 5: Print Queue ---</h2><p>...</p></article>\
+            <article><h2 id=\"part2\">--- Part Two ---</h2></article></main>";
+
+        assert_eq!(parse_puzzle_title(html).as_deref(), Some("Print Queue"));
+        assert_eq!(parse_puzzle_title("<main><article></article></main>"), None);
+    }
+}
@@ -1,11 +1,20 @@
 mod http_ansicalendar;
+mod http_answers;
+mod http_examples;
+mod http_leaderboard;
 mod http_markdown;
 mod http_submission;
 
 pub use http_ansicalendar::{
     Calendar, CalendarCell, CalendarRow, CalendarStars, Rgb, parse_calendar,
 };
-pub use http_markdown::parse_puzzle_markdown;
+pub use http_answers::{ExampleAnswerCandidates, parse_accepted_answers, parse_example_answers};
+pub use http_examples::parse_puzzle_examples;
+pub use http_leaderboard::{
+    DEFAULT_EVENT_DAYS, DayCompletion, LeaderboardMember, PrivateLeaderboard,
+    parse_private_leaderboard,
+};
+pub use http_markdown::{parse_puzzle_markdown, parse_puzzle_title};
 pub use http_submission::{AocSubmissionResult, parse_submission};
 
 use thiserror::Error;
@@ -18,6 +27,8 @@ pub enum ParserError {
     MissingCalendar,
     #[error("submission response did not contain an article")]
     MissingSubmissionArticle,
+    #[error("private leaderboard response is not valid JSON: {0}")]
+    InvalidLeaderboard(#[from] serde_json::Error),
 }
 
 pub type ParserResult<T> = Result<T, ParserError>;
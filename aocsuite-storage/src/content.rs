@@ -1,18 +1,34 @@
 use std::{
     fs,
     path::PathBuf,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use aocsuite_client::{AocClient, AocClientError, AocPage};
-use aocsuite_parser::{parse_puzzle_markdown, AocSubmissionResult, ParserError};
+use aocsuite_client::{AocClient, AocClientError, AocPage, CacheValidators, Download};
+use aocsuite_parser::{
+    parse_accepted_answers, parse_calendar, parse_example_answers, parse_private_leaderboard,
+    parse_puzzle_examples, parse_puzzle_markdown, parse_puzzle_title, AocSubmissionResult,
+    CalendarStars, ParserError, PrivateLeaderboard,
+};
 use aocsuite_utils::{
-    atomic_write, set_owner_only_permissions, LanguageId, PuzzleId, PuzzlePart, PuzzleYear,
-    RunHistoryLimit,
+    atomic_write, set_owner_only_permissions, LanguageId, PuzzleDay, PuzzleId, PuzzlePart,
+    PuzzleYear, RunHistoryLimit,
 };
 use thiserror::Error;
 
-use crate::database::{CacheEntry, DatabaseError, StateDatabase};
+use crate::{
+    database::{
+        BenchmarkRecord, CacheEntry, DatabaseError, RunTimingRecord, StateDatabase,
+        SubmissionRecord,
+    },
+    workspace::PuzzleAnswers,
+};
+
+/// AoC asks for at least a minute between wrong answers; longer waits arrive as rate limits.
+const INCORRECT_ANSWER_COOLDOWN_SECS: i64 = 60;
+
+/// AoC asks that a private leaderboard is fetched at most once every 15 minutes.
+pub const LEADERBOARD_REFRESH_FLOOR: Duration = Duration::from_secs(15 * 60);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum CacheKey {
@@ -20,6 +36,7 @@ pub(crate) enum CacheKey {
     PuzzleMarkdown(PuzzleId),
     Input(PuzzleId),
     Calendar(PuzzleYear),
+    PrivateLeaderboard(PuzzleYear, u32),
 }
 
 impl CacheKey {
@@ -28,9 +45,24 @@ impl CacheKey {
             Self::PuzzleHtml(puzzle) => Some(AocPage::Puzzle(puzzle)),
             Self::Input(puzzle) => Some(AocPage::Input(puzzle)),
             Self::Calendar(year) => Some(AocPage::Calendar(year)),
+            Self::PrivateLeaderboard(year, id) => Some(AocPage::PrivateLeaderboardJson(year, id)),
             Self::PuzzleMarkdown(_) => None,
         }
     }
+
+    /// Names the cached content for messages, e.g. "the input of 2024 day 3".
+    fn description(self) -> String {
+        match self {
+            Self::PuzzleHtml(puzzle) | Self::PuzzleMarkdown(puzzle) => {
+                format!("the description of {} day {}", puzzle.year, puzzle.day)
+            }
+            Self::Input(puzzle) => format!("the input of {} day {}", puzzle.year, puzzle.day),
+            Self::Calendar(year) => format!("the {year} calendar"),
+            Self::PrivateLeaderboard(year, id) => {
+                format!("the {year} private leaderboard {id}")
+            }
+        }
+    }
 }
 
 pub struct ContentStore<'client> {
@@ -52,6 +84,75 @@ pub struct CacheCleanReport {
     pub already_absent: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunTiming {
+    /// The runtime of a single run, or the median of a benchmark.
+    pub duration: Duration,
+    pub recorded_at: SystemTime,
+    pub benchmark: Option<RunBenchmark>,
+}
+
+/// Aggregated timings of a benchmark run, excluding warmup runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunBenchmark {
+    pub iterations: u32,
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+/// Retained runs of one puzzle part in one language, newest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunHistory {
+    pub runs: Vec<RunTiming>,
+}
+
+impl RunHistory {
+    pub fn min(&self) -> Option<Duration> {
+        self.runs.iter().map(|run| run.duration).min()
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.runs.iter().map(|run| run.duration).max()
+    }
+
+    /// The middle runtime, or the mean of the two middle runtimes for an even count.
+    pub fn median(&self) -> Option<Duration> {
+        let mut durations = self.runs.iter().map(|run| run.duration).collect::<Vec<_>>();
+        durations.sort_unstable();
+        let middle = durations.len() / 2;
+        match durations.len() {
+            0 => None,
+            length if length % 2 == 1 => Some(durations[middle]),
+            _ => Some((durations[middle - 1] + durations[middle]) / 2),
+        }
+    }
+}
+
+/// Stars earned per day of one year, as last seen on the calendar or through submissions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct YearStars {
+    pub days: Vec<(PuzzleDay, u8)>,
+}
+
+impl YearStars {
+    pub fn day(&self, day: PuzzleDay) -> u8 {
+        self.days
+            .iter()
+            .find(|(stored, _)| *stored == day)
+            .map_or(0, |(_, stars)| *stars)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.days.iter().map(|(_, stars)| u32::from(*stars)).sum()
+    }
+
+    pub fn completed_days(&self) -> usize {
+        self.days.iter().filter(|(_, stars)| *stars == 2).count()
+    }
+}
+
 impl<'client> ContentStore<'client> {
     pub fn open(cache_dir: PathBuf, client: &'client AocClient) -> ContentResult<Self> {
         fs::create_dir_all(&cache_dir)?;
@@ -65,31 +166,148 @@ impl<'client> ContentStore<'client> {
     }
 
     pub fn load_calendar(&self, year: PuzzleYear) -> ContentResult<String> {
-        let path = self.load_or_fetch(CacheKey::Calendar(year))?;
-        Ok(fs::read_to_string(path)?)
+        let key = CacheKey::Calendar(year);
+        let fetched = !self.is_cached(key)?;
+        let calendar = fs::read_to_string(self.load_or_fetch(key)?)?;
+        if fetched {
+            self.store_calendar_stars(year, &calendar)?;
+        }
+        Ok(calendar)
     }
 
+    /// Fetches the calendar again, revalidating a cached copy with its `ETag`/`Last-Modified`.
+    ///
+    /// Offline, the cached calendar is returned as is.
     pub fn refresh_calendar(&self, year: PuzzleYear) -> ContentResult<String> {
         let key = CacheKey::Calendar(year);
+        if self.client.is_offline() {
+            return self.load_calendar(year);
+        }
         let path = self.cache_path(key);
         self.ensure_replaceable(key)?;
 
-        let body = self.client.download(&AocPage::Calendar(year))?;
-        let entry = self.cache_entry(key, body.len());
+        let Some(download) = self.fetch_if_modified(AocPage::Calendar(year), &[key])? else {
+            return Ok(fs::read_to_string(path)?);
+        };
+        let entry = self.fetched_entry(key, &download);
+        let body = download.body;
         replace_with_rollback(&path, body.as_bytes(), || {
             self.database
                 .upsert_cache_entry(&entry)
                 .map_err(ContentError::from_database)
         })?;
+        self.store_calendar_stars(year, &body)?;
         Ok(body)
     }
 
+    /// Returns the stored stars of a year without fetching.
+    ///
+    /// A year never seen before is filled from its cached calendar, if any.
+    pub fn year_stars(&self, year: PuzzleYear) -> ContentResult<YearStars> {
+        let stored = || {
+            self.database
+                .calendar_stars(year)
+                .map_err(ContentError::from_database)
+        };
+        let mut days = stored()?;
+        let key = CacheKey::Calendar(year);
+        if days.is_empty() && self.is_cached(key)? {
+            self.store_calendar_stars(year, &fs::read_to_string(self.cache_path(key))?)?;
+            days = stored()?;
+        }
+        Ok(YearStars { days })
+    }
+
+    /// Returns a private leaderboard, fetching it only when the cached copy is older than
+    /// [`LEADERBOARD_REFRESH_FLOOR`].
+    pub fn private_leaderboard(
+        &self,
+        year: PuzzleYear,
+        id: u32,
+    ) -> ContentResult<PrivateLeaderboard> {
+        let key = CacheKey::PrivateLeaderboard(year, id);
+        let cached = match self.cached_private_leaderboard(year, id) {
+            // A cached copy that no longer parses is replaced instead of failing every read.
+            Err(ContentError::Parser(_)) => {
+                self.database
+                    .invalidate_cache_entry(key)
+                    .map_err(ContentError::from_database)?;
+                None
+            }
+            cached => cached?,
+        };
+        if let Some(leaderboard) = cached {
+            if self.client.is_offline() || self.leaderboard_refresh_in(year, id)?.is_some() {
+                return Ok(leaderboard);
+            }
+        }
+
+        self.ensure_online(key)?;
+        self.ensure_replaceable(key)?;
+        let download = self
+            .client
+            .fetch(&AocPage::PrivateLeaderboardJson(year, id))?;
+        let leaderboard = parse_private_leaderboard(&download.body)?;
+        let entry = self.fetched_entry(key, &download);
+        replace_with_rollback(&self.cache_path(key), download.body.as_bytes(), || {
+            self.database
+                .upsert_cache_entry(&entry)
+                .map_err(ContentError::from_database)
+        })?;
+        Ok(leaderboard)
+    }
+
+    /// Returns the cached private leaderboard without fetching it.
+    pub fn cached_private_leaderboard(
+        &self,
+        year: PuzzleYear,
+        id: u32,
+    ) -> ContentResult<Option<PrivateLeaderboard>> {
+        let key = CacheKey::PrivateLeaderboard(year, id);
+        if !self.is_cached(key)? {
+            return Ok(None);
+        }
+        let json = fs::read_to_string(self.cache_path(key))?;
+        Ok(Some(parse_private_leaderboard(&json)?))
+    }
+
+    /// Returns how long the cached private leaderboard is still served before it is fetched
+    /// again, if at all.
+    pub fn leaderboard_refresh_in(
+        &self,
+        year: PuzzleYear,
+        id: u32,
+    ) -> ContentResult<Option<Duration>> {
+        let key = CacheKey::PrivateLeaderboard(year, id);
+        if !self.is_cached(key)? {
+            return Ok(None);
+        }
+        let fetched_at = self
+            .database
+            .cache_entry(key)
+            .map_err(ContentError::from_database)?
+            .and_then(|entry| entry.fetched_at)
+            .unwrap_or_default();
+        let age = current_unix_timestamp().saturating_sub(fetched_at);
+        Ok(u64::try_from(age)
+            .ok()
+            .and_then(|age| LEADERBOARD_REFRESH_FLOOR.as_secs().checked_sub(age))
+            .filter(|remaining| *remaining > 0)
+            .map(Duration::from_secs))
+    }
+
     pub fn ensure_input(&self, puzzle: PuzzleId) -> ContentResult<PathBuf> {
         let path = self.load_or_fetch(CacheKey::Input(puzzle))?;
         set_owner_only_permissions(&path)?;
         Ok(path)
     }
 
+    /// Returns the cached input path without fetching it.
+    pub fn cached_input(&self, puzzle: PuzzleId) -> ContentResult<Option<PathBuf>> {
+        let key = CacheKey::Input(puzzle);
+        Ok(self.is_cached(key)?.then(|| self.cache_path(key)))
+    }
+
     pub fn ensure_puzzle_markdown(&self, puzzle: PuzzleId) -> ContentResult<PathBuf> {
         let html_path = self.load_or_fetch(CacheKey::PuzzleHtml(puzzle))?;
         let markdown_key = CacheKey::PuzzleMarkdown(puzzle);
@@ -113,18 +331,94 @@ impl<'client> ContentStore<'client> {
         Ok(Some(fs::read_to_string(self.cache_path(key))?))
     }
 
+    pub fn puzzle_examples(&self, puzzle: PuzzleId) -> ContentResult<Vec<String>> {
+        let html_path = self.load_or_fetch(CacheKey::PuzzleHtml(puzzle))?;
+        Ok(parse_puzzle_examples(&fs::read_to_string(html_path)?))
+    }
+
+    /// Reads the puzzle title from the cached puzzle page, without fetching it.
+    pub fn cached_puzzle_title(&self, puzzle: PuzzleId) -> ContentResult<Option<String>> {
+        let key = CacheKey::PuzzleHtml(puzzle);
+        if !self.is_cached(key)? {
+            return Ok(None);
+        }
+        Ok(parse_puzzle_title(&fs::read_to_string(
+            self.cache_path(key),
+        )?))
+    }
+
+    pub fn cached_example_answers(&self, puzzle: PuzzleId) -> ContentResult<Option<PuzzleAnswers>> {
+        let key = CacheKey::PuzzleHtml(puzzle);
+        if !self.is_cached(key)? {
+            return Ok(None);
+        }
+        let candidates = parse_example_answers(&fs::read_to_string(self.cache_path(key))?);
+        Ok(Some(PuzzleAnswers {
+            part1: candidates.part1.into_iter().next(),
+            part2: candidates.part2.into_iter().next(),
+        }))
+    }
+
+    /// Returns the answers AoC accepted for the puzzle, used to catch regressions in old
+    /// solutions.
+    pub fn accepted_answers(&self, puzzle: PuzzleId) -> ContentResult<PuzzleAnswers> {
+        let stored = |part| {
+            self.database
+                .accepted_answer(puzzle, part)
+                .map_err(ContentError::from_database)
+        };
+        Ok(PuzzleAnswers {
+            part1: stored(PuzzlePart::One)?,
+            part2: stored(PuzzlePart::Two)?,
+        })
+    }
+
+    /// Stores accepted answers shown on the cached puzzle page that are not known yet.
+    ///
+    /// This never fetches: a page that is not cached, or was invalidated by a correct
+    /// submission, only contributes once it has been downloaded again.
+    pub fn backfill_accepted_answers(&self, puzzle: PuzzleId) -> ContentResult<()> {
+        let known = self.accepted_answers(puzzle)?;
+        let key = CacheKey::PuzzleHtml(puzzle);
+        if known.part2.is_some() || !self.is_cached(key)? {
+            return Ok(());
+        }
+
+        let recorded_at = current_unix_timestamp();
+        let parsed = parse_accepted_answers(&fs::read_to_string(self.cache_path(key))?);
+        for (part, answer) in [PuzzlePart::One, PuzzlePart::Two].into_iter().zip(parsed) {
+            if known.part(part).is_none() {
+                self.database
+                    .set_accepted_answer(puzzle, part, &answer, recorded_at)
+                    .map_err(ContentError::from_database)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches the puzzle page again, revalidating a cached copy with its `ETag`/`Last-Modified`.
+    ///
+    /// Offline, the cached description is returned instead.
     pub fn download_puzzle_markdown(&self, puzzle: PuzzleId) -> ContentResult<String> {
+        if self.client.is_offline() {
+            return self.load_puzzle_markdown(puzzle);
+        }
         let html_key = CacheKey::PuzzleHtml(puzzle);
         let markdown_key = CacheKey::PuzzleMarkdown(puzzle);
         self.ensure_replaceable(html_key)?;
         self.ensure_replaceable(markdown_key)?;
 
-        let html = self.client.download(&AocPage::Puzzle(puzzle))?;
-        let markdown = parse_puzzle_markdown(&html)?;
-        let html_path = self.cache_path(html_key);
         let markdown_path = self.cache_path(markdown_key);
+        let Some(download) =
+            self.fetch_if_modified(AocPage::Puzzle(puzzle), &[html_key, markdown_key])?
+        else {
+            return Ok(fs::read_to_string(markdown_path)?);
+        };
+        let markdown = parse_puzzle_markdown(&download.body)?;
+        let html = download.body.as_str();
+        let html_path = self.cache_path(html_key);
         let entries = [
-            self.cache_entry(html_key, html.len()),
+            self.fetched_entry(html_key, &download),
             self.cache_entry(markdown_key, markdown.len()),
         ];
         replace_with_rollback(&html_path, html.as_bytes(), || {
@@ -137,29 +431,126 @@ impl<'client> ContentStore<'client> {
         Ok(markdown)
     }
 
+    /// Refuses answers that earlier submissions already prove wrong.
+    pub fn check_submission(
+        &self,
+        puzzle: PuzzleId,
+        part: PuzzlePart,
+        answer: &str,
+    ) -> ContentResult<()> {
+        let answer = answer.trim();
+        let numeric = answer.parse::<i128>().ok();
+        let mut too_high: Option<(i128, String)> = None;
+        let mut too_low: Option<(i128, String)> = None;
+        for record in self
+            .database
+            .submissions(puzzle, part)
+            .map_err(ContentError::from_database)?
+        {
+            let rejected = matches!(
+                record.result.as_str(),
+                "incorrect" | "incorrect_too_high" | "incorrect_too_low"
+            );
+            if rejected && record.answer.trim() == answer {
+                return Err(ContentError::SubmissionBlocked(
+                    SubmissionBlock::AlreadyRejected {
+                        answer: answer.to_owned(),
+                    },
+                ));
+            }
+            let Ok(bound) = record.answer.trim().parse::<i128>() else {
+                continue;
+            };
+            match record.result.as_str() {
+                "incorrect_too_high" if too_high.as_ref().is_none_or(|(high, _)| bound < *high) => {
+                    too_high = Some((bound, record.answer));
+                }
+                "incorrect_too_low" if too_low.as_ref().is_none_or(|(low, _)| bound > *low) => {
+                    too_low = Some((bound, record.answer));
+                }
+                _ => {}
+            }
+        }
+
+        let Some(numeric) = numeric else {
+            return Ok(());
+        };
+        if let Some((_, bound)) = too_high.filter(|(high, _)| numeric >= *high) {
+            return Err(ContentError::SubmissionBlocked(SubmissionBlock::TooHigh {
+                answer: answer.to_owned(),
+                bound,
+            }));
+        }
+        if let Some((_, bound)) = too_low.filter(|(low, _)| numeric <= *low) {
+            return Err(ContentError::SubmissionBlocked(SubmissionBlock::TooLow {
+                answer: answer.to_owned(),
+                bound,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Returns how long AoC still refuses answers for the puzzle, if at all.
+    pub fn submission_cooldown(&self, puzzle: PuzzleId) -> ContentResult<Option<Duration>> {
+        let expires_at = self
+            .database
+            .submission_cooldown(puzzle)
+            .map_err(ContentError::from_database)?;
+        Ok(expires_at
+            .map(|expires_at| expires_at.saturating_sub(current_unix_timestamp()))
+            .and_then(|remaining| u64::try_from(remaining).ok())
+            .filter(|remaining| *remaining > 0)
+            .map(Duration::from_secs))
+    }
+
     pub fn record_submission(
         &self,
         puzzle: PuzzleId,
         part: PuzzlePart,
+        answer: &str,
         result: &AocSubmissionResult,
     ) -> ContentResult<()> {
-        match result {
-            AocSubmissionResult::Correct => {
-                self.database
-                    .increment_submission_count(puzzle, part, true)
-                    .map_err(ContentError::from_database)?;
-            }
+        let correct = match result {
+            AocSubmissionResult::Correct => Some(true),
             AocSubmissionResult::Incorrect
             | AocSubmissionResult::IncorrectTooHigh
-            | AocSubmissionResult::IncorrectTooLow => {
-                self.database
-                    .increment_submission_count(puzzle, part, false)
-                    .map_err(ContentError::from_database)?;
-                return Ok(());
+            | AocSubmissionResult::IncorrectTooLow => Some(false),
+            _ => None,
+        };
+        let record = SubmissionRecord {
+            answer: answer.trim().to_owned(),
+            result: submission_result_name(result).to_owned(),
+            submitted_at: current_unix_timestamp(),
+        };
+        self.database
+            .record_submission(puzzle, part, &record, correct)
+            .map_err(ContentError::from_database)?;
+        let cooldown = match result {
+            AocSubmissionResult::RateLimited(seconds) => {
+                Some(i64::try_from(*seconds).unwrap_or(i64::MAX))
             }
-            _ => return Ok(()),
+            _ if correct == Some(false) => Some(INCORRECT_ANSWER_COOLDOWN_SECS),
+            _ => None,
+        };
+        if let Some(cooldown) = cooldown {
+            self.database
+                .extend_submission_cooldown(puzzle, record.submitted_at.saturating_add(cooldown))
+                .map_err(ContentError::from_database)?;
+        }
+        if correct != Some(true) {
+            return Ok(());
         }
 
+        self.database
+            .set_accepted_answer(puzzle, part, &record.answer, record.submitted_at)
+            .map_err(ContentError::from_database)?;
+        let stars = match part {
+            PuzzlePart::One => 1,
+            PuzzlePart::Two => 2,
+        };
+        self.database
+            .raise_calendar_stars(puzzle.year, &[(puzzle.day, stars)])
+            .map_err(ContentError::from_database)?;
         self.database
             .invalidate_cache_entry(CacheKey::Calendar(puzzle.year))
             .map_err(ContentError::from_database)?;
@@ -186,18 +577,112 @@ impl<'client> ContentStore<'client> {
             .checked_mul(1_000_000)
             .and_then(|duration| u64::try_from(duration).ok())
             .ok_or(ContentError::InvalidRuntime)?;
+        self.store_run_timing(
+            puzzle,
+            language,
+            part,
+            duration_nanos,
+            None,
+            retention_limit,
+        )
+    }
+
+    /// Records a benchmark run with its median as the runtime.
+    pub fn record_benchmark(
+        &self,
+        puzzle: PuzzleId,
+        language: LanguageId,
+        part: PuzzlePart,
+        benchmark: &RunBenchmark,
+        retention_limit: RunHistoryLimit,
+    ) -> ContentResult<()> {
+        let nanos = |duration: Duration| {
+            u64::try_from(duration.as_nanos()).map_err(|_| ContentError::InvalidRuntime)
+        };
+        let record = BenchmarkRecord {
+            iterations: benchmark.iterations,
+            min_nanos: nanos(benchmark.min)?,
+            mean_nanos: nanos(benchmark.mean)?,
+            stddev_nanos: nanos(benchmark.stddev)?,
+        };
+        self.store_run_timing(
+            puzzle,
+            language,
+            part,
+            nanos(benchmark.median)?,
+            Some(record),
+            retention_limit,
+        )
+    }
+
+    fn store_run_timing(
+        &self,
+        puzzle: PuzzleId,
+        language: LanguageId,
+        part: PuzzlePart,
+        duration_nanos: u64,
+        benchmark: Option<BenchmarkRecord>,
+        retention_limit: RunHistoryLimit,
+    ) -> ContentResult<()> {
         self.database
             .record_run_timing(
                 puzzle,
                 language,
                 part,
-                duration_nanos,
+                RunTimingRecord {
+                    duration_nanos,
+                    recorded_at: current_unix_timestamp(),
+                    benchmark,
+                },
                 retention_limit.get(),
-                current_unix_timestamp(),
             )
             .map_err(ContentError::from_database)
     }
 
+    /// Returns the most recently recorded runtime for the part, in milliseconds.
+    pub fn latest_runtime_ms(
+        &self,
+        puzzle: PuzzleId,
+        language: LanguageId,
+        part: PuzzlePart,
+    ) -> ContentResult<Option<u128>> {
+        Ok(self
+            .database
+            .run_timings(puzzle, language, part, 1)
+            .map_err(ContentError::from_database)?
+            .first()
+            .map(|timing| u128::from(timing.duration_nanos) / 1_000_000))
+    }
+
+    /// Returns the last `limit` recorded runs of the part, newest first.
+    pub fn run_history(
+        &self,
+        puzzle: PuzzleId,
+        language: LanguageId,
+        part: PuzzlePart,
+        limit: RunHistoryLimit,
+    ) -> ContentResult<RunHistory> {
+        let runs = self
+            .database
+            .run_timings(puzzle, language, part, limit.get())
+            .map_err(ContentError::from_database)?
+            .into_iter()
+            .map(|timing| RunTiming {
+                duration: Duration::from_nanos(timing.duration_nanos),
+                recorded_at: UNIX_EPOCH
+                    + Duration::from_secs(u64::try_from(timing.recorded_at).unwrap_or_default()),
+                benchmark: timing.benchmark.map(|benchmark| RunBenchmark {
+                    iterations: benchmark.iterations,
+                    min: Duration::from_nanos(benchmark.min_nanos),
+                    mean: Duration::from_nanos(benchmark.mean_nanos),
+                    median: Duration::from_nanos(timing.duration_nanos),
+                    stddev: Duration::from_nanos(benchmark.stddev_nanos),
+                }),
+            })
+            .collect();
+        Ok(RunHistory { runs })
+    }
+
     pub fn clean(&self, scope: CacheCleanScope) -> ContentResult<CacheCleanReport> {
         let mut report = CacheCleanReport::default();
         for entry in self
@@ -227,14 +712,90 @@ impl<'client> ContentStore<'client> {
         Ok(report)
     }
 
+    /// Records the stars shown on a calendar page; pages without a calendar are ignored.
+    fn store_calendar_stars(&self, year: PuzzleYear, html: &str) -> ContentResult<()> {
+        let Ok(calendar) = parse_calendar(html) else {
+            return Ok(());
+        };
+        let mut days: Vec<(PuzzleDay, u8)> = Vec::new();
+        for cell in calendar.rows.iter().flat_map(|row| &row.cells) {
+            let Some(puzzle) = cell.puzzle.filter(|puzzle| puzzle.year == year) else {
+                continue;
+            };
+            let stars = match cell.stars {
+                Some(CalendarStars::One) => 1,
+                Some(CalendarStars::Two) => 2,
+                None => 0,
+            };
+            match days.iter_mut().find(|(day, _)| *day == puzzle.day) {
+                Some((_, stored)) => *stored = (*stored).max(stars),
+                None => days.push((puzzle.day, stars)),
+            }
+        }
+        self.database
+            .raise_calendar_stars(year, &days)
+            .map_err(ContentError::from_database)
+    }
+
     fn load_or_fetch(&self, key: CacheKey) -> ContentResult<PathBuf> {
         if self.is_cached(key)? {
             return Ok(self.cache_path(key));
         }
 
+        self.ensure_online(key)?;
         let page = key.source_page().expect("cache only fetch source content");
-        let body = self.client.download(&page)?;
-        self.save(key, body.as_bytes())
+        let download = self.client.fetch(&page)?;
+        self.save_entry(
+            &self.fetched_entry(key, &download),
+            download.body.as_bytes(),
+        )
+    }
+
+    /// Downloads `page` for the cache entries `keys`, the first of which holds its validators.
+    ///
+    /// While every entry is cached the request is conditional, and `304 Not Modified` only
+    /// bumps their fetch time and returns `None`.
+    fn fetch_if_modified(
+        &self,
+        page: AocPage,
+        keys: &[CacheKey],
+    ) -> ContentResult<Option<Download>> {
+        let mut validators = CacheValidators::default();
+        if let Some(entry) = self
+            .database
+            .cache_entry(keys[0])
+            .map_err(ContentError::from_database)?
+        {
+            validators.etag = entry.etag;
+            validators.last_modified = entry.last_modified;
+        }
+        for key in keys {
+            if !self.is_cached(*key)? {
+                validators = CacheValidators::default();
+            }
+        }
+        if validators.is_empty() {
+            return Ok(Some(self.client.fetch(&page)?));
+        }
+
+        let download = self.client.revalidate(&page, &validators)?;
+        if download.is_none() {
+            self.database
+                .touch_cache_entries(keys, current_unix_timestamp())
+                .map_err(ContentError::from_database)?;
+        }
+        Ok(download)
+    }
+
+    /// Refuses to fetch uncached content while the client is offline.
+    fn ensure_online(&self, key: CacheKey) -> ContentResult<()> {
+        if self.client.is_offline() {
+            Err(ContentError::MissingOffline {
+                content: key.description(),
+            })
+        } else {
+            Ok(())
+        }
     }
 
     fn is_cached(&self, key: CacheKey) -> ContentResult<bool> {
@@ -265,17 +826,30 @@ impl<'client> ContentStore<'client> {
     }
 
     fn save(&self, key: CacheKey, contents: &[u8]) -> ContentResult<PathBuf> {
-        let path = self.cache_path(key);
+        self.save_entry(&self.cache_entry(key, contents.len()), contents)
+    }
+
+    fn save_entry(&self, entry: &CacheEntry, contents: &[u8]) -> ContentResult<PathBuf> {
+        let path = self.cache_path(entry.key);
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
         atomic_write(&path, contents)?;
         self.database
-            .upsert_cache_entry(&self.cache_entry(key, contents.len()))
+            .upsert_cache_entry(entry)
             .map_err(ContentError::from_database)?;
         Ok(path)
     }
 
+    /// An entry for a downloaded page, keeping the validators it was served with.
+    fn fetched_entry(&self, key: CacheKey, download: &Download) -> CacheEntry {
+        CacheEntry {
+            etag: download.validators.etag.clone(),
+            last_modified: download.validators.last_modified.clone(),
+            ..self.cache_entry(key, download.body.len())
+        }
+    }
+
     fn cache_entry(&self, key: CacheKey, byte_size: usize) -> CacheEntry {
         CacheEntry {
             key,
@@ -300,6 +874,9 @@ impl<'client> ContentStore<'client> {
             }
             CacheKey::Input(puzzle) => PathBuf::from("inputs").join(format!("{puzzle}.txt")),
             CacheKey::Calendar(year) => PathBuf::from("calendars").join(format!("year{year}.html")),
+            CacheKey::PrivateLeaderboard(year, id) => {
+                PathBuf::from("leaderboards").join(format!("year{year}_{id}.json"))
+            }
         }
     }
 }
@@ -347,7 +924,8 @@ impl CacheCleanScope {
                 CacheKey::PuzzleHtml(puzzle)
                 | CacheKey::PuzzleMarkdown(puzzle)
                 | CacheKey::Input(puzzle) => puzzle.year == year,
-                CacheKey::Calendar(calendar_year) => calendar_year == year,
+                CacheKey::Calendar(calendar_year)
+                | CacheKey::PrivateLeaderboard(calendar_year, _) => calendar_year == year,
             },
             Self::Date(puzzle) => matches!(
                 key,
@@ -360,6 +938,21 @@ impl CacheCleanScope {
     }
 }
 
+fn submission_result_name(result: &AocSubmissionResult) -> &'static str {
+    match result {
+        AocSubmissionResult::Correct => "correct",
+        AocSubmissionResult::AlreadyCompleted => "already_completed",
+        AocSubmissionResult::IncorrectTooHigh => "incorrect_too_high",
+        AocSubmissionResult::IncorrectTooLow => "incorrect_too_low",
+        AocSubmissionResult::Incorrect => "incorrect",
+        AocSubmissionResult::RateLimited(_) => "rate_limited",
+        AocSubmissionResult::Locked => "locked",
+        AocSubmissionResult::EmptySubmission => "empty_submission",
+        AocSubmissionResult::InvalidFormat => "invalid_format",
+        AocSubmissionResult::Unknown(_) => "unknown",
+    }
+}
+
 fn current_unix_timestamp() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -369,6 +962,17 @@ fn current_unix_timestamp() -> i64 {
         .unwrap_or(i64::MAX)
 }
 
+/// Why an answer was refused before it reached AoC.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SubmissionBlock {
+    #[error("{answer} was already submitted and rejected")]
+    AlreadyRejected { answer: String },
+    #[error("{answer} is not lower than {bound}, which AoC already reported as too high")]
+    TooHigh { answer: String, bound: String },
+    #[error("{answer} is not higher than {bound}, which AoC already reported as too low")]
+    TooLow { answer: String, bound: String },
+}
+
 #[derive(Debug, Error)]
 pub enum ContentError {
     #[error(transparent)]
@@ -379,8 +983,12 @@ pub enum ContentError {
     CorruptStateDatabase { detail: String },
     #[error("content state error: {0}")]
     State(String),
+    #[error("submission blocked locally: {0}")]
+    SubmissionBlocked(SubmissionBlock),
     #[error("solver runtime is too large to store")]
     InvalidRuntime,
+    #[error("working offline and {content} is not cached")]
+    MissingOffline { content: String },
     #[error("refusing to replace unmanaged cache file {path}")]
     UnmanagedCacheFile { path: PathBuf },
     #[error("content refresh persistence failed ({persistence}) and restoring {path} also failed")]
@@ -420,14 +1028,18 @@ mod tests {
         net::TcpListener,
         sync::mpsc::{self, Receiver},
         thread,
+        time::Duration,
     };
 
     use aocsuite_client::{AocClient, AocClientOptions};
     use aocsuite_parser::{AocSubmissionResult, ParserError};
-    use aocsuite_utils::{PuzzleDay, PuzzlePart, PuzzleYear};
+    use aocsuite_utils::{LanguageId, PuzzleDay, PuzzlePart, PuzzleYear, RunHistoryLimit};
     use tempfile::tempdir;
 
-    use super::{CacheCleanScope, CacheKey, ContentError, ContentStore};
+    use super::{
+        CacheCleanScope, CacheKey, ContentError, ContentStore, PuzzleAnswers, RunBenchmark,
+        RunHistory, SubmissionBlock, YearStars, LEADERBOARD_REFRESH_FLOOR,
+    };
 
     fn puzzle(day: u32, year: i32) -> aocsuite_utils::PuzzleId {
         aocsuite_utils::PuzzleId::new(
@@ -441,19 +1053,37 @@ mod tests {
     }
 
     fn serve_responses(responses: Vec<(u16, &'static str)>) -> (AocClient, Receiver<Vec<String>>) {
+        serve_session_responses(None, responses)
+    }
+
+    fn serve_session_responses(
+        session: Option<&str>,
+        responses: Vec<(u16, &'static str)>,
+    ) -> (AocClient, Receiver<Vec<String>>) {
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| (status, "", body))
+            .collect();
+        serve_responses_with_headers(session, responses)
+    }
+
+    fn serve_responses_with_headers(
+        session: Option<&str>,
+        responses: Vec<(u16, &'static str, &'static str)>,
+    ) -> (AocClient, Receiver<Vec<String>>) {
         let listener = TcpListener::bind("127.0.0.1:0").expect("bind test server");
         let address = listener.local_addr().expect("read test server address");
         let (sender, receiver) = mpsc::channel();
         thread::spawn(move || {
             let mut requests = Vec::new();
-            for (status, body) in responses {
+            for (status, headers, body) in responses {
                 let (mut stream, _) = listener.accept().expect("accept test request");
                 let mut request = [0; 4096];
                 let bytes = stream.read(&mut request).expect("read test request");
                 requests.push(String::from_utf8_lossy(&request[..bytes]).into_owned());
                 write!(
                     stream,
-                    "HTTP/1.1 {status} Test\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    "HTTP/1.1 {status} Test\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
                     body.len()
                 )
                 .expect("write test response");
@@ -461,7 +1091,7 @@ mod tests {
             sender.send(requests).expect("send test requests");
         });
         let client = AocClient::new(
-            None,
+            session,
             AocClientOptions {
                 base_url: format!("http://{address}"),
                 user_agent: "aocsuite-storage-test/1".to_owned(),
@@ -494,6 +1124,34 @@ mod tests {
         assert_eq!(requests.recv().expect("receive requests").len(), 1);
     }
 
+    #[test]
+    fn puzzle_examples_and_answers_reuse_the_cached_puzzle_html() {
+        let temp = tempdir().expect("create temporary cache root");
+        let (client, requests) = serve_responses(vec![(
+            200,
+            "<main><article><p>For example:</p><pre><code>1 2 3\n</code></pre>\
+             <p>The sum is <code><em>6</em></code>.</p></article></main>",
+        )]);
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+
+        store
+            .load_puzzle_markdown(puzzle(1, 2024))
+            .expect("load puzzle markdown");
+        let examples = store
+            .puzzle_examples(puzzle(1, 2024))
+            .expect("parse cached examples");
+
+        assert_eq!(examples, ["1 2 3\n"]);
+        let answers = store
+            .cached_example_answers(puzzle(1, 2024))
+            .expect("parse cached example answers")
+            .expect("puzzle html is cached");
+        assert_eq!(answers.part1.as_deref(), Some("6"));
+        assert_eq!(answers.part2, None);
+        assert_eq!(requests.recv().expect("receive requests").len(), 1);
+    }
+
     #[test]
     fn puzzle_markdown_download_replaces_an_existing_preview() {
         let temp = tempdir().expect("create temporary cache root");
@@ -657,6 +1315,250 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejected_answers_and_known_bounds_block_resubmission() {
+        let temp = tempdir().expect("create temporary cache root");
+        let client = client();
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let puzzle = puzzle(1, 2024);
+        for (answer, result) in [
+            ("100", AocSubmissionResult::IncorrectTooHigh),
+            ("80", AocSubmissionResult::IncorrectTooHigh),
+            ("20", AocSubmissionResult::IncorrectTooLow),
+            ("abc", AocSubmissionResult::Incorrect),
+        ] {
+            store
+                .record_submission(puzzle, PuzzlePart::One, answer, &result)
+                .expect("record rejected submission");
+        }
+
+        for (answer, expected) in [
+            (
+                " abc ",
+                SubmissionBlock::AlreadyRejected {
+                    answer: "abc".to_owned(),
+                },
+            ),
+            (
+                "90",
+                SubmissionBlock::TooHigh {
+                    answer: "90".to_owned(),
+                    bound: "80".to_owned(),
+                },
+            ),
+            (
+                "20",
+                SubmissionBlock::AlreadyRejected {
+                    answer: "20".to_owned(),
+                },
+            ),
+            (
+                "-5",
+                SubmissionBlock::TooLow {
+                    answer: "-5".to_owned(),
+                    bound: "20".to_owned(),
+                },
+            ),
+        ] {
+            assert!(matches!(
+                store.check_submission(puzzle, PuzzlePart::One, answer),
+                Err(ContentError::SubmissionBlocked(block)) if block == expected
+            ));
+        }
+        store
+            .check_submission(puzzle, PuzzlePart::One, "50")
+            .expect("answer inside the known bounds");
+        store
+            .check_submission(puzzle, PuzzlePart::Two, "90")
+            .expect("bounds are tracked per part");
+        store
+            .check_submission(puzzle, PuzzlePart::One, "def")
+            .expect("non-numeric answers skip bound checks");
+    }
+
+    #[test]
+    fn rate_limits_and_wrong_answers_persist_a_cooldown() {
+        let temp = tempdir().expect("create temporary cache root");
+        let cache_dir = temp.path().join("cache");
+        let client = client();
+        let limited = puzzle(1, 2024);
+        {
+            let store = ContentStore::open(cache_dir.clone(), &client).expect("open content store");
+            assert_eq!(store.submission_cooldown(limited).unwrap(), None);
+            store
+                .record_submission(
+                    limited,
+                    PuzzlePart::One,
+                    "1",
+                    &AocSubmissionResult::RateLimited(600),
+                )
+                .expect("record rate limit");
+            store
+                .record_submission(
+                    limited,
+                    PuzzlePart::One,
+                    "2",
+                    &AocSubmissionResult::Incorrect,
+                )
+                .expect("record wrong answer");
+        }
+
+        let store = ContentStore::open(cache_dir, &client).expect("reopen content store");
+        let remaining = store
+            .submission_cooldown(limited)
+            .expect("read cooldown")
+            .expect("cooldown survives a restart");
+        assert!(remaining.as_secs() > 60 && remaining.as_secs() <= 600);
+        assert_eq!(store.submission_cooldown(puzzle(2, 2024)).unwrap(), None);
+    }
+
+    #[test]
+    fn accepted_answers_come_from_correct_submissions_and_the_cached_page() {
+        let temp = tempdir().expect("create temporary cache root");
+        let client = client();
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let solved = puzzle(1, 2024);
+        assert_eq!(
+            store.accepted_answers(solved).unwrap(),
+            PuzzleAnswers::default()
+        );
+
+        store
+            .record_submission(
+                solved,
+                PuzzlePart::One,
+                " 42 ",
+                &AocSubmissionResult::Correct,
+            )
+            .expect("record correct answer");
+        store
+            .save(
+                CacheKey::PuzzleHtml(solved),
+                b"<main><p>Your puzzle answer was <code>41</code>.</p>\
+                <p>Your puzzle answer was <code>99</code>.</p></main>",
+            )
+            .expect("save solved puzzle page");
+        assert_eq!(
+            store.accepted_answers(solved).unwrap().part2,
+            None,
+            "reading does not learn answers from the page"
+        );
+        store
+            .backfill_accepted_answers(solved)
+            .expect("backfill from the cached page");
+
+        let expected = PuzzleAnswers {
+            part1: Some("42".to_owned()),
+            part2: Some("99".to_owned()),
+        };
+        assert_eq!(store.accepted_answers(solved).unwrap(), expected);
+        fs::remove_file(store.cache_path(CacheKey::PuzzleHtml(solved)))
+            .expect("remove cached page");
+        store
+            .database
+            .invalidate_cache_entry(CacheKey::PuzzleHtml(solved))
+            .expect("invalidate cached page");
+        assert_eq!(store.accepted_answers(solved).unwrap(), expected);
+        assert_eq!(expected.matches(PuzzlePart::Two, "98"), Some(false));
+    }
+
+    #[test]
+    fn calendar_stars_are_stored_from_fetched_calendars_and_correct_answers() {
+        let calendar = concat!(
+            r#"<pre class="calendar">"#,
+            r#"<a href="/2017/day/1" class="calendar-day1 calendar-verycomplete">one</a>"#,
+            "\n",
+            r#"<a href="/2017/day/2" class="calendar-day2 calendar-complete">two</a>"#,
+            "\n",
+            r#"<a href="/2017/day/3" class="calendar-day3">three</a>"#,
+            "</pre>",
+        );
+        let (client, _) = serve_responses(vec![(200, calendar)]);
+        let temp = tempdir().expect("create temporary cache root");
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let year = PuzzleYear::new(2017).expect("valid year");
+        assert_eq!(store.year_stars(year).unwrap(), YearStars::default());
+
+        store.load_calendar(year).expect("fetch calendar");
+        store
+            .record_submission(
+                puzzle(3, 2017),
+                PuzzlePart::One,
+                "9",
+                &AocSubmissionResult::Correct,
+            )
+            .expect("record correct answer");
+        store
+            .record_submission(
+                puzzle(1, 2017),
+                PuzzlePart::One,
+                "4",
+                &AocSubmissionResult::Correct,
+            )
+            .expect("record repeated answer");
+
+        let stars = store.year_stars(year).expect("read stars");
+        assert_eq!(stars.day(PuzzleDay::new(1).unwrap()), 2);
+        assert_eq!(stars.day(PuzzleDay::new(2).unwrap()), 1);
+        assert_eq!(stars.day(PuzzleDay::new(3).unwrap()), 1);
+        assert_eq!(stars.day(PuzzleDay::new(4).unwrap()), 0);
+        assert_eq!((stars.total(), stars.completed_days()), (4, 1));
+    }
+
+    #[test]
+    fn run_history_returns_recent_runs_with_summary_statistics() {
+        let temp = tempdir().expect("create temporary cache root");
+        let client = client();
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let timed = puzzle(4, 2024);
+        let limit = |runs| RunHistoryLimit::new(runs).expect("valid history limit");
+        for runtime_ms in [40, 10, 30, 20] {
+            store
+                .record_run_timing(
+                    timed,
+                    LanguageId::Rust,
+                    PuzzlePart::One,
+                    runtime_ms,
+                    limit(3),
+                )
+                .expect("record run timing");
+        }
+
+        let history = store
+            .run_history(timed, LanguageId::Rust, PuzzlePart::One, limit(10))
+            .expect("read run history");
+        let durations = history
+            .runs
+            .iter()
+            .map(|run| run.duration.as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(durations, [20, 30, 10]);
+        assert_eq!(history.min(), Some(Duration::from_millis(10)));
+        assert_eq!(history.median(), Some(Duration::from_millis(20)));
+        assert_eq!(history.max(), Some(Duration::from_millis(30)));
+
+        let recent = store
+            .run_history(timed, LanguageId::Rust, PuzzlePart::One, limit(2))
+            .expect("read limited history");
+        assert_eq!(recent.median(), Some(Duration::from_millis(25)));
+        assert_eq!(
+            store
+                .run_history(timed, LanguageId::Python, PuzzlePart::One, limit(2))
+                .expect("read empty history"),
+            RunHistory::default()
+        );
+        assert_eq!(
+            store
+                .latest_runtime_ms(timed, LanguageId::Rust, PuzzlePart::One)
+                .unwrap(),
+            Some(20)
+        );
+    }
+
     #[test]
     fn recording_a_correct_submission_invalidates_affected_cache_entries() {
         let temp = tempdir().expect("create temporary cache root");
@@ -675,7 +1577,7 @@ mod tests {
         }
 
         store
-            .record_submission(puzzle, PuzzlePart::One, &AocSubmissionResult::Correct)
+            .record_submission(puzzle, PuzzlePart::One, "42", &AocSubmissionResult::Correct)
             .expect("record submission");
 
         assert!(!store
@@ -688,4 +1590,215 @@ mod tests {
             .is_cached(CacheKey::PuzzleMarkdown(puzzle))
             .expect("check puzzle markdown cache"));
     }
+
+    #[test]
+    fn benchmarks_are_recorded_with_their_median_as_runtime() {
+        let temp = tempdir().expect("create temporary cache root");
+        let client = client();
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let timed = puzzle(5, 2024);
+        let limit = RunHistoryLimit::new(5).expect("valid history limit");
+        let benchmark = RunBenchmark {
+            iterations: 100,
+            min: Duration::from_nanos(1_200),
+            mean: Duration::from_nanos(1_530),
+            median: Duration::from_nanos(1_450),
+            stddev: Duration::from_nanos(210),
+        };
+        store
+            .record_run_timing(timed, LanguageId::Rust, PuzzlePart::Two, 3, limit)
+            .expect("record single run");
+        store
+            .record_benchmark(timed, LanguageId::Rust, PuzzlePart::Two, &benchmark, limit)
+            .expect("record benchmark");
+
+        let history = store
+            .run_history(timed, LanguageId::Rust, PuzzlePart::Two, limit)
+            .expect("read run history");
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(history.runs[0].duration, Duration::from_nanos(1_450));
+        assert_eq!(history.runs[0].benchmark, Some(benchmark));
+        assert_eq!(history.runs[1].duration, Duration::from_millis(3));
+        assert_eq!(history.runs[1].benchmark, None);
+        assert_eq!(
+            store
+                .latest_runtime_ms(timed, LanguageId::Rust, PuzzlePart::Two)
+                .unwrap(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn private_leaderboards_are_refetched_only_after_the_refresh_floor() {
+        const FIRST: &str = r#"{"owner_id":1,"members":{"1":{"id":1,"name":"owner","stars":1,"local_score":1,"last_star_ts":1,"completion_day_level":{"1":{"1":{"get_star_ts":1}}}}}}"#;
+        const SECOND: &str = r#"{"owner_id":1,"members":{"1":{"id":1,"name":"owner","stars":2,"local_score":2,"last_star_ts":2,"completion_day_level":{}}}}"#;
+        let temp = tempdir().expect("create temporary cache root");
+        let (client, requests) = serve_session_responses(
+            Some("test-session"),
+            vec![(200, FIRST), (200, SECOND), (200, FIRST)],
+        );
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let year = PuzzleYear::new(2024).expect("valid year");
+        assert_eq!(store.cached_private_leaderboard(year, 1).unwrap(), None);
+        assert_eq!(store.leaderboard_refresh_in(year, 1).unwrap(), None);
+
+        let first = store
+            .private_leaderboard(year, 1)
+            .expect("fetch leaderboard");
+        assert_eq!(first.members[0].stars, 1);
+        assert_eq!(store.private_leaderboard(year, 1).unwrap(), first);
+        let remaining = store
+            .leaderboard_refresh_in(year, 1)
+            .unwrap()
+            .expect("refresh is deferred");
+        assert!(remaining > LEADERBOARD_REFRESH_FLOOR - Duration::from_secs(60));
+
+        let key = CacheKey::PrivateLeaderboard(year, 1);
+        let mut entry = store.database.cache_entry(key).unwrap().expect("indexed");
+        entry.fetched_at = entry
+            .fetched_at
+            .map(|fetched_at| fetched_at - LEADERBOARD_REFRESH_FLOOR.as_secs() as i64);
+        store.database.upsert_cache_entry(&entry).unwrap();
+        assert_eq!(store.leaderboard_refresh_in(year, 1).unwrap(), None);
+
+        let second = store
+            .private_leaderboard(year, 1)
+            .expect("refetch leaderboard");
+        assert_eq!(second.members[0].stars, 2);
+        assert_eq!(
+            store.cached_private_leaderboard(year, 1).unwrap(),
+            Some(second)
+        );
+
+        fs::write(store.cache_path(key), "{").expect("corrupt cached leaderboard");
+        let refetched = store
+            .private_leaderboard(year, 1)
+            .expect("refetch corrupt leaderboard");
+        assert_eq!(refetched, first);
+        let requests = requests.recv().expect("receive requests");
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("GET /2024/leaderboard/private/view/1.json"));
+
+        store
+            .clean(CacheCleanScope::Year(year))
+            .expect("clean year");
+        assert_eq!(store.cached_private_leaderboard(year, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn refreshes_revalidate_cached_pages_and_keep_them_on_not_modified() {
+        let temp = tempdir().expect("create temporary cache root");
+        let page = "<main><article><h2>--- Day 1: Cached ---</h2><p>Text.</p></article></main>";
+        let (client, requests) = serve_responses_with_headers(
+            None,
+            vec![
+                (200, "ETag: \"cal-1\"\r\n", "<p>calendar</p>"),
+                (304, "", ""),
+                (
+                    200,
+                    "Last-Modified: Sun, 01 Dec 2024 05:00:00 GMT\r\n",
+                    page,
+                ),
+                (304, "", ""),
+                (200, "", "<p>calendar after a star</p>"),
+            ],
+        );
+        let store =
+            ContentStore::open(temp.path().join("cache"), &client).expect("open content store");
+        let year = PuzzleYear::new(2024).unwrap();
+        let puzzle = puzzle(1, 2024);
+        let calendar = CacheKey::Calendar(year);
+        let age_entry = |key| {
+            let mut entry = store.database.cache_entry(key).unwrap().expect("indexed");
+            entry.fetched_at = Some(0);
+            store.database.upsert_cache_entry(&entry).unwrap();
+        };
+
+        store.load_calendar(year).expect("fetch calendar");
+        age_entry(calendar);
+        assert_eq!(store.refresh_calendar(year).unwrap(), "<p>calendar</p>");
+        let entry = store.database.cache_entry(calendar).unwrap().unwrap();
+        assert_eq!(entry.etag.as_deref(), Some("\"cal-1\""));
+        assert!(entry.fetched_at.is_some_and(|fetched_at| fetched_at > 0));
+
+        let markdown = store
+            .download_puzzle_markdown(puzzle)
+            .expect("fetch puzzle");
+        age_entry(CacheKey::PuzzleMarkdown(puzzle));
+        assert_eq!(store.download_puzzle_markdown(puzzle).unwrap(), markdown);
+        let entry = store
+            .database
+            .cache_entry(CacheKey::PuzzleMarkdown(puzzle))
+            .unwrap()
+            .unwrap();
+        assert!(entry.fetched_at.is_some_and(|fetched_at| fetched_at > 0));
+
+        store
+            .record_submission(puzzle, PuzzlePart::One, "1", &AocSubmissionResult::Correct)
+            .expect("record correct answer");
+        assert_eq!(
+            store.refresh_calendar(year).unwrap(),
+            "<p>calendar after a star</p>"
+        );
+
+        let requests: Vec<_> = requests
+            .recv()
+            .unwrap()
+            .into_iter()
+            .map(|request| request.to_ascii_lowercase())
+            .collect();
+        assert!(!requests[0].contains("if-none-match"));
+        assert!(requests[1].contains("if-none-match: \"cal-1\"\r\n"));
+        assert!(requests[3].contains("if-modified-since: sun, 01 dec 2024 05:00:00 gmt\r\n"));
+        assert!(!requests[4].contains("if-none-match"));
+    }
+
+    #[test]
+    fn offline_stores_serve_cached_content_and_name_what_is_missing() {
+        let temp = tempdir().expect("create temporary cache root");
+        let cache_dir = temp.path().join("cache");
+        let (client, _) = serve_session_responses(
+            Some("test-session"),
+            vec![(200, "1\n2\n"), (200, "<p>calendar</p>")],
+        );
+        let cached = puzzle(1, 2024);
+        let year = PuzzleYear::new(2024).unwrap();
+        {
+            let store = ContentStore::open(cache_dir.clone(), &client).expect("open content store");
+            store.ensure_input(cached).expect("fetch input");
+            store.load_calendar(year).expect("fetch calendar");
+        }
+        let offline = AocClient::new(
+            None,
+            AocClientOptions {
+                offline: true,
+                ..AocClientOptions::default()
+            },
+        )
+        .expect("create offline client");
+        let store = ContentStore::open(cache_dir, &offline).expect("open content store");
+
+        assert_eq!(
+            fs::read_to_string(store.ensure_input(cached).unwrap()).unwrap(),
+            "1\n2\n"
+        );
+        assert_eq!(store.refresh_calendar(year).unwrap(), "<p>calendar</p>");
+        assert_eq!(
+            store.ensure_input(puzzle(2, 2024)).unwrap_err().to_string(),
+            "working offline and the input of 2024 day 2 is not cached"
+        );
+        assert_eq!(
+            store
+                .download_puzzle_markdown(cached)
+                .unwrap_err()
+                .to_string(),
+            "working offline and the description of 2024 day 1 is not cached"
+        );
+        assert!(matches!(
+            store.private_leaderboard(year, 7),
+            Err(ContentError::MissingOffline { .. })
+        ));
+    }
 }
@@ -1,12 +1,12 @@
 use std::path::{Component, Path, PathBuf};
 
-use aocsuite_utils::{LanguageId, PuzzleId, PuzzlePart, PuzzleYear};
+use aocsuite_utils::{LanguageId, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear};
 use rusqlite::{params, Connection, ErrorCode, OptionalExtension, Transaction};
 use thiserror::Error;
 
 use crate::content::CacheKey;
 
-const SCHEMA_VERSION: u32 = 1;
+const SCHEMA_VERSION: u32 = 6;
 
 pub(crate) struct StateDatabase {
     connection: Connection,
@@ -23,6 +23,29 @@ pub(crate) struct CacheEntry {
     pub is_valid: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SubmissionRecord {
+    pub answer: String,
+    pub result: String,
+    pub submitted_at: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RunTimingRecord {
+    pub duration_nanos: u64,
+    pub recorded_at: i64,
+    pub benchmark: Option<BenchmarkRecord>,
+}
+
+/// Aggregates of a benchmark run; the median is stored as the run's `duration_nanos`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BenchmarkRecord {
+    pub iterations: u32,
+    pub min_nanos: u64,
+    pub mean_nanos: u64,
+    pub stddev_nanos: u64,
+}
+
 impl StateDatabase {
     pub(crate) fn open(path: &Path) -> DatabaseResult<Self> {
         Self::open_database(path)
@@ -37,16 +60,16 @@ impl StateDatabase {
     }
 
     pub(crate) fn cache_entry(&self, key: CacheKey) -> DatabaseResult<Option<CacheEntry>> {
-        let (content_type, year, day) = cache_key_parts(key);
+        let (content_type, year, day, leaderboard_id) = cache_key_parts(key);
         let entry = self
             .connection
             .query_row(
                 "
                 SELECT relative_path, byte_size, fetched_at, etag, last_modified, is_valid
                 FROM cache_entries
-                WHERE content_type = ?1 AND year = ?2 AND day = ?3
+                WHERE content_type = ?1 AND year = ?2 AND day = ?3 AND leaderboard_id = ?4
                 ",
-                params![content_type, year, day],
+                params![content_type, year, day, leaderboard_id],
                 |row| {
                     let relative_path = PathBuf::from(row.get::<_, String>(0)?);
                     let byte_size = row.get::<_, i64>(1)?;
@@ -91,7 +114,8 @@ impl StateDatabase {
     pub(crate) fn cache_entries(&self) -> DatabaseResult<Vec<CacheEntry>> {
         let mut statement = self.connection.prepare(
             "
-            SELECT content_type, year, day, relative_path, byte_size, fetched_at, etag, last_modified, is_valid
+            SELECT content_type, year, day, leaderboard_id, relative_path, byte_size, fetched_at, etag,
+                last_modified, is_valid
             FROM cache_entries
             ",
         )?;
@@ -101,12 +125,13 @@ impl StateDatabase {
                     row.get::<_, String>(0)?,
                     row.get::<_, i32>(1)?,
                     row.get::<_, i64>(2)?,
-                    PathBuf::from(row.get::<_, String>(3)?),
-                    row.get::<_, i64>(4)?,
-                    row.get(5)?,
+                    row.get::<_, i64>(3)?,
+                    PathBuf::from(row.get::<_, String>(4)?),
+                    row.get::<_, i64>(5)?,
                     row.get(6)?,
                     row.get(7)?,
-                    row.get::<_, i64>(8)?,
+                    row.get(8)?,
+                    row.get::<_, i64>(9)?,
                 ))
             })?
             .map(|entry| {
@@ -114,6 +139,7 @@ impl StateDatabase {
                     content_type,
                     year,
                     day,
+                    leaderboard_id,
                     relative_path,
                     byte_size,
                     fetched_at,
@@ -121,7 +147,7 @@ impl StateDatabase {
                     last_modified,
                     is_valid,
                 ) = entry?;
-                let key = cache_key_from_parts(&content_type, year, day)?;
+                let key = cache_key_from_parts(&content_type, year, day, leaderboard_id)?;
                 let relative_path = validated_relative_path(relative_path)?;
                 let byte_size = u64::try_from(byte_size)
                     .map_err(|_| DatabaseError::InvalidCacheEntry("negative byte size"))?;
@@ -158,69 +184,316 @@ impl StateDatabase {
     }
 
     pub(crate) fn remove_cache_entry(&self, key: CacheKey) -> DatabaseResult<bool> {
-        let (content_type, year, day) = cache_key_parts(key);
+        let (content_type, year, day, leaderboard_id) = cache_key_parts(key);
         Ok(self.connection.execute(
-            "DELETE FROM cache_entries WHERE content_type = ?1 AND year = ?2 AND day = ?3",
-            params![content_type, year, day],
+            "
+            DELETE FROM cache_entries
+            WHERE content_type = ?1 AND year = ?2 AND day = ?3 AND leaderboard_id = ?4
+            ",
+            params![content_type, year, day, leaderboard_id],
         )? > 0)
     }
 
     pub(crate) fn invalidate_cache_entry(&self, key: CacheKey) -> DatabaseResult<bool> {
-        let (content_type, year, day) = cache_key_parts(key);
+        let (content_type, year, day, leaderboard_id) = cache_key_parts(key);
         Ok(self.connection.execute(
             "
             UPDATE cache_entries
             SET is_valid = 0
-            WHERE content_type = ?1 AND year = ?2 AND day = ?3 AND is_valid = 1
+            WHERE content_type = ?1 AND year = ?2 AND day = ?3 AND leaderboard_id = ?4
+                AND is_valid = 1
             ",
-            params![content_type, year, day],
+            params![content_type, year, day, leaderboard_id],
         )? > 0)
     }
 
-    pub(crate) fn increment_submission_count(
+    /// Marks still-valid entries as fetched at `fetched_at`, keeping their files and validators.
+    pub(crate) fn touch_cache_entries(
+        &self,
+        keys: &[CacheKey],
+        fetched_at: i64,
+    ) -> DatabaseResult<()> {
+        let transaction = self.connection.unchecked_transaction()?;
+        for key in keys {
+            let (content_type, year, day, leaderboard_id) = cache_key_parts(*key);
+            transaction.execute(
+                "
+                UPDATE cache_entries
+                SET fetched_at = ?5
+                WHERE content_type = ?1 AND year = ?2 AND day = ?3 AND leaderboard_id = ?4
+                    AND is_valid = 1
+                ",
+                params![content_type, year, day, leaderboard_id, fetched_at],
+            )?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Records a submission event and, for correct or incorrect answers, its count.
+    pub(crate) fn record_submission(
+        &self,
+        puzzle: PuzzleId,
+        part: PuzzlePart,
+        record: &SubmissionRecord,
+        correct: Option<bool>,
+    ) -> DatabaseResult<()> {
+        let transaction = self.connection.unchecked_transaction()?;
+        transaction.execute(
+            "
+            INSERT INTO submissions (year, day, part, answer, result, submitted_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ",
+            params![
+                puzzle.year.get(),
+                puzzle.day.get(),
+                puzzle_part_value(part),
+                record.answer,
+                record.result,
+                record.submitted_at,
+            ],
+        )?;
+        if let Some(correct) = correct {
+            increment_submission_count(&transaction, puzzle, part, correct)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn submissions(
+        &self,
+        puzzle: PuzzleId,
+        part: PuzzlePart,
+    ) -> DatabaseResult<Vec<SubmissionRecord>> {
+        let mut statement = self.connection.prepare(
+            "
+            SELECT answer, result, submitted_at
+            FROM submissions
+            WHERE year = ?1 AND day = ?2 AND part = ?3
+            ORDER BY submitted_at, id
+            ",
+        )?;
+        let records = statement
+            .query_map(
+                params![puzzle.year.get(), puzzle.day.get(), puzzle_part_value(part)],
+                |row| {
+                    Ok(SubmissionRecord {
+                        answer: row.get(0)?,
+                        result: row.get(1)?,
+                        submitted_at: row.get(2)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(records)
+    }
+
+    /// Extends the puzzle's cooldown to `expires_at` unless a later expiry is stored.
+    pub(crate) fn extend_submission_cooldown(
+        &self,
+        puzzle: PuzzleId,
+        expires_at: i64,
+    ) -> DatabaseResult<()> {
+        self.connection.execute(
+            "
+            INSERT INTO submission_cooldowns (year, day, expires_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT (year, day) DO UPDATE SET
+                expires_at = max(expires_at, excluded.expires_at)
+            ",
+            params![puzzle.year.get(), puzzle.day.get(), expires_at],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn submission_cooldown(&self, puzzle: PuzzleId) -> DatabaseResult<Option<i64>> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT expires_at FROM submission_cooldowns WHERE year = ?1 AND day = ?2",
+                params![puzzle.year.get(), puzzle.day.get()],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Raises the stored star counts of a year's days; stars are never taken away.
+    pub(crate) fn raise_calendar_stars(
+        &self,
+        year: PuzzleYear,
+        stars: &[(PuzzleDay, u8)],
+    ) -> DatabaseResult<()> {
+        let transaction = self.connection.unchecked_transaction()?;
+        for (day, stars) in stars {
+            transaction.execute(
+                "
+                INSERT INTO calendar_stars (year, day, stars)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT (year, day) DO UPDATE SET
+                    stars = max(stars, excluded.stars)
+                ",
+                params![year.get(), day.get(), stars],
+            )?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn calendar_stars(&self, year: PuzzleYear) -> DatabaseResult<Vec<(PuzzleDay, u8)>> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT day, stars FROM calendar_stars WHERE year = ?1 ORDER BY day")?;
+        let stars = statement
+            .query_map(params![year.get()], |row| {
+                Ok((row.get::<_, u32>(0)?, row.get::<_, u8>(1)?))
+            })?
+            .map(|row| {
+                let (day, stars) = row?;
+                let day = PuzzleDay::new(day)
+                    .map_err(|_| DatabaseError::InvalidStars("invalid puzzle day"))?;
+                Ok((day, stars))
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        Ok(stars)
+    }
+
+    /// Stores the answer AoC accepted for the part, replacing any earlier one.
+    pub(crate) fn set_accepted_answer(
         &self,
         puzzle: PuzzleId,
         part: PuzzlePart,
-        correct: bool,
+        answer: &str,
+        recorded_at: i64,
     ) -> DatabaseResult<()> {
-        let (correct_count, incorrect_count) = if correct { (1, 0) } else { (0, 1) };
         self.connection.execute(
             "
-            INSERT INTO submission_counts (year, day, part, correct_count, incorrect_count)
+            INSERT INTO accepted_answers (year, day, part, answer, recorded_at)
             VALUES (?1, ?2, ?3, ?4, ?5)
             ON CONFLICT (year, day, part) DO UPDATE SET
-                correct_count = correct_count + excluded.correct_count,
-                incorrect_count = incorrect_count + excluded.incorrect_count
+                answer = excluded.answer,
+                recorded_at = excluded.recorded_at
             ",
             params![
                 puzzle.year.get(),
                 puzzle.day.get(),
                 puzzle_part_value(part),
-                correct_count,
-                incorrect_count,
+                answer,
+                recorded_at,
             ],
         )?;
         Ok(())
     }
 
+    pub(crate) fn accepted_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: PuzzlePart,
+    ) -> DatabaseResult<Option<String>> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT answer FROM accepted_answers WHERE year = ?1 AND day = ?2 AND part = ?3",
+                params![puzzle.year.get(), puzzle.day.get(), puzzle_part_value(part)],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Returns up to `limit` retained timings, newest first.
+    pub(crate) fn run_timings(
+        &self,
+        puzzle: PuzzleId,
+        language: LanguageId,
+        part: PuzzlePart,
+        limit: usize,
+    ) -> DatabaseResult<Vec<RunTimingRecord>> {
+        let limit = i64::try_from(limit)
+            .map_err(|_| DatabaseError::InvalidTiming("limit exceeds SQLite range"))?;
+        let mut statement = self.connection.prepare(
+            "
+            SELECT duration_nanos, recorded_at, iterations, min_nanos, mean_nanos, stddev_nanos
+            FROM run_timings
+            WHERE year = ?1 AND day = ?2 AND language = ?3 AND part = ?4
+            ORDER BY recorded_at DESC, id DESC
+            LIMIT ?5
+            ",
+        )?;
+        let timings = statement
+            .query_map(
+                params![
+                    puzzle.year.get(),
+                    puzzle.day.get(),
+                    language.to_string(),
+                    puzzle_part_value(part),
+                    limit,
+                ],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, Option<i64>>(2)?,
+                        row.get::<_, Option<i64>>(3)?,
+                        row.get::<_, Option<i64>>(4)?,
+                        row.get::<_, Option<i64>>(5)?,
+                    ))
+                },
+            )?
+            .map(|row| {
+                let (duration_nanos, recorded_at, iterations, min_nanos, mean_nanos, stddev_nanos) =
+                    row?;
+                let benchmark = match (iterations, min_nanos, mean_nanos, stddev_nanos) {
+                    (Some(iterations), Some(min_nanos), Some(mean_nanos), Some(stddev_nanos)) => {
+                        Some(BenchmarkRecord {
+                            iterations: u32::try_from(iterations).map_err(|_| {
+                                DatabaseError::InvalidTiming("invalid stored iteration count")
+                            })?,
+                            min_nanos: stored_nanos(min_nanos)?,
+                            mean_nanos: stored_nanos(mean_nanos)?,
+                            stddev_nanos: stored_nanos(stddev_nanos)?,
+                        })
+                    }
+                    _ => None,
+                };
+                Ok(RunTimingRecord {
+                    duration_nanos: stored_nanos(duration_nanos)?,
+                    recorded_at,
+                    benchmark,
+                })
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        Ok(timings)
+    }
+
     pub(crate) fn record_run_timing(
         &self,
         puzzle: PuzzleId,
         language: LanguageId,
         part: PuzzlePart,
-        duration_nanos: u64,
+        timing: RunTimingRecord,
         retention_limit: usize,
-        recorded_at: i64,
     ) -> DatabaseResult<()> {
-        let duration_nanos = i64::try_from(duration_nanos)
-            .map_err(|_| DatabaseError::InvalidTiming("duration exceeds SQLite range"))?;
+        let duration_nanos = sqlite_nanos(timing.duration_nanos)?;
+        let benchmark = timing
+            .benchmark
+            .map(|benchmark| {
+                Ok::<_, DatabaseError>((
+                    i64::from(benchmark.iterations),
+                    sqlite_nanos(benchmark.min_nanos)?,
+                    sqlite_nanos(benchmark.mean_nanos)?,
+                    sqlite_nanos(benchmark.stddev_nanos)?,
+                ))
+            })
+            .transpose()?;
         let retention_limit = i64::try_from(retention_limit)
             .map_err(|_| DatabaseError::InvalidTiming("retention limit exceeds SQLite range"))?;
         let transaction = self.connection.unchecked_transaction()?;
         transaction.execute(
             "
-            INSERT INTO run_timings (year, day, language, part, duration_nanos, recorded_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            INSERT INTO run_timings (
+                year, day, language, part, duration_nanos, recorded_at,
+                iterations, min_nanos, mean_nanos, stddev_nanos
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
             ",
             params![
                 puzzle.year.get(),
@@ -228,7 +501,11 @@ impl StateDatabase {
                 language.to_string(),
                 puzzle_part_value(part),
                 duration_nanos,
-                recorded_at,
+                timing.recorded_at,
+                benchmark.map(|benchmark| benchmark.0),
+                benchmark.map(|benchmark| benchmark.1),
+                benchmark.map(|benchmark| benchmark.2),
+                benchmark.map(|benchmark| benchmark.3),
             ],
         )?;
         transaction.execute(
@@ -254,17 +531,44 @@ impl StateDatabase {
     }
 }
 
+fn increment_submission_count(
+    connection: &Connection,
+    puzzle: PuzzleId,
+    part: PuzzlePart,
+    correct: bool,
+) -> DatabaseResult<()> {
+    let (correct_count, incorrect_count) = if correct { (1, 0) } else { (0, 1) };
+    connection.execute(
+        "
+        INSERT INTO submission_counts (year, day, part, correct_count, incorrect_count)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT (year, day, part) DO UPDATE SET
+            correct_count = correct_count + excluded.correct_count,
+            incorrect_count = incorrect_count + excluded.incorrect_count
+        ",
+        params![
+            puzzle.year.get(),
+            puzzle.day.get(),
+            puzzle_part_value(part),
+            correct_count,
+            incorrect_count,
+        ],
+    )?;
+    Ok(())
+}
+
 fn upsert_cache_entry(connection: &Connection, entry: &CacheEntry) -> DatabaseResult<()> {
     let relative_path = validated_relative_path(entry.relative_path.clone())?;
     let byte_size = i64::try_from(entry.byte_size)
         .map_err(|_| DatabaseError::InvalidCacheEntry("byte size exceeds SQLite range"))?;
-    let (content_type, year, day) = cache_key_parts(entry.key);
+    let (content_type, year, day, leaderboard_id) = cache_key_parts(entry.key);
     connection.execute(
-            "
+        "
             INSERT INTO cache_entries (
-                content_type, year, day, relative_path, byte_size, fetched_at, etag, last_modified, is_valid
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
-            ON CONFLICT (content_type, year, day) DO UPDATE SET
+                content_type, year, day, leaderboard_id, relative_path, byte_size, fetched_at, etag,
+                last_modified, is_valid
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            ON CONFLICT (content_type, year, day, leaderboard_id) DO UPDATE SET
                 relative_path = excluded.relative_path,
                 byte_size = excluded.byte_size,
                 fetched_at = excluded.fetched_at,
@@ -272,45 +576,61 @@ fn upsert_cache_entry(connection: &Connection, entry: &CacheEntry) -> DatabaseRe
                 last_modified = excluded.last_modified,
                 is_valid = excluded.is_valid
             ",
-            params![
-                content_type,
-                year,
-                day,
-                relative_path.to_string_lossy(),
-                byte_size,
-                entry.fetched_at,
-                entry.etag,
-                entry.last_modified,
-                i64::from(entry.is_valid),
-            ],
-        )?;
+        params![
+            content_type,
+            year,
+            day,
+            leaderboard_id,
+            relative_path.to_string_lossy(),
+            byte_size,
+            entry.fetched_at,
+            entry.etag,
+            entry.last_modified,
+            i64::from(entry.is_valid),
+        ],
+    )?;
     Ok(())
 }
 
-fn cache_key_parts(key: CacheKey) -> (&'static str, i32, i64) {
-    match key {
-        CacheKey::PuzzleHtml(puzzle) => (
-            "puzzle_html",
-            puzzle.year.get(),
-            i64::from(puzzle.day.get()),
-        ),
-        CacheKey::PuzzleMarkdown(puzzle) => (
-            "puzzle_markdown",
+/// Columns of the cache key: content type, year, day and leaderboard id, with `0` for the
+/// parts a content type does not have.
+fn cache_key_parts(key: CacheKey) -> (&'static str, i32, i64, i64) {
+    let puzzle_parts = |content_type, puzzle: PuzzleId| {
+        (
+            content_type,
             puzzle.year.get(),
             i64::from(puzzle.day.get()),
-        ),
-        CacheKey::Input(puzzle) => ("input", puzzle.year.get(), i64::from(puzzle.day.get())),
-        CacheKey::Calendar(year) => ("calendar", year.get(), 0),
+            0,
+        )
+    };
+    match key {
+        CacheKey::PuzzleHtml(puzzle) => puzzle_parts("puzzle_html", puzzle),
+        CacheKey::PuzzleMarkdown(puzzle) => puzzle_parts("puzzle_markdown", puzzle),
+        CacheKey::Input(puzzle) => puzzle_parts("input", puzzle),
+        CacheKey::Calendar(year) => ("calendar", year.get(), 0, 0),
+        CacheKey::PrivateLeaderboard(year, id) => {
+            ("private_leaderboard", year.get(), 0, i64::from(id))
+        }
     }
 }
 
-fn cache_key_from_parts(content_type: &str, year: i32, day: i64) -> DatabaseResult<CacheKey> {
+fn cache_key_from_parts(
+    content_type: &str,
+    year: i32,
+    day: i64,
+    leaderboard_id: i64,
+) -> DatabaseResult<CacheKey> {
+    if content_type != "private_leaderboard" && leaderboard_id != 0 {
+        return Err(DatabaseError::InvalidCacheEntry(
+            "unexpected leaderboard id",
+        ));
+    }
     let year = PuzzleYear::new(year)
         .map_err(|_| DatabaseError::InvalidCacheEntry("invalid puzzle year"))?;
     let puzzle = || -> DatabaseResult<PuzzleId> {
         let day = u32::try_from(day)
             .ok()
-            .and_then(|day| aocsuite_utils::PuzzleDay::new(day).ok())
+            .and_then(|day| PuzzleDay::new(day).ok())
             .ok_or(DatabaseError::InvalidCacheEntry("invalid puzzle day"))?;
         Ok::<PuzzleId, DatabaseError>(PuzzleId::new(day, year))
     };
@@ -320,6 +640,12 @@ fn cache_key_from_parts(content_type: &str, year: i32, day: i64) -> DatabaseResu
         "input" => Ok(CacheKey::Input(puzzle()?)),
         "calendar" if day == 0 => Ok(CacheKey::Calendar(year)),
         "calendar" => Err(DatabaseError::InvalidCacheEntry("invalid calendar day")),
+        "private_leaderboard" if day == 0 => u32::try_from(leaderboard_id)
+            .ok()
+            .filter(|id| *id > 0)
+            .map(|id| CacheKey::PrivateLeaderboard(year, id))
+            .ok_or(DatabaseError::InvalidCacheEntry("invalid leaderboard id")),
+        "private_leaderboard" => Err(DatabaseError::InvalidCacheEntry("invalid leaderboard day")),
         _ => Err(DatabaseError::InvalidCacheEntry("invalid content type")),
     }
 }
@@ -367,9 +693,24 @@ fn migrate(connection: &Connection) -> DatabaseResult<()> {
     }
 
     let transaction = connection.unchecked_transaction()?;
-    if version == 0 {
+    if version < 1 {
         migrate_to_version_one(&transaction)?;
     }
+    if version < 2 {
+        migrate_to_version_two(&transaction)?;
+    }
+    if version < 3 {
+        migrate_to_version_three(&transaction)?;
+    }
+    if version < 4 {
+        migrate_to_version_four(&transaction)?;
+    }
+    if version < 5 {
+        migrate_to_version_five(&transaction)?;
+    }
+    if version < 6 {
+        migrate_to_version_six(&transaction)?;
+    }
     transaction.pragma_update(None, "user_version", SCHEMA_VERSION)?;
     transaction.commit()?;
     Ok(())
@@ -424,6 +765,114 @@ fn migrate_to_version_one(transaction: &Transaction<'_>) -> DatabaseResult<()> {
     Ok(())
 }
 
+fn migrate_to_version_two(transaction: &Transaction<'_>) -> DatabaseResult<()> {
+    transaction.execute_batch(
+        "
+        CREATE TABLE submissions (
+            id INTEGER PRIMARY KEY,
+            year INTEGER NOT NULL,
+            day INTEGER NOT NULL,
+            part INTEGER NOT NULL CHECK (part IN (1, 2)),
+            answer TEXT NOT NULL,
+            result TEXT NOT NULL,
+            submitted_at INTEGER NOT NULL
+        ) STRICT;
+
+        CREATE INDEX submissions_by_part
+            ON submissions (year, day, part, submitted_at, id);
+        ",
+    )?;
+    Ok(())
+}
+
+fn migrate_to_version_three(transaction: &Transaction<'_>) -> DatabaseResult<()> {
+    transaction.execute_batch(
+        "
+        CREATE TABLE submission_cooldowns (
+            year INTEGER NOT NULL,
+            day INTEGER NOT NULL,
+            expires_at INTEGER NOT NULL,
+            PRIMARY KEY (year, day)
+        ) STRICT;
+        ",
+    )?;
+    Ok(())
+}
+
+fn migrate_to_version_four(transaction: &Transaction<'_>) -> DatabaseResult<()> {
+    transaction.execute_batch(
+        "
+        CREATE TABLE accepted_answers (
+            year INTEGER NOT NULL,
+            day INTEGER NOT NULL,
+            part INTEGER NOT NULL CHECK (part IN (1, 2)),
+            answer TEXT NOT NULL,
+            recorded_at INTEGER NOT NULL,
+            PRIMARY KEY (year, day, part)
+        ) STRICT;
+        ",
+    )?;
+    Ok(())
+}
+
+fn migrate_to_version_five(transaction: &Transaction<'_>) -> DatabaseResult<()> {
+    transaction.execute_batch(
+        "
+        ALTER TABLE run_timings ADD COLUMN iterations INTEGER CHECK (iterations > 0);
+        ALTER TABLE run_timings ADD COLUMN min_nanos INTEGER CHECK (min_nanos >= 0);
+        ALTER TABLE run_timings ADD COLUMN mean_nanos INTEGER CHECK (mean_nanos >= 0);
+        ALTER TABLE run_timings ADD COLUMN stddev_nanos INTEGER CHECK (stddev_nanos >= 0);
+        ",
+    )?;
+    Ok(())
+}
+
+/// Gives private leaderboards a key column of their own instead of storing their id as the day.
+/// SQLite cannot change a primary key in place, so the table is rebuilt.
+fn migrate_to_version_six(transaction: &Transaction<'_>) -> DatabaseResult<()> {
+    transaction.execute_batch(
+        "
+        CREATE TABLE cache_entries_v6 (
+            content_type TEXT NOT NULL,
+            year INTEGER NOT NULL,
+            day INTEGER NOT NULL DEFAULT 0,
+            leaderboard_id INTEGER NOT NULL DEFAULT 0,
+            relative_path TEXT NOT NULL,
+            byte_size INTEGER NOT NULL,
+            fetched_at INTEGER,
+            etag TEXT,
+            last_modified TEXT,
+            is_valid INTEGER NOT NULL CHECK (is_valid IN (0, 1)),
+            PRIMARY KEY (content_type, year, day, leaderboard_id)
+        ) STRICT;
+
+        INSERT INTO cache_entries_v6 (
+            content_type, year, day, leaderboard_id, relative_path, byte_size, fetched_at, etag,
+            last_modified, is_valid
+        )
+        SELECT
+            content_type,
+            year,
+            CASE WHEN content_type = 'private_leaderboard' THEN 0 ELSE day END,
+            CASE WHEN content_type = 'private_leaderboard' THEN day ELSE 0 END,
+            relative_path, byte_size, fetched_at, etag, last_modified, is_valid
+        FROM cache_entries;
+
+        DROP TABLE cache_entries;
+        ALTER TABLE cache_entries_v6 RENAME TO cache_entries;
+        ",
+    )?;
+    Ok(())
+}
+
+fn sqlite_nanos(nanos: u64) -> DatabaseResult<i64> {
+    i64::try_from(nanos).map_err(|_| DatabaseError::InvalidTiming("duration exceeds SQLite range"))
+}
+
+fn stored_nanos(nanos: i64) -> DatabaseResult<u64> {
+    u64::try_from(nanos).map_err(|_| DatabaseError::InvalidTiming("negative stored duration"))
+}
+
 #[derive(Debug, Error)]
 pub(crate) enum DatabaseError {
     #[error("state database schema {found} is newer than supported schema {supported}")]
@@ -434,6 +883,8 @@ pub(crate) enum DatabaseError {
     InvalidCacheEntry(&'static str),
     #[error("invalid run timing: {0}")]
     InvalidTiming(&'static str),
+    #[error("invalid calendar stars: {0}")]
+    InvalidStars(&'static str),
     #[error(transparent)]
     Sql(rusqlite::Error),
 }
@@ -462,11 +913,15 @@ pub(crate) type DatabaseResult<T> = Result<T, DatabaseError>;
 mod tests {
     use std::fs;
 
-    use aocsuite_utils::{PuzzleDay, PuzzleId, PuzzleYear};
+    use aocsuite_utils::{LanguageId, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear};
     use rusqlite::Connection;
     use tempfile::tempdir;
 
-    use super::{CacheEntry, DatabaseError, StateDatabase, SCHEMA_VERSION};
+    use super::{
+        migrate_to_version_five, migrate_to_version_four, migrate_to_version_one,
+        migrate_to_version_three, migrate_to_version_two, BenchmarkRecord, CacheEntry,
+        DatabaseError, RunTimingRecord, StateDatabase, SubmissionRecord, SCHEMA_VERSION,
+    };
     use crate::content::CacheKey;
 
     fn puzzle() -> PuzzleId {
@@ -486,7 +941,7 @@ mod tests {
     }
 
     #[test]
-    fn opening_an_empty_database_bootstraps_schema_version_one() {
+    fn opening_an_empty_database_bootstraps_the_current_schema() {
         let temp = tempdir().expect("create temporary directory");
         let path = temp.path().join("state.sqlite");
 
@@ -499,6 +954,135 @@ mod tests {
         assert_eq!(version, SCHEMA_VERSION);
     }
 
+    #[test]
+    fn version_one_databases_upgrade_and_keep_existing_state() {
+        let temp = tempdir().expect("create temporary directory");
+        let path = temp.path().join("state.sqlite");
+        let connection = Connection::open(&path).expect("create database");
+        let transaction = connection
+            .unchecked_transaction()
+            .expect("start version one transaction");
+        migrate_to_version_one(&transaction).expect("create version one schema");
+        transaction
+            .pragma_update(None, "user_version", 1)
+            .expect("set version one");
+        transaction.commit().expect("commit version one schema");
+        connection
+            .execute(
+                "INSERT INTO submission_counts (year, day, part, incorrect_count) VALUES (2024, 1, 1, 2)",
+                [],
+            )
+            .expect("insert version one counts");
+        connection
+            .execute(
+                "INSERT INTO run_timings (year, day, language, part, duration_nanos, recorded_at)
+                 VALUES (2024, 1, 'rust', 1, 5000, 4)",
+                [],
+            )
+            .expect("insert version one timing");
+        drop(connection);
+
+        let database = StateDatabase::open(&path).expect("upgrade database");
+        let record = SubmissionRecord {
+            answer: "42".to_owned(),
+            result: "incorrect_too_low".to_owned(),
+            submitted_at: 7,
+        };
+        database
+            .record_submission(puzzle(), PuzzlePart::One, &record, Some(false))
+            .expect("record submission");
+
+        assert_eq!(
+            database
+                .submissions(puzzle(), PuzzlePart::One)
+                .expect("read submissions"),
+            [record]
+        );
+        let incorrect: i64 = database
+            .connection
+            .query_row(
+                "SELECT incorrect_count FROM submission_counts WHERE year = 2024 AND day = 1 AND part = 1",
+                [],
+                |row| row.get(0),
+            )
+            .expect("read submission counts");
+        assert_eq!(incorrect, 3);
+        assert_eq!(
+            database
+                .run_timings(puzzle(), LanguageId::Rust, PuzzlePart::One, 1)
+                .expect("read upgraded run timings"),
+            [RunTimingRecord {
+                duration_nanos: 5000,
+                recorded_at: 4,
+                benchmark: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn version_five_leaderboard_entries_move_their_id_out_of_the_day_column() {
+        let temp = tempdir().expect("create temporary directory");
+        let path = temp.path().join("state.sqlite");
+        let connection = Connection::open(&path).expect("create database");
+        let transaction = connection
+            .unchecked_transaction()
+            .expect("start version five transaction");
+        migrate_to_version_one(&transaction).expect("create version one schema");
+        migrate_to_version_two(&transaction).expect("upgrade to version two");
+        migrate_to_version_three(&transaction).expect("upgrade to version three");
+        migrate_to_version_four(&transaction).expect("upgrade to version four");
+        migrate_to_version_five(&transaction).expect("upgrade to version five");
+        transaction
+            .pragma_update(None, "user_version", 5)
+            .expect("set version five");
+        transaction.commit().expect("commit version five schema");
+        connection
+            .execute_batch(
+                "
+                INSERT INTO cache_entries (content_type, year, day, relative_path, byte_size, is_valid)
+                VALUES
+                    ('private_leaderboard', 2024, 123456, 'leaderboards/2024/123456.json', 2, 1),
+                    ('input', 2024, 1, 'inputs/2024/day1.txt', 3, 1);
+                ",
+            )
+            .expect("insert version five cache entries");
+        drop(connection);
+
+        let database = StateDatabase::open(&path).expect("upgrade database");
+        let year = PuzzleYear::new(2024).unwrap();
+        let leaderboard = database
+            .cache_entry(CacheKey::PrivateLeaderboard(year, 123456))
+            .expect("read leaderboard entry")
+            .expect("leaderboard entry kept");
+        assert_eq!(
+            leaderboard.relative_path,
+            std::path::PathBuf::from("leaderboards/2024/123456.json")
+        );
+        let day: i64 = database
+            .connection
+            .query_row(
+                "SELECT day FROM cache_entries WHERE content_type = 'private_leaderboard'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("read leaderboard day");
+        assert_eq!(day, 0);
+        let mut keys = database
+            .cache_entries()
+            .expect("read cache entries")
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect::<Vec<_>>();
+        keys.sort_by_key(|key| format!("{key:?}"));
+        assert_eq!(
+            keys,
+            [
+                CacheKey::Input(puzzle()),
+                CacheKey::PrivateLeaderboard(year, 123456)
+            ]
+        );
+    }
+
     #[test]
     fn cache_entry_batches_are_transactional() {
         let temp = tempdir().expect("create temporary directory");
@@ -533,6 +1117,46 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn run_timings_keep_the_retention_limit_and_read_back_newest_first() {
+        let temp = tempdir().expect("create temporary directory");
+        let database =
+            StateDatabase::open(&temp.path().join("state.sqlite")).expect("open state database");
+        let timings = |limit| {
+            database
+                .run_timings(puzzle(), LanguageId::Rust, PuzzlePart::One, limit)
+                .expect("read run timings")
+        };
+        assert!(timings(5).is_empty());
+
+        let timing = |duration_nanos, recorded_at, benchmark| RunTimingRecord {
+            duration_nanos,
+            recorded_at,
+            benchmark,
+        };
+        let benchmark = BenchmarkRecord {
+            iterations: 50,
+            min_nanos: 8,
+            mean_nanos: 11,
+            stddev_nanos: 2,
+        };
+        for record in [
+            timing(30, 1, None),
+            timing(10, 3, Some(benchmark)),
+            timing(20, 2, None),
+        ] {
+            database
+                .record_run_timing(puzzle(), LanguageId::Rust, PuzzlePart::One, record, 2)
+                .expect("record run timing");
+        }
+
+        assert_eq!(
+            timings(5),
+            [timing(10, 3, Some(benchmark)), timing(20, 2, None)]
+        );
+        assert_eq!(timings(1), [timing(10, 3, Some(benchmark))]);
+    }
+
     #[test]
     fn newer_schema_is_rejected_without_modification() {
         let temp = tempdir().expect("create temporary directory");
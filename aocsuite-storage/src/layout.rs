@@ -84,6 +84,11 @@ impl RuntimeLayout {
         self.root.join("cache")
     }
 
+    /// Request throttle state shared by every AoC Suite process using this root.
+    pub fn throttle_dir(&self) -> PathBuf {
+        self.cache_dir().join("throttle")
+    }
+
     pub fn workspace_dir(&self) -> PathBuf {
         self.root.join("workspace")
     }
@@ -3,6 +3,9 @@ mod database;
 mod layout;
 mod workspace;
 
-pub use content::{CacheCleanReport, CacheCleanScope, ContentError, ContentResult, ContentStore};
+pub use content::{
+    CacheCleanReport, CacheCleanScope, ContentError, ContentResult, ContentStore, RunBenchmark,
+    RunHistory, RunTiming, SubmissionBlock, YearStars, LEADERBOARD_REFRESH_FLOOR,
+};
 pub use layout::{get_aocsuite_dir, LayoutError, RuntimeLayout, CURRENT_LAYOUT_VERSION};
-pub use workspace::{GitMode, Workspace, WorkspaceError, WorkspaceResult};
+pub use workspace::{GitMode, PuzzleAnswers, Workspace, WorkspaceError, WorkspaceResult};
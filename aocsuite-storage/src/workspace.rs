@@ -6,13 +6,21 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 
-use aocsuite_utils::{atomic_write, CommandExecutor, CommandRequest, LanguageId, PuzzleId};
+use aocsuite_utils::{
+    atomic_write, CommandExecutor, CommandRequest, LanguageId, PuzzleId, PuzzlePart,
+};
 use thiserror::Error;
 
 const GITIGNORE: &str = r#"rust/target/
 rust/src/solution.rs
 python/venv/
 python/solution.py
+go/bin/
+go/solution.go
+cpp/build/
+cpp/solution.cpp
+typescript/node_modules/
+typescript/solution.ts
 /.aocsuite-runs/
 **/__pycache__/
 *.pyc
@@ -24,6 +32,69 @@ pub struct Workspace {
     directory: PathBuf,
 }
 
+/// Known answers to both parts of a puzzle: the example answers extracted from the puzzle prose
+/// or overridden by the user, or the answers AoC accepted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl PuzzleAnswers {
+    pub fn part(&self, part: PuzzlePart) -> Option<&str> {
+        match part {
+            PuzzlePart::One => self.part1.as_deref(),
+            PuzzlePart::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Compares a solver answer with the known one, or `None` when no answer is known.
+    pub fn matches(&self, part: PuzzlePart, answer: &str) -> Option<bool> {
+        self.part(part)
+            .map(|expected| expected.trim() == answer.trim())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+
+    fn parse(contents: &str, path: &Path) -> WorkspaceResult<Self> {
+        let mut answers = Self::default();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let slot = match line.split_once(':') {
+                Some((key, value)) if !value.trim().is_empty() => match key.trim() {
+                    "part1" => Some((&mut answers.part1, value)),
+                    "part2" => Some((&mut answers.part2, value)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some((slot, value)) = slot else {
+                return Err(WorkspaceError::ExampleAnswers {
+                    path: path.to_path_buf(),
+                    line: index + 1,
+                });
+            };
+            *slot = Some(value.trim().to_owned());
+        }
+        Ok(answers)
+    }
+
+    fn render(&self) -> String {
+        let mut rendered = String::new();
+        for (key, answer) in [("part1", &self.part1), ("part2", &self.part2)] {
+            if let Some(answer) = answer {
+                rendered.push_str(&format!("{key}: {answer}\n"));
+            }
+        }
+        rendered
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GitMode {
     Captured,
@@ -104,19 +175,132 @@ impl Workspace {
         .into())
     }
 
-    pub fn ensure_example(&self, puzzle: PuzzleId) -> WorkspaceResult<PathBuf> {
-        let examples_dir = self.directory.join("examples");
-        let path = examples_dir.join(format!("{puzzle}.txt"));
-        fs::create_dir_all(&examples_dir)?;
-        match OpenOptions::new().write(true).create_new(true).open(&path) {
-            Ok(_) => Ok(path),
-            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists && path.is_file() => {
-                Ok(path)
-            }
+    /// Path of the shared example input, whether or not it exists yet.
+    pub fn example_path(&self, puzzle: PuzzleId) -> PathBuf {
+        self.directory
+            .join("examples")
+            .join(format!("{puzzle}.txt"))
+    }
+
+    /// Ensures `examples/{puzzle}.txt` exists, filling it when missing or empty.
+    ///
+    /// `examples` is only consulted when the example has no content yet, and its error is
+    /// returned without creating the example. The first candidate becomes the example and the
+    /// rest are kept as `{puzzle}.2.txt`, `{puzzle}.3.txt`, ... without replacing files that
+    /// already exist.
+    pub fn ensure_example<E: From<WorkspaceError>>(
+        &self,
+        puzzle: PuzzleId,
+        examples: impl FnOnce() -> Result<Vec<String>, E>,
+    ) -> Result<PathBuf, E> {
+        let path = self.example_path(puzzle);
+        if self.example_is_filled(&path)? {
+            return Ok(path);
+        }
+        let examples = examples()?;
+        Ok(self.write_examples(puzzle, path, examples)?)
+    }
+
+    fn example_is_filled(&self, path: &Path) -> WorkspaceResult<bool> {
+        let examples_dir = path.parent().expect("example path has a parent");
+        fs::create_dir_all(examples_dir)?;
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() && metadata.len() > 0 => Ok(true),
+            Ok(metadata) if !metadata.is_file() => Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("example path '{}' is not a file", path.display()),
+            )
+            .into()),
+            Ok(_) => Ok(false),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
             Err(error) => Err(error.into()),
         }
     }
 
+    fn write_examples(
+        &self,
+        puzzle: PuzzleId,
+        path: PathBuf,
+        examples: Vec<String>,
+    ) -> WorkspaceResult<PathBuf> {
+        let examples_dir = path.parent().expect("example path has a parent");
+        let mut examples = examples.into_iter();
+        let Some(primary) = examples.next() else {
+            return match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => Ok(path),
+                Err(error)
+                    if error.kind() == std::io::ErrorKind::AlreadyExists && path.is_file() =>
+                {
+                    Ok(path)
+                }
+                Err(error) => Err(error.into()),
+            };
+        };
+        atomic_write(&path, primary.as_bytes())?;
+        for (index, alternate) in examples.enumerate() {
+            let alternate_path = examples_dir.join(format!("{puzzle}.{}.txt", index + 2));
+            if !alternate_path.exists() {
+                atomic_write(&alternate_path, alternate.as_bytes())?;
+            }
+        }
+        Ok(path)
+    }
+
+    /// Replaces the extracted answers stored next to the shared example.
+    pub fn save_example_answers(
+        &self,
+        puzzle: PuzzleId,
+        answers: &PuzzleAnswers,
+    ) -> WorkspaceResult<()> {
+        if answers.is_empty() {
+            return Ok(());
+        }
+        let path = self.example_answers_path(puzzle, false);
+        let rendered = answers.render();
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == rendered => return Ok(()),
+            Ok(_) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        atomic_write(&path, rendered.as_bytes())?;
+        Ok(())
+    }
+
+    /// Loads the expected example answers, preferring `{puzzle}.answers.override` per part.
+    pub fn example_answers(&self, puzzle: PuzzleId) -> WorkspaceResult<PuzzleAnswers> {
+        let mut answers = PuzzleAnswers::default();
+        for path in self.example_answer_paths(puzzle) {
+            let contents = match fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error.into()),
+            };
+            let parsed = PuzzleAnswers::parse(&contents, &path)?;
+            answers.part1 = parsed.part1.or(answers.part1);
+            answers.part2 = parsed.part2.or(answers.part2);
+        }
+        Ok(answers)
+    }
+
+    /// Paths of `{puzzle}.answers` and `{puzzle}.answers.override`, whether or not they exist.
+    pub fn example_answer_paths(&self, puzzle: PuzzleId) -> [PathBuf; 2] {
+        [
+            self.example_answers_path(puzzle, false),
+            self.example_answers_path(puzzle, true),
+        ]
+    }
+
+    fn example_answers_path(&self, puzzle: PuzzleId, user_override: bool) -> PathBuf {
+        let suffix = if user_override { ".override" } else { "" };
+        self.directory
+            .join("examples")
+            .join(format!("{puzzle}.answers{suffix}"))
+    }
+
     pub fn run_git(
         &self,
         args: &[String],
@@ -224,6 +408,8 @@ pub enum WorkspaceError {
         #[source]
         source: std::io::Error,
     },
+    #[error("invalid example answers in '{}' on line {line}; expected `part1: ANSWER` or `part2: ANSWER`", path.display())]
+    ExampleAnswers { path: PathBuf, line: usize },
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
@@ -234,8 +420,10 @@ pub type WorkspaceResult<T> = Result<T, WorkspaceError>;
 mod tests {
     use std::{io, process::Output, sync::Mutex};
 
-    use super::{GitMode, Workspace};
-    use aocsuite_utils::{CommandExecutor, CommandRequest};
+    use super::{GitMode, PuzzleAnswers, Workspace, WorkspaceError};
+    use aocsuite_utils::{
+        CommandExecutor, CommandRequest, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear,
+    };
 
     #[derive(Default)]
     struct RecordingExecutor {
@@ -290,6 +478,105 @@ mod tests {
         assert!(!other.gitignore_path().exists());
     }
 
+    #[test]
+    fn example_is_filled_once_with_numbered_alternates() {
+        let temp = tempfile::tempdir().unwrap();
+        let workspace = Workspace::new(temp.path().join("workspace"));
+        let puzzle = PuzzleId::new(PuzzleDay::new(5).unwrap(), PuzzleYear::new(2024).unwrap());
+        let examples_dir = workspace.root_dir().join("examples");
+        std::fs::create_dir_all(&examples_dir).unwrap();
+        std::fs::write(examples_dir.join(format!("{puzzle}.txt")), "").unwrap();
+        std::fs::write(examples_dir.join(format!("{puzzle}.3.txt")), "edited\n").unwrap();
+
+        let path = workspace
+            .ensure_example(puzzle, || {
+                Ok::<_, WorkspaceError>(vec![
+                    "sample\n".to_owned(),
+                    "second\n".to_owned(),
+                    "third\n".to_owned(),
+                ])
+            })
+            .expect("populate example");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "sample\n");
+        assert_eq!(
+            std::fs::read_to_string(examples_dir.join(format!("{puzzle}.2.txt"))).unwrap(),
+            "second\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(examples_dir.join(format!("{puzzle}.3.txt"))).unwrap(),
+            "edited\n"
+        );
+
+        std::fs::write(&path, "manual\n").unwrap();
+        let path = workspace
+            .ensure_example(puzzle, || -> Result<Vec<String>, WorkspaceError> {
+                panic!("filled examples are not re-extracted")
+            })
+            .expect("keep example");
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "manual\n");
+    }
+
+    #[test]
+    fn example_answer_overrides_replace_extracted_parts() {
+        let temp = tempfile::tempdir().unwrap();
+        let workspace = Workspace::new(temp.path().join("workspace"));
+        let puzzle = PuzzleId::new(PuzzleDay::new(5).unwrap(), PuzzleYear::new(2024).unwrap());
+        workspace
+            .save_example_answers(
+                puzzle,
+                &PuzzleAnswers {
+                    part1: Some("143".to_owned()),
+                    part2: Some("9".to_owned()),
+                },
+            )
+            .expect("save extracted answers");
+        let override_path = workspace
+            .root_dir()
+            .join(format!("examples/{puzzle}.answers.override"));
+        std::fs::write(&override_path, "# fixed by hand\npart2: 123\n").unwrap();
+
+        let answers = workspace.example_answers(puzzle).expect("load answers");
+        assert_eq!(answers.part(PuzzlePart::One), Some("143"));
+        assert_eq!(answers.part(PuzzlePart::Two), Some("123"));
+        assert_eq!(answers.matches(PuzzlePart::One, " 143\n"), Some(true));
+        assert_eq!(answers.matches(PuzzlePart::Two, "9"), Some(false));
+
+        std::fs::write(&override_path, "part3: 1\n").unwrap();
+        assert!(matches!(
+            workspace.example_answers(puzzle),
+            Err(WorkspaceError::ExampleAnswers { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn example_without_candidates_is_created_empty() {
+        let temp = tempfile::tempdir().unwrap();
+        let workspace = Workspace::new(temp.path().join("workspace"));
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+
+        let path = workspace
+            .ensure_example(puzzle, || Ok::<_, WorkspaceError>(Vec::new()))
+            .expect("create example");
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "");
+    }
+
+    #[test]
+    fn example_extraction_errors_are_returned_without_creating_the_example() {
+        let temp = tempfile::tempdir().unwrap();
+        let workspace = Workspace::new(temp.path().join("workspace"));
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+
+        let error = workspace
+            .ensure_example(puzzle, || {
+                Err(WorkspaceError::from(std::io::Error::other(
+                    "puzzle page unavailable",
+                )))
+            })
+            .unwrap_err();
+        assert!(error.to_string().contains("puzzle page unavailable"));
+        assert!(!workspace.example_path(puzzle).exists());
+    }
+
     #[cfg(unix)]
     fn status(success: bool) -> std::process::ExitStatus {
         use std::os::unix::process::ExitStatusExt;
@@ -1,7 +1,18 @@
-use std::{collections::HashSet, path::PathBuf};
-
-use aocsuite_parser::{AocSubmissionResult, Calendar};
-use aocsuite_utils::{LanguageId, PuzzleId, PuzzlePart, PuzzleYear, RunHistoryLimit};
+use std::{
+    collections::{HashSet, VecDeque},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aocsuite_parser::{AocSubmissionResult, Calendar, PrivateLeaderboard};
+use aocsuite_storage::{PuzzleAnswers, RunHistory, YearStars};
+use aocsuite_utils::{
+    format_cooldown, puzzle_unlock_timestamp, CancellationToken, LanguageId, OutputLine, PuzzleDay,
+    PuzzleId, PuzzlePart, PuzzleYear, RunHistoryLimit, RunTimeout, SolutionId, WatchList,
+};
+
+/// Only the most recent solver output lines are kept for the live run log.
+const RUN_LOG_LIMIT: usize = 500;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum RunInput {
@@ -9,6 +20,13 @@ pub(crate) enum RunInput {
     Example,
 }
 
+fn cooldown_message(remaining: Duration) -> String {
+    format!(
+        "Submission cooldown: wait {} before submitting",
+        format_cooldown(remaining)
+    )
+}
+
 pub(crate) fn friendly_puzzle(puzzle: PuzzleId) -> String {
     format!("{} Day {}", puzzle.year, puzzle.day)
 }
@@ -26,6 +44,93 @@ pub(crate) struct RunPartReport {
     pub part: PuzzlePart,
     pub answer: String,
     pub runtime_ms: u128,
+    pub expected: PuzzleAnswers,
+    pub accepted: PuzzleAnswers,
+}
+
+/// Recent runtimes of the selected puzzle in the active language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RunHistoryView {
+    pub puzzle: PuzzleId,
+    pub language: LanguageId,
+    pub parts: Vec<(PuzzlePart, RunHistory)>,
+}
+
+/// Stored solutions of the selected puzzle in the active language and the one runs use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SolutionVariants {
+    pub puzzle: PuzzleId,
+    pub language: LanguageId,
+    pub solutions: Vec<SolutionId>,
+    pub active: SolutionId,
+}
+
+/// A private leaderboard of the selected year, as last read from the cache or AoC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LeaderboardView {
+    pub year: PuzzleYear,
+    pub id: u32,
+    pub leaderboard: PrivateLeaderboard,
+    /// Refreshes before this keep serving the cached copy.
+    refresh_at: Option<Instant>,
+}
+
+impl LeaderboardView {
+    /// Time left before AoC may be asked for the leaderboard again.
+    pub(crate) fn refresh_in(&self) -> Option<Duration> {
+        self.refresh_at
+            .map(|refresh_at| refresh_at.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// The days of the event released so far.
+    pub(crate) fn days(&self, latest_puzzle: PuzzleId) -> Vec<PuzzleDay> {
+        self.leaderboard
+            .days((self.year == latest_puzzle.year).then_some(latest_puzzle.day))
+    }
+}
+
+/// A member's star times on one day, measured from the puzzle unlock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LeaderboardDayRow {
+    pub name: String,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl LeaderboardDayRow {
+    /// Time between the two stars.
+    pub(crate) fn gap(&self) -> Option<Duration> {
+        self.part2?.checked_sub(self.part1?)
+    }
+}
+
+/// Members with a star on the day, fastest second star first and first-star-only members last.
+pub(crate) fn leaderboard_day_rows(
+    leaderboard: &PrivateLeaderboard,
+    puzzle: PuzzleId,
+) -> Vec<LeaderboardDayRow> {
+    let unlock = puzzle_unlock_timestamp(puzzle);
+    let elapsed = |timestamp: Option<i64>| {
+        timestamp
+            .and_then(|timestamp| u64::try_from(timestamp - unlock).ok())
+            .map(Duration::from_secs)
+    };
+    let mut rows: Vec<LeaderboardDayRow> = leaderboard
+        .members
+        .iter()
+        .filter_map(|member| {
+            let completion = member.day(puzzle.day);
+            completion.part1?;
+            Some(LeaderboardDayRow {
+                name: member.display_name(),
+                part1: elapsed(completion.part1),
+                part2: elapsed(completion.part2),
+            })
+        })
+        .collect();
+    rows.sort_by_key(|row| (row.part2.is_none(), row.part2, row.part1));
+    rows
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -152,16 +257,23 @@ impl std::fmt::Debug for SubmissionDialog {
 pub(crate) enum Tab {
     Calendar,
     Language,
+    Leaderboard,
     Config,
 }
 
 impl Tab {
-    pub const ALL: [Self; 3] = [Self::Calendar, Self::Language, Self::Config];
+    pub const ALL: [Self; 4] = [
+        Self::Calendar,
+        Self::Language,
+        Self::Leaderboard,
+        Self::Config,
+    ];
 
     pub const fn title(self) -> &'static str {
         match self {
             Self::Calendar => "Calendar",
             Self::Language => "Language",
+            Self::Leaderboard => "Leaderboard",
             Self::Config => "Config",
         }
     }
@@ -169,7 +281,8 @@ impl Tab {
     fn next(self) -> Self {
         match self {
             Self::Calendar => Self::Language,
-            Self::Language => Self::Config,
+            Self::Language => Self::Leaderboard,
+            Self::Leaderboard => Self::Config,
             Self::Config => Self::Calendar,
         }
     }
@@ -178,7 +291,8 @@ impl Tab {
         match self {
             Self::Calendar => Self::Config,
             Self::Language => Self::Calendar,
-            Self::Config => Self::Language,
+            Self::Leaderboard => Self::Language,
+            Self::Config => Self::Leaderboard,
         }
     }
 }
@@ -265,14 +379,16 @@ pub(crate) enum ConfigField {
     Year,
     Editor,
     RunHistoryLimit,
+    RunTimeout,
     Session,
 }
 
 impl ConfigField {
-    pub const ALL: [Self; 4] = [
+    pub const ALL: [Self; 5] = [
         Self::Year,
         Self::Editor,
         Self::RunHistoryLimit,
+        Self::RunTimeout,
         Self::Session,
     ];
 
@@ -281,6 +397,7 @@ impl ConfigField {
             Self::Year => "Default year",
             Self::Editor => "Editor executable",
             Self::RunHistoryLimit => "Run-history retention",
+            Self::RunTimeout => "Solver timeout (seconds)",
             Self::Session => "Session",
         }
     }
@@ -291,6 +408,7 @@ pub(crate) enum NonSecretConfigField {
     Year,
     Editor,
     RunHistoryLimit,
+    RunTimeout,
 }
 
 impl NonSecretConfigField {
@@ -299,6 +417,7 @@ impl NonSecretConfigField {
             Self::Year => ConfigField::Year.label(),
             Self::Editor => ConfigField::Editor.label(),
             Self::RunHistoryLimit => ConfigField::RunHistoryLimit.label(),
+            Self::RunTimeout => ConfigField::RunTimeout.label(),
         }
     }
 }
@@ -308,6 +427,7 @@ pub(crate) struct ConfigData {
     pub year: String,
     pub editor: Option<String>,
     pub run_history_limit: String,
+    pub run_timeout: String,
     pub session_configured: bool,
 }
 
@@ -401,6 +521,9 @@ pub(crate) enum ConfigMutation {
 pub(crate) struct App {
     pub active_tab: Tab,
     pub calendar: Option<Calendar>,
+    pub year_stars: Option<YearStars>,
+    pub run_history: Option<RunHistoryView>,
+    solution_variants: Option<SolutionVariants>,
     pub calendar_loading: bool,
     pub selected_year: PuzzleYear,
     selected_puzzle: Option<PuzzleId>,
@@ -412,11 +535,20 @@ pub(crate) struct App {
     pub calendar_scroll: (u16, u16),
     pub exercise_preparing: bool,
     pub active_run: Option<RunRequest>,
+    run_cancellation: Option<CancellationToken>,
+    run_started: Option<Instant>,
+    pub run_log: VecDeque<OutputLine>,
+    pub watching: Option<RunRequest>,
+    watch_pending: bool,
+    /// Set by `--offline` or the `offline` config key: AoC is never contacted and submitting is
+    /// disabled. Background effects report it whenever they reread the configuration.
+    pub offline: bool,
     pub run_input: RunInput,
     pub run_spinner_frame: usize,
     pub run_dialog: Option<RunDialog>,
     pub submission_dialog: Option<SubmissionDialog>,
     pub active_submission: Option<SubmissionRequest>,
+    submission_cooldown: Option<(PuzzleId, Instant)>,
     pub language: LanguageId,
     pub language_packages: Vec<String>,
     pub language_libraries: Vec<String>,
@@ -431,6 +563,14 @@ pub(crate) struct App {
     pub config_selection: usize,
     pub config_operation: ConfigOperationState,
     pub config_dialog: Option<ConfigDialog>,
+    /// Configured private leaderboard ids, once read.
+    pub leaderboard_ids: Option<Vec<u32>>,
+    pub leaderboard_selection: usize,
+    pub leaderboard: Option<LeaderboardView>,
+    pub leaderboard_loading: bool,
+    /// The day whose star times are shown instead of the ranking.
+    pub leaderboard_day: Option<PuzzleDay>,
+    pub leaderboard_scroll: u16,
     pub help_open: bool,
     pub help_scroll: u16,
     lazygit_preparing: bool,
@@ -457,7 +597,10 @@ pub(crate) enum Action {
     OpenLazygit,
     RunPart(PuzzlePart),
     ToggleRunInput,
+    NextSolutionVariant,
+    ToggleWatch,
     Tick,
+    CancelRun,
     CancelRunDialog,
     ScrollRunUp,
     ScrollRunDown,
@@ -494,6 +637,14 @@ pub(crate) enum Action {
     ConfigScrollMessageDown,
     ConfigSubmit,
     ConfigCancel,
+    RefreshLeaderboard,
+    PreviousLeaderboard,
+    NextLeaderboard,
+    ToggleLeaderboardDay,
+    PreviousLeaderboardDay,
+    NextLeaderboardDay,
+    ScrollLeaderboardUp,
+    ScrollLeaderboardDown,
     OpenHelp,
     CloseHelp,
     ScrollHelpUp,
@@ -527,6 +678,19 @@ pub(crate) enum Action {
         language: LanguageId,
         result: Result<PreparedExercise, String>,
     },
+    RunOutput {
+        request: RunRequest,
+        line: OutputLine,
+    },
+    WatchedFilesResolved {
+        request: RunRequest,
+        result: Result<WatchList, String>,
+    },
+    WatchChanged(RunRequest),
+    WatchFailed {
+        request: RunRequest,
+        message: String,
+    },
     RunFinished {
         request: RunRequest,
         result: Result<RunReport, RunFailure>,
@@ -535,6 +699,24 @@ pub(crate) enum Action {
         request: SubmissionRequest,
         result: Result<AocSubmissionResult, String>,
     },
+    SubmissionCooldownLoaded {
+        puzzle: PuzzleId,
+        result: Result<Option<Duration>, String>,
+    },
+    StarsLoaded {
+        year: PuzzleYear,
+        result: Result<YearStars, String>,
+    },
+    RunHistoryLoaded {
+        puzzle: PuzzleId,
+        language: LanguageId,
+        result: Result<Vec<(PuzzlePart, RunHistory)>, String>,
+    },
+    SolutionVariantsLoaded {
+        puzzle: PuzzleId,
+        language: LanguageId,
+        result: Result<(Vec<SolutionId>, SolutionId), String>,
+    },
     LanguageDataFinished {
         language: LanguageId,
         result: Result<LanguageData, String>,
@@ -557,10 +739,23 @@ pub(crate) enum Action {
     ConfigSaved {
         result: Result<ConfigData, String>,
     },
+    LeaderboardIdsLoaded {
+        refresh: bool,
+        result: Result<Vec<u32>, String>,
+    },
+    LeaderboardLoaded {
+        year: PuzzleYear,
+        id: u32,
+        result: Result<(PrivateLeaderboard, Option<Duration>), String>,
+    },
     BackgroundSubmissionFailed {
         effect: BackgroundEffect,
         message: String,
     },
+    /// A background request is waiting for its throttle slot.
+    Throttled(String),
+    /// Whether the latest AoC client was built offline.
+    OfflineMode(bool),
     ForegroundFinished(Result<(), String>),
 }
 
@@ -579,8 +774,26 @@ pub(crate) enum BackgroundEffect {
     PrepareLazygit {
         language_active: bool,
     },
-    RunSolver(RunRequest),
+    RunSolver {
+        request: RunRequest,
+        cancellation: CancellationToken,
+    },
+    ResolveWatchedFiles(RunRequest),
     SubmitAnswer(SubmissionRequest),
+    LoadSubmissionCooldown(PuzzleId),
+    LoadStars(PuzzleYear),
+    LoadRunHistory {
+        puzzle: PuzzleId,
+        language: LanguageId,
+    },
+    LoadSolutionVariants {
+        puzzle: PuzzleId,
+        language: LanguageId,
+    },
+    SelectSolution {
+        language: LanguageId,
+        solution: SolutionId,
+    },
     LoadLanguageData {
         language: LanguageId,
     },
@@ -600,6 +813,16 @@ pub(crate) enum BackgroundEffect {
         latest_year: PuzzleYear,
         mutation: ConfigMutation,
     },
+    LoadLeaderboardIds {
+        refresh: bool,
+    },
+    /// Reads the cached leaderboard, fetching it when missing or, on refresh, when the cache
+    /// floor has passed.
+    LoadLeaderboard {
+        year: PuzzleYear,
+        id: u32,
+        refresh: bool,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -624,6 +847,11 @@ pub(crate) struct PreparedExercise {
 pub(crate) enum Effect {
     Background(BackgroundEffect),
     Foreground(ForegroundEffect),
+    Watch {
+        request: RunRequest,
+        watched: WatchList,
+    },
+    StopWatch,
 }
 
 impl App {
@@ -638,6 +866,9 @@ impl App {
         Self {
             active_tab: Tab::Calendar,
             calendar: None,
+            year_stars: None,
+            run_history: None,
+            solution_variants: None,
             calendar_loading: false,
             selected_year,
             selected_puzzle: None,
@@ -649,11 +880,18 @@ impl App {
             calendar_scroll: (0, 0),
             exercise_preparing: false,
             active_run: None,
+            run_cancellation: None,
+            run_started: None,
+            run_log: VecDeque::new(),
+            watching: None,
+            watch_pending: false,
+            offline: false,
             run_input: RunInput::Aoc,
             run_spinner_frame: 0,
             run_dialog: None,
             submission_dialog: None,
             active_submission: None,
+            submission_cooldown: None,
             language,
             language_packages: Vec::new(),
             language_libraries: Vec::new(),
@@ -668,6 +906,12 @@ impl App {
             config_selection: 0,
             config_operation: ConfigOperationState::Idle,
             config_dialog: None,
+            leaderboard_ids: None,
+            leaderboard_selection: 0,
+            leaderboard: None,
+            leaderboard_loading: false,
+            leaderboard_day: None,
+            leaderboard_scroll: 0,
             help_open: false,
             help_scroll: 0,
             lazygit_preparing: false,
@@ -687,16 +931,30 @@ impl App {
         })]
     }
 
+    // Arms check their preconditions inside the body so a refused action is consumed instead
+    // of falling through to later arms.
+    #[allow(clippy::collapsible_match)]
     pub fn update(&mut self, action: Action) -> Vec<Effect> {
         if self.active_submission.is_some()
             && !matches!(
                 action,
                 Action::Tick
                     | Action::SubmissionFinished { .. }
+                    | Action::SubmissionCooldownLoaded { .. }
+                    | Action::WatchedFilesResolved { .. }
+                    | Action::WatchChanged(_)
+                    | Action::WatchFailed { .. }
                     | Action::BackgroundSubmissionFailed { .. }
                     | Action::CalendarFinished { .. }
+                    | Action::StarsLoaded { .. }
+                    | Action::RunHistoryLoaded { .. }
+                    | Action::SolutionVariantsLoaded { .. }
                     | Action::CachedDescriptionFinished { .. }
                     | Action::DescriptionDownloaded { .. }
+                    | Action::LeaderboardIdsLoaded { .. }
+                    | Action::LeaderboardLoaded { .. }
+                    | Action::Throttled(_)
+                    | Action::OfflineMode(_)
             )
         {
             return Vec::new();
@@ -705,9 +963,18 @@ impl App {
             && !matches!(
                 action,
                 Action::Tick
+                    | Action::CancelRun
+                    | Action::RunOutput { .. }
                     | Action::RunFinished { .. }
+                    | Action::WatchedFilesResolved { .. }
+                    | Action::WatchChanged(_)
+                    | Action::WatchFailed { .. }
+                    | Action::SubmissionCooldownLoaded { .. }
                     | Action::BackgroundSubmissionFailed { .. }
                     | Action::CalendarFinished { .. }
+                    | Action::StarsLoaded { .. }
+                    | Action::RunHistoryLoaded { .. }
+                    | Action::SolutionVariantsLoaded { .. }
                     | Action::CachedDescriptionFinished { .. }
                     | Action::DescriptionDownloaded { .. }
                     | Action::ExercisePrepared { .. }
@@ -716,6 +983,10 @@ impl App {
                     | Action::LanguageFilePrepared { .. }
                     | Action::ConfigLoaded { .. }
                     | Action::ConfigSaved { .. }
+                    | Action::LeaderboardIdsLoaded { .. }
+                    | Action::LeaderboardLoaded { .. }
+                    | Action::Throttled(_)
+                    | Action::OfflineMode(_)
                     | Action::ForegroundFinished(_)
             )
         {
@@ -850,11 +1121,14 @@ impl App {
                     part,
                     input: self.run_input,
                 };
-                self.active_run = Some(request);
-                self.run_spinner_frame = 0;
-                self.run_dialog = None;
-                self.status = None;
-                return vec![Effect::Background(BackgroundEffect::RunSolver(request))];
+                let mut effects = Vec::new();
+                if self.watching.is_some_and(|watched| {
+                    watched.puzzle == puzzle && watched.language == self.language
+                }) {
+                    effects.extend(self.watch(request));
+                }
+                effects.extend(self.start_run(request));
+                return effects;
             }
             Action::ToggleRunInput if self.active_tab == Tab::Calendar => {
                 self.run_input = match self.run_input {
@@ -862,10 +1136,113 @@ impl App {
                     RunInput::Example => RunInput::Aoc,
                 };
                 self.status = None;
+                if let Some(watched) = self.watching {
+                    return self.watch(RunRequest {
+                        input: self.run_input,
+                        ..watched
+                    });
+                }
+            }
+            Action::NextSolutionVariant if self.active_tab == Tab::Calendar => {
+                if self.language_busy() {
+                    self.status = Some("A language operation is already running".to_owned());
+                    return Vec::new();
+                }
+                let Some(variants) = self.selected_solution_variants() else {
+                    return Vec::new();
+                };
+                if variants.solutions.len() < 2 {
+                    self.status = Some(format!(
+                        "{} has no other solution variants",
+                        friendly_puzzle(variants.puzzle)
+                    ));
+                    return Vec::new();
+                }
+                let next = variants
+                    .solutions
+                    .iter()
+                    .position(|solution| *solution == variants.active)
+                    .map_or(0, |index| (index + 1) % variants.solutions.len());
+                let effect = Effect::Background(BackgroundEffect::SelectSolution {
+                    language: variants.language,
+                    solution: variants.solutions[next],
+                });
+                self.status = None;
+                return vec![effect];
+            }
+            Action::ToggleWatch if self.active_tab == Tab::Calendar => {
+                if let Some(watched) = self.watching.take() {
+                    self.watch_pending = false;
+                    self.status = Some(format!(
+                        "Stopped watching {}",
+                        friendly_puzzle(watched.puzzle)
+                    ));
+                    return vec![Effect::StopWatch];
+                }
+                if self.language_busy() {
+                    self.status = Some("A language operation is already running".to_owned());
+                    return Vec::new();
+                }
+                let Some(puzzle) = self.selected_puzzle_or_status() else {
+                    return Vec::new();
+                };
+                let request = RunRequest {
+                    puzzle,
+                    language: self.language,
+                    part: PuzzlePart::One,
+                    input: self.run_input,
+                };
+                let mut effects = self.watch(request);
+                effects.extend(self.start_run(request));
+                return effects;
+            }
+            Action::WatchedFilesResolved { request, result } if self.watching == Some(request) => {
+                match result {
+                    Ok(watched) => return vec![Effect::Watch { request, watched }],
+                    Err(message) => {
+                        self.watching = None;
+                        self.watch_pending = false;
+                        self.status = Some(message);
+                    }
+                }
+            }
+            Action::WatchChanged(request) if self.watching == Some(request) => {
+                // The change may be another variant selected outside the TUI, so the watched
+                // files are resolved again.
+                let mut effects = self.watch(request);
+                // Runs never overlap, and a save should not interrupt a submission.
+                if self.language_busy()
+                    || self.active_submission.is_some()
+                    || self.submission_dialog.is_some()
+                {
+                    self.watch_pending = true;
+                } else {
+                    effects.extend(self.start_run(request));
+                }
+                return effects;
+            }
+            Action::WatchFailed { request, message } if self.watching == Some(request) => {
+                self.watching = None;
+                self.watch_pending = false;
+                self.status = Some(message);
             }
             Action::Tick if self.active_run.is_some() || self.active_submission.is_some() => {
                 self.run_spinner_frame = (self.run_spinner_frame + 1) % 4;
             }
+            Action::CancelRun => {
+                // The worker is busy with the run, so the flag is raised here rather than
+                // through another background effect.
+                if let Some(cancellation) = &self.run_cancellation {
+                    cancellation.cancel();
+                    self.status = Some("Stopping the solver run".to_owned());
+                }
+            }
+            Action::RunOutput { request, line } if self.active_run == Some(request) => {
+                if self.run_log.len() == RUN_LOG_LIMIT {
+                    self.run_log.pop_front();
+                }
+                self.run_log.push_back(line);
+            }
             Action::CancelRunDialog => self.run_dialog = None,
             Action::ScrollRunUp => {
                 if let Some(dialog) = &mut self.run_dialog {
@@ -881,11 +1258,19 @@ impl App {
                 if self.submission_dialog.is_some() || self.active_submission.is_some() {
                     return Vec::new();
                 }
+                if self.offline {
+                    self.status = Some("Offline: submitting answers is disabled".to_owned());
+                    return Vec::new();
+                }
                 if let Some(request) = self.run_submission_request() {
+                    let puzzle = request.puzzle;
                     self.submission_dialog = Some(SubmissionDialog::Confirm {
                         request,
                         submit: false,
                     });
+                    return vec![Effect::Background(
+                        BackgroundEffect::LoadSubmissionCooldown(puzzle),
+                    )];
                 } else if self.run_dialog.is_some() {
                     self.status = Some("This run result cannot be submitted".to_owned());
                 } else if self.active_tab == Tab::Calendar {
@@ -896,6 +1281,9 @@ impl App {
                         puzzle,
                         part: PuzzlePart::One,
                     });
+                    return vec![Effect::Background(
+                        BackgroundEffect::LoadSubmissionCooldown(puzzle),
+                    )];
                 }
             }
             Action::ToggleSubmissionChoice => match &mut self.submission_dialog {
@@ -939,12 +1327,19 @@ impl App {
                 if self.language_busy() {
                     return Vec::new();
                 }
-                self.language = match self.language {
-                    LanguageId::Rust => LanguageId::Python,
-                    LanguageId::Python => LanguageId::Rust,
-                };
+                // Custom languages are chosen in the config; switching cycles the built-ins.
+                let next = LanguageId::BUILT_IN
+                    .iter()
+                    .position(|language| *language == self.language)
+                    .map_or(0, |index| (index + 1) % LanguageId::BUILT_IN.len());
+                self.language = LanguageId::BUILT_IN[next];
                 self.clear_language_data();
-                return self.load_language_data();
+                let mut effects = self.load_language_data();
+                if let Some(puzzle) = self.selected_puzzle {
+                    effects.push(self.load_run_history(puzzle));
+                    effects.push(self.load_solution_variants(puzzle));
+                }
+                return effects;
             }
             Action::RefreshLanguage if self.active_tab == Tab::Language => {
                 if !self.language_busy() {
@@ -1076,6 +1471,12 @@ impl App {
                                 value: None,
                             });
                         }
+                        ConfigField::RunTimeout => {
+                            return self.save_config(ConfigMutation::Set {
+                                field: NonSecretConfigField::RunTimeout,
+                                value: None,
+                            });
+                        }
                         ConfigField::Session
                             if self
                                 .config
@@ -1177,6 +1578,7 @@ impl App {
                     return Vec::new();
                 }
                 self.calendar_loading = false;
+                let load_stars = Effect::Background(BackgroundEffect::LoadStars(year));
                 match result {
                     Ok(calendar) => {
                         let previous_puzzle = self.selected_puzzle;
@@ -1188,11 +1590,13 @@ impl App {
                         self.calendar = Some(calendar);
                         self.selected_puzzle = selected;
                         self.preview_puzzle = selected;
-                        let mut effects = Vec::new();
+                        let mut effects = vec![load_stars];
                         if selected != previous_puzzle {
                             self.clear_description();
                             if let Some(puzzle) = selected {
                                 effects.push(self.check_cached_description(puzzle));
+                                effects.push(self.load_run_history(puzzle));
+                                effects.push(self.load_solution_variants(puzzle));
                             }
                         }
                         self.status = None;
@@ -1203,9 +1607,19 @@ impl App {
                             self.preview_puzzle = None;
                         }
                         self.status = Some(message);
+                        return vec![load_stars];
                     }
                 }
             }
+            Action::StarsLoaded { year, result } => {
+                if year != self.selected_year {
+                    return Vec::new();
+                }
+                match result {
+                    Ok(stars) => self.year_stars = Some(stars),
+                    Err(message) => self.status = Some(message),
+                }
+            }
             Action::CachedDescriptionFinished { puzzle, result } => {
                 if Some(puzzle) != self.selected_puzzle()
                     || !matches!(
@@ -1270,12 +1684,75 @@ impl App {
             }
             Action::RunFinished { request, result } => {
                 self.active_run = None;
+                self.run_cancellation = None;
+                self.run_started = None;
+                self.run_log.clear();
                 self.status = None;
+                let recorded = result.is_ok();
                 self.run_dialog = Some(RunDialog {
                     request,
                     result,
                     scroll: 0,
                 });
+                let mut effects = Vec::new();
+                if recorded && self.selected_puzzle == Some(request.puzzle) {
+                    effects.push(self.load_run_history(request.puzzle));
+                }
+                if let Some(watched) = self.watching.filter(|_| self.watch_pending) {
+                    self.watch_pending = false;
+                    effects.extend(self.start_run(watched));
+                }
+                return effects;
+            }
+            Action::RunHistoryLoaded {
+                puzzle,
+                language,
+                result,
+            } => {
+                if Some(puzzle) != self.selected_puzzle || language != self.language {
+                    return Vec::new();
+                }
+                match result {
+                    Ok(parts) => {
+                        self.run_history = Some(RunHistoryView {
+                            puzzle,
+                            language,
+                            parts,
+                        });
+                    }
+                    Err(message) => self.status = Some(message),
+                }
+            }
+            Action::SolutionVariantsLoaded {
+                puzzle,
+                language,
+                result,
+            } => {
+                if Some(puzzle) != self.selected_puzzle || language != self.language {
+                    return Vec::new();
+                }
+                match result {
+                    Ok((solutions, active)) => {
+                        let changed = self.solution_variants.as_ref().is_some_and(|variants| {
+                            variants.puzzle == puzzle
+                                && variants.language == language
+                                && variants.active != active
+                        });
+                        self.solution_variants = Some(SolutionVariants {
+                            puzzle,
+                            language,
+                            solutions,
+                            active,
+                        });
+                        // A watch follows the selected solution file.
+                        if let Some(watched) = self.watching.filter(|watched| {
+                            changed && watched.puzzle == puzzle && watched.language == language
+                        }) {
+                            return self.watch(watched);
+                        }
+                    }
+                    Err(message) => self.status = Some(message),
+                }
             }
             Action::SubmissionFinished { request, result } => {
                 self.active_submission = None;
@@ -1286,10 +1763,21 @@ impl App {
                     result,
                     scroll: 0,
                 });
+                let mut effects = vec![Effect::Background(
+                    BackgroundEffect::LoadSubmissionCooldown(request.puzzle),
+                )];
                 if correct {
-                    return self.submission_refreshes(request.puzzle, request.part);
+                    effects.extend(self.submission_refreshes(request.puzzle, request.part));
                 }
+                return effects;
             }
+            Action::SubmissionCooldownLoaded { puzzle, result } => match result {
+                Ok(remaining) => {
+                    self.submission_cooldown =
+                        remaining.map(|remaining| (puzzle, Instant::now() + remaining));
+                }
+                Err(message) => self.status = Some(message),
+            },
             Action::LanguageDataFinished { language, result } => {
                 if language != self.language {
                     return Vec::new();
@@ -1378,9 +1866,106 @@ impl App {
                     self.show_config_error(message);
                 }
             },
+            Action::RefreshLeaderboard
+                if self.active_tab == Tab::Leaderboard && !self.leaderboard_loading =>
+            {
+                if let Some(remaining) = self
+                    .leaderboard
+                    .as_ref()
+                    .filter(|view| self.leaderboard_selected(view.year, view.id))
+                    .and_then(LeaderboardView::refresh_in)
+                {
+                    self.status = Some(format!(
+                        "AoC asks for 15 minutes between leaderboard fetches: showing the cached \
+                         copy, refresh again in {}",
+                        format_cooldown(remaining)
+                    ));
+                    return Vec::new();
+                }
+                return self.load_leaderboard_ids(true);
+            }
+            Action::PreviousLeaderboard | Action::NextLeaderboard
+                if self.active_tab == Tab::Leaderboard && !self.leaderboard_loading =>
+            {
+                let count = self.leaderboard_ids.as_ref().map_or(0, Vec::len);
+                let selection = if matches!(action, Action::PreviousLeaderboard) {
+                    self.leaderboard_selection.saturating_sub(1)
+                } else {
+                    (self.leaderboard_selection + 1).min(count.saturating_sub(1))
+                };
+                if selection != self.leaderboard_selection {
+                    self.leaderboard_selection = selection;
+                    return self.load_selected_leaderboard(false);
+                }
+            }
+            Action::ToggleLeaderboardDay if self.active_tab == Tab::Leaderboard => {
+                self.leaderboard_scroll = 0;
+                if self.leaderboard_day.take().is_none() {
+                    self.leaderboard_day = self
+                        .leaderboard
+                        .as_ref()
+                        .and_then(|view| view.days(self.latest_puzzle).last().copied());
+                }
+            }
+            Action::PreviousLeaderboardDay | Action::NextLeaderboardDay
+                if self.active_tab == Tab::Leaderboard =>
+            {
+                let (Some(day), Some(view)) = (self.leaderboard_day, &self.leaderboard) else {
+                    return Vec::new();
+                };
+                let days = view.days(self.latest_puzzle);
+                let index = days.iter().position(|shown| *shown == day).unwrap_or(0);
+                let index = if matches!(action, Action::PreviousLeaderboardDay) {
+                    index.saturating_sub(1)
+                } else {
+                    (index + 1).min(days.len().saturating_sub(1))
+                };
+                if let Some(day) = days.get(index) {
+                    self.leaderboard_day = Some(*day);
+                    self.leaderboard_scroll = 0;
+                }
+            }
+            Action::ScrollLeaderboardUp if self.active_tab == Tab::Leaderboard => {
+                self.leaderboard_scroll = self.leaderboard_scroll.saturating_sub(1);
+            }
+            Action::ScrollLeaderboardDown if self.active_tab == Tab::Leaderboard => {
+                self.leaderboard_scroll = self.leaderboard_scroll.saturating_add(1);
+            }
+            Action::LeaderboardIdsLoaded { refresh, result } => match result {
+                Ok(ids) => {
+                    self.leaderboard_selection =
+                        self.leaderboard_selection.min(ids.len().saturating_sub(1));
+                    self.leaderboard_ids = Some(ids);
+                    return self.load_selected_leaderboard(refresh);
+                }
+                Err(message) => {
+                    self.leaderboard_loading = false;
+                    self.status = Some(message);
+                }
+            },
+            Action::LeaderboardLoaded { year, id, result } => {
+                if !self.leaderboard_selected(year, id) {
+                    return Vec::new();
+                }
+                self.leaderboard_loading = false;
+                match result {
+                    Ok((leaderboard, refresh_in)) => {
+                        self.leaderboard = Some(LeaderboardView {
+                            year,
+                            id,
+                            leaderboard,
+                            refresh_at: refresh_in.map(|remaining| Instant::now() + remaining),
+                        });
+                        self.status = None;
+                    }
+                    Err(message) => self.status = Some(message),
+                }
+            }
             Action::BackgroundSubmissionFailed { effect, message } => {
                 self.background_submission_failed(effect, message);
             }
+            Action::Throttled(message) => self.status = Some(message),
+            Action::OfflineMode(offline) => self.offline = offline,
             Action::ForegroundFinished(result) => {
                 if let Some(language_active) = self.lazygit_opening.take() {
                     self.lazygit_error = result.err();
@@ -1480,8 +2065,11 @@ impl App {
                     "Could not queue workspace Git preparation: {message}"
                 ));
             }
-            BackgroundEffect::RunSolver(request) => {
+            BackgroundEffect::RunSolver { request, .. } => {
                 self.active_run = None;
+                self.run_cancellation = None;
+                self.run_started = None;
+                self.run_log.clear();
                 self.status = None;
                 self.run_dialog = Some(RunDialog {
                     request,
@@ -1492,6 +2080,13 @@ impl App {
                     scroll: 0,
                 });
             }
+            BackgroundEffect::ResolveWatchedFiles(request) => {
+                if self.watching == Some(request) {
+                    self.watching = None;
+                    self.watch_pending = false;
+                    self.status = Some(format!("Could not queue watching the solution: {message}"));
+                }
+            }
             BackgroundEffect::SubmitAnswer(request) => {
                 self.active_submission = None;
                 self.submission_dialog = Some(SubmissionDialog::Outcome {
@@ -1501,6 +2096,11 @@ impl App {
                     scroll: 0,
                 });
             }
+            BackgroundEffect::LoadSubmissionCooldown(_)
+            | BackgroundEffect::LoadStars(_)
+            | BackgroundEffect::LoadRunHistory { .. }
+            | BackgroundEffect::LoadSolutionVariants { .. }
+            | BackgroundEffect::SelectSolution { .. } => self.status = Some(message),
             BackgroundEffect::LoadLanguageData { .. }
             | BackgroundEffect::MutateLanguage { .. }
             | BackgroundEffect::PrepareLanguageFile { .. } => {
@@ -1511,6 +2111,11 @@ impl App {
                 self.quit_after_config_save = false;
                 self.show_config_error(message);
             }
+            BackgroundEffect::LoadLeaderboardIds { .. }
+            | BackgroundEffect::LoadLeaderboard { .. } => {
+                self.leaderboard_loading = false;
+                self.status = Some(message);
+            }
         }
     }
 
@@ -1535,18 +2140,29 @@ impl App {
                 ..
             } => {
                 let answer = answer.trim().to_owned();
-                if answer.is_empty() {
+                let error = if answer.is_empty() {
+                    Some("Answer cannot be empty".to_owned())
+                } else {
+                    self.submission_cooldown_remaining(puzzle)
+                        .map(cooldown_message)
+                };
+                if error.is_some() {
                     self.submission_dialog = Some(SubmissionDialog::Answer {
                         puzzle,
                         part,
                         answer,
-                        error: Some("Answer cannot be empty".to_owned()),
+                        error,
                     });
                     return Vec::new();
                 }
                 self.start_submission(SubmissionRequest::new(puzzle, part, answer))
             }
             SubmissionDialog::Confirm { request, submit } if submit => {
+                if let Some(remaining) = self.submission_cooldown_remaining(request.puzzle) {
+                    self.status = Some(cooldown_message(remaining));
+                    self.submission_dialog = Some(SubmissionDialog::Confirm { request, submit });
+                    return Vec::new();
+                }
                 self.run_dialog = None;
                 self.start_submission(request)
             }
@@ -1554,6 +2170,19 @@ impl App {
         }
     }
 
+    /// How long the active solver run has been going.
+    pub(crate) fn run_elapsed(&self) -> Option<Duration> {
+        self.run_started.map(|started| started.elapsed())
+    }
+
+    /// Time left before AoC accepts another answer for the puzzle.
+    pub(crate) fn submission_cooldown_remaining(&self, puzzle: PuzzleId) -> Option<Duration> {
+        self.submission_cooldown
+            .filter(|(cooldown_puzzle, _)| *cooldown_puzzle == puzzle)
+            .map(|(_, expires_at)| expires_at.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
     fn start_submission(&mut self, request: SubmissionRequest) -> Vec<Effect> {
         if self.active_submission.is_some() {
             return Vec::new();
@@ -1594,9 +2223,65 @@ impl App {
         if tab == Tab::Config && self.config.is_none() && !self.config_busy() {
             return self.load_config();
         }
+        if tab == Tab::Leaderboard && !self.leaderboard_loading {
+            if self.leaderboard_ids.is_none() {
+                return self.load_leaderboard_ids(false);
+            }
+            // The leaderboard follows the year picked on the calendar.
+            if self
+                .leaderboard
+                .as_ref()
+                .is_none_or(|view| view.year != self.selected_year)
+            {
+                return self.load_selected_leaderboard(false);
+            }
+        }
         Vec::new()
     }
 
+    fn load_leaderboard_ids(&mut self, refresh: bool) -> Vec<Effect> {
+        self.leaderboard_loading = true;
+        self.status = None;
+        vec![Effect::Background(BackgroundEffect::LoadLeaderboardIds {
+            refresh,
+        })]
+    }
+
+    pub(crate) fn selected_leaderboard_id(&self) -> Option<u32> {
+        self.leaderboard_ids
+            .as_ref()?
+            .get(self.leaderboard_selection)
+            .copied()
+    }
+
+    fn leaderboard_selected(&self, year: PuzzleYear, id: u32) -> bool {
+        year == self.selected_year && self.selected_leaderboard_id() == Some(id)
+    }
+
+    fn load_selected_leaderboard(&mut self, refresh: bool) -> Vec<Effect> {
+        let Some(id) = self.selected_leaderboard_id() else {
+            self.leaderboard_loading = false;
+            self.leaderboard = None;
+            return Vec::new();
+        };
+        let year = self.selected_year;
+        if self
+            .leaderboard
+            .as_ref()
+            .is_some_and(|view| view.year != year || view.id != id)
+        {
+            self.leaderboard = None;
+            self.leaderboard_day = None;
+            self.leaderboard_scroll = 0;
+        }
+        self.leaderboard_loading = true;
+        vec![Effect::Background(BackgroundEffect::LoadLeaderboard {
+            year,
+            id,
+            refresh,
+        })]
+    }
+
     fn config_busy(&self) -> bool {
         self.config_operation != ConfigOperationState::Idle
     }
@@ -1637,6 +2322,11 @@ impl App {
                 value: String::new(),
                 error: None,
             },
+            ConfigField::RunTimeout => ConfigDialog::Text {
+                field: NonSecretConfigField::RunTimeout,
+                value: String::new(),
+                error: None,
+            },
             ConfigField::Session => ConfigDialog::Session {
                 value: SecretString::empty(),
                 error: None,
@@ -1698,6 +2388,23 @@ impl App {
                             value: Some(value),
                         }
                     }
+                    NonSecretConfigField::RunTimeout if value.is_empty() => {
+                        ConfigMutation::Set { field, value: None }
+                    }
+                    NonSecretConfigField::RunTimeout => match value.parse::<RunTimeout>() {
+                        Ok(timeout) => ConfigMutation::Set {
+                            field,
+                            value: Some(timeout.to_string()),
+                        },
+                        Err(_) => {
+                            self.config_dialog = Some(ConfigDialog::Text {
+                                field,
+                                value,
+                                error: Some("Enter a positive number of seconds".to_owned()),
+                            });
+                            return Vec::new();
+                        }
+                    },
                 };
                 self.save_config(mutation)
             }
@@ -1735,6 +2442,30 @@ impl App {
         self.config_dialog = Some(ConfigDialog::Message { message, scroll: 0 });
     }
 
+    fn start_run(&mut self, request: RunRequest) -> Vec<Effect> {
+        let cancellation = CancellationToken::new();
+        self.active_run = Some(request);
+        self.run_cancellation = Some(cancellation.clone());
+        self.run_started = Some(Instant::now());
+        self.run_log.clear();
+        self.run_spinner_frame = 0;
+        self.run_dialog = None;
+        self.status = None;
+        vec![Effect::Background(BackgroundEffect::RunSolver {
+            request,
+            cancellation,
+        })]
+    }
+
+    /// Watches the files `request` depends on, replacing any previous watch.
+    fn watch(&mut self, request: RunRequest) -> Vec<Effect> {
+        self.watching = Some(request);
+        self.watch_pending = false;
+        vec![Effect::Background(BackgroundEffect::ResolveWatchedFiles(
+            request,
+        ))]
+    }
+
     fn language_busy(&self) -> bool {
         matches!(
             self.language_operation,
@@ -1919,6 +2650,7 @@ impl App {
         self.preview_puzzle = self.selected_puzzle.or(self.preview_puzzle);
         self.selected_puzzle = None;
         self.calendar = None;
+        self.year_stars = None;
         self.calendar_loading = true;
         self.calendar_scroll = (0, 0);
         self.clear_description();
@@ -1953,7 +2685,11 @@ impl App {
         self.selected_puzzle = Some(puzzle);
         self.preview_puzzle = Some(puzzle);
         self.clear_description();
-        vec![self.check_cached_description(puzzle)]
+        vec![
+            self.check_cached_description(puzzle),
+            self.load_run_history(puzzle),
+            self.load_solution_variants(puzzle),
+        ]
     }
 
     fn selected_puzzle_or_status(&mut self) -> Option<PuzzleId> {
@@ -1969,6 +2705,34 @@ impl App {
         Effect::Background(BackgroundEffect::LoadCachedDescription(puzzle))
     }
 
+    fn load_run_history(&self, puzzle: PuzzleId) -> Effect {
+        Effect::Background(BackgroundEffect::LoadRunHistory {
+            puzzle,
+            language: self.language,
+        })
+    }
+
+    fn load_solution_variants(&self, puzzle: PuzzleId) -> Effect {
+        Effect::Background(BackgroundEffect::LoadSolutionVariants {
+            puzzle,
+            language: self.language,
+        })
+    }
+
+    /// Solutions of the selected puzzle, once loaded for the active language.
+    pub(crate) fn selected_solution_variants(&self) -> Option<&SolutionVariants> {
+        self.solution_variants.as_ref().filter(|variants| {
+            Some(variants.puzzle) == self.selected_puzzle && variants.language == self.language
+        })
+    }
+
+    /// Recent runtimes for the selected puzzle, once loaded for the active language.
+    pub(crate) fn selected_run_history(&self) -> Option<&RunHistoryView> {
+        self.run_history.as_ref().filter(|history| {
+            Some(history.puzzle) == self.selected_puzzle && history.language == self.language
+        })
+    }
+
     fn clear_description(&mut self) {
         self.description = DescriptionState::Empty;
         self.description_scroll = 0;
@@ -1993,13 +2757,22 @@ fn calendar_puzzles(calendar: &Calendar) -> Vec<PuzzleId> {
 
 #[cfg(test)]
 mod tests {
-    use aocsuite_parser::{AocSubmissionResult, Calendar, CalendarCell, CalendarRow, Rgb};
-    use aocsuite_utils::{LanguageId, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear};
+    use std::time::Duration;
+
+    use aocsuite_parser::{
+        parse_private_leaderboard, AocSubmissionResult, Calendar, CalendarCell, CalendarRow, Rgb,
+    };
+    use aocsuite_storage::{PuzzleAnswers, RunHistory};
+    use aocsuite_utils::{
+        puzzle_unlock_timestamp, CancellationToken, LanguageId, OutputLine, OutputStream,
+        PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear, SolutionId, WatchList,
+    };
 
     use super::{
-        Action, App, BackgroundEffect, ConfigData, ConfigDialog, DescriptionState, Effect,
-        LanguageData, LanguageDialog, LanguageOperationState, NonSecretConfigField, RunDialog,
-        RunInput, RunPartReport, RunReport, SubmissionDialog, SubmissionRequest,
+        leaderboard_day_rows, Action, App, BackgroundEffect, ConfigData, ConfigDialog,
+        DescriptionState, Effect, LanguageData, LanguageDialog, LanguageOperationState,
+        NonSecretConfigField, RunDialog, RunFailure, RunInput, RunPartReport, RunReport,
+        SubmissionDialog, SubmissionRequest, Tab,
     };
 
     fn puzzle(day: u32, year: i32) -> PuzzleId {
@@ -2034,6 +2807,7 @@ mod tests {
                 year: "2026".to_owned(),
                 editor: Some("vim".to_owned()),
                 run_history_limit: "10".to_owned(),
+                run_timeout: "30".to_owned(),
                 session_configured,
             }),
         });
@@ -2084,6 +2858,8 @@ mod tests {
                     part: PuzzlePart::Two,
                     answer: "retained-answer".to_owned(),
                     runtime_ms: 1,
+                    expected: PuzzleAnswers::default(),
+                    accepted: PuzzleAnswers::default(),
                 }],
                 warning: None,
             }),
@@ -2146,6 +2922,49 @@ mod tests {
         assert!(format!("{dialog:?}").contains("validation context"));
     }
 
+    #[test]
+    fn offline_mode_disables_submission() {
+        let mut app = app();
+        app.offline = true;
+        app.selected_puzzle = Some(puzzle(10, 2026));
+
+        assert!(app.update(Action::OpenSubmission).is_empty());
+        assert!(app.submission_dialog.is_none());
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Offline: submitting answers is disabled")
+        );
+    }
+
+    #[test]
+    fn offline_mode_follows_what_the_effects_report() {
+        let mut app = app();
+        app.selected_puzzle = Some(puzzle(10, 2026));
+
+        app.update(Action::OfflineMode(true));
+        assert!(app.update(Action::OpenSubmission).is_empty());
+        assert!(app.submission_dialog.is_none());
+
+        app.update(Action::OfflineMode(false));
+        app.update(Action::OpenSubmission);
+        assert!(app.submission_dialog.is_some());
+    }
+
+    #[test]
+    fn throttled_requests_show_their_wait_in_the_status() {
+        let mut app = selected_app();
+        app.active_run = Some(super::RunRequest {
+            puzzle: app.selected_puzzle().unwrap(),
+            language: LanguageId::Rust,
+            part: PuzzlePart::One,
+            input: RunInput::Aoc,
+        });
+
+        let message = "Throttled: waiting 4s before the next AoC input request";
+        assert!(app.update(Action::Throttled(message.to_owned())).is_empty());
+        assert_eq!(app.status.as_deref(), Some(message));
+    }
+
     #[test]
     fn active_submission_blocks_duplicates_and_correct_refreshes_visible_content() {
         let mut app = app();
@@ -2179,6 +2998,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn loaded_submission_cooldown_holds_answers_until_it_expires() {
+        let mut app = app();
+        let selected = puzzle(10, 2026);
+        app.submission_dialog = Some(SubmissionDialog::Answer {
+            puzzle: selected,
+            part: PuzzlePart::One,
+            answer: "42".to_owned(),
+            error: None,
+        });
+        app.update(Action::SubmissionCooldownLoaded {
+            puzzle: selected,
+            result: Ok(Some(Duration::from_secs(60))),
+        });
+
+        assert!(app.update(Action::SubmissionSubmit).is_empty());
+        assert!(app.active_submission.is_none());
+        assert!(matches!(
+            app.submission_dialog,
+            Some(SubmissionDialog::Answer { error: Some(ref error), .. })
+                if error.starts_with("Submission cooldown")
+        ));
+        assert!(app.submission_cooldown_remaining(puzzle(9, 2026)).is_none());
+
+        app.update(Action::SubmissionCooldownLoaded {
+            puzzle: selected,
+            result: Ok(None),
+        });
+        assert_eq!(
+            app.update(Action::SubmissionSubmit),
+            vec![Effect::Background(BackgroundEffect::SubmitAnswer(
+                SubmissionRequest::new(selected, PuzzlePart::One, "42".to_owned())
+            ))]
+        );
+    }
+
     fn load_calendar(app: &mut App, calendar: Calendar, refresh: bool) -> Vec<Effect> {
         app.update(Action::CalendarFinished {
             year: app.selected_year,
@@ -2200,6 +3055,87 @@ mod tests {
         app
     }
 
+    #[test]
+    fn run_history_follows_the_selected_puzzle_and_language() {
+        let mut app = selected_app();
+        let first = app.selected_puzzle().unwrap();
+        let effects = app.update(Action::NextCalendarPuzzle);
+        let second = app.selected_puzzle().unwrap();
+        assert!(
+            effects.contains(&Effect::Background(BackgroundEffect::LoadRunHistory {
+                puzzle: second,
+                language: LanguageId::Rust,
+            }))
+        );
+
+        let history = vec![(PuzzlePart::One, RunHistory::default())];
+        for (puzzle, language) in [(first, LanguageId::Rust), (second, LanguageId::Python)] {
+            app.update(Action::RunHistoryLoaded {
+                puzzle,
+                language,
+                result: Ok(history.clone()),
+            });
+            assert!(app.selected_run_history().is_none());
+        }
+        app.update(Action::RunHistoryLoaded {
+            puzzle: second,
+            language: LanguageId::Rust,
+            result: Ok(history.clone()),
+        });
+        assert_eq!(
+            app.selected_run_history().map(|view| &view.parts),
+            Some(&history)
+        );
+
+        app.update(Action::PreviousCalendarPuzzle);
+        assert!(app.selected_run_history().is_none());
+    }
+
+    #[test]
+    fn solution_variant_picker_cycles_and_rewatches_the_selected_solution() {
+        let mut app = selected_app();
+        let puzzle = app.selected_puzzle().unwrap();
+        let fast: SolutionId = format!("{puzzle}.fast").parse().unwrap();
+        let loaded = |active| Action::SolutionVariantsLoaded {
+            puzzle,
+            language: LanguageId::Rust,
+            result: Ok((vec![puzzle.into(), fast], active)),
+        };
+        assert!(app.update(Action::NextSolutionVariant).is_empty());
+
+        app.update(loaded(puzzle.into()));
+        assert_eq!(
+            app.update(Action::NextSolutionVariant),
+            vec![Effect::Background(BackgroundEffect::SelectSolution {
+                language: LanguageId::Rust,
+                solution: fast,
+            })]
+        );
+
+        let request = super::RunRequest {
+            puzzle,
+            language: LanguageId::Rust,
+            part: PuzzlePart::One,
+            input: RunInput::Aoc,
+        };
+        app.update(Action::ToggleWatch);
+        app.update(Action::RunFinished {
+            request,
+            result: Err(RunFailure {
+                summary: "Solver failed".to_owned(),
+                details: None,
+            }),
+        });
+        assert_eq!(
+            app.update(loaded(fast)),
+            vec![Effect::Background(BackgroundEffect::ResolveWatchedFiles(
+                request
+            ))]
+        );
+        assert_eq!(app.selected_solution_variants().unwrap().active, fast);
+        assert!(app.update(loaded(fast)).is_empty());
+    }
+
     #[test]
     fn calendar_navigation_follows_visual_puzzle_order() {
         let mut app = app();
@@ -2265,12 +3201,117 @@ mod tests {
             part: PuzzlePart::One,
             input: RunInput::Aoc,
         };
+        let cancellation = app.run_cancellation.clone().expect("run can be cancelled");
         assert_eq!(
             effects,
-            vec![Effect::Background(BackgroundEffect::RunSolver(request))]
+            vec![Effect::Background(BackgroundEffect::RunSolver {
+                request,
+                cancellation: cancellation.clone(),
+            })]
         );
         assert_eq!(app.active_run, Some(request));
         assert!(app.update(Action::RunPart(PuzzlePart::Two)).is_empty());
+
+        let line = |text: &str| OutputLine {
+            stream: OutputStream::Stdout,
+            text: text.to_owned(),
+        };
+        app.update(Action::RunOutput {
+            request,
+            line: line("parsed 4 rows"),
+        });
+        app.update(Action::RunOutput {
+            request: super::RunRequest {
+                part: PuzzlePart::Two,
+                ..request
+            },
+            line: line("stale"),
+        });
+        assert_eq!(app.run_log, [line("parsed 4 rows")]);
+        assert!(app.run_elapsed().is_some());
+
+        assert!(app.update(Action::CancelRun).is_empty());
+        assert!(cancellation.is_cancelled());
+        assert_eq!(app.active_run, Some(request));
+        app.update(Action::RunFinished {
+            request,
+            result: Err(RunFailure {
+                summary: "Solver run cancelled".to_owned(),
+                details: None,
+            }),
+        });
+        assert!(app.active_run.is_none());
+        assert!(app.run_cancellation.is_none());
+        assert!(app.run_log.is_empty());
+        assert!(app.run_elapsed().is_none());
+    }
+
+    #[test]
+    fn watched_saves_rerun_the_solution_once_the_current_run_finishes() {
+        let mut app = selected_app();
+        let request = super::RunRequest {
+            puzzle: app.selected_puzzle().unwrap(),
+            language: LanguageId::Rust,
+            part: PuzzlePart::One,
+            input: RunInput::Aoc,
+        };
+        let finished = |request| Action::RunFinished {
+            request,
+            result: Err(RunFailure {
+                summary: "Solver failed".to_owned(),
+                details: None,
+            }),
+        };
+        let is_run = |effects: &[Effect]| {
+            matches!(
+                effects,
+                [Effect::Background(BackgroundEffect::RunSolver { request: run, .. })]
+                    if *run == request
+            )
+        };
+
+        let effects = app.update(Action::ToggleWatch);
+        assert_eq!(
+            effects[0],
+            Effect::Background(BackgroundEffect::ResolveWatchedFiles(request))
+        );
+        assert!(is_run(&effects[1..]));
+        assert_eq!(app.watching, Some(request));
+        let watched = WatchList {
+            files: vec![std::path::PathBuf::from("solutions/year2026_day10.rs")],
+            dirs: Vec::new(),
+        };
+        assert_eq!(
+            app.update(Action::WatchedFilesResolved {
+                request,
+                result: Ok(watched.clone()),
+            }),
+            vec![Effect::Watch { request, watched }]
+        );
+
+        let resolve = Effect::Background(BackgroundEffect::ResolveWatchedFiles(request));
+        assert_eq!(
+            app.update(Action::WatchChanged(request)),
+            std::slice::from_ref(&resolve)
+        );
+        let effects = app.update(finished(request));
+        assert!(is_run(&effects));
+        app.update(finished(request));
+        assert!(app.run_dialog.is_some());
+
+        let effects = app.update(Action::WatchChanged(request));
+        assert_eq!(effects[0], resolve);
+        assert!(is_run(&effects[1..]));
+        app.update(finished(request));
+        let stale = super::RunRequest {
+            part: PuzzlePart::Two,
+            ..request
+        };
+        assert!(app.update(Action::WatchChanged(stale)).is_empty());
+
+        assert_eq!(app.update(Action::ToggleWatch), vec![Effect::StopWatch]);
+        assert!(app.watching.is_none());
+        assert!(app.update(Action::WatchChanged(request)).is_empty());
     }
 
     #[test]
@@ -2284,7 +3325,10 @@ mod tests {
         };
         app.active_run = Some(run_request);
         app.update(Action::BackgroundSubmissionFailed {
-            effect: BackgroundEffect::RunSolver(run_request),
+            effect: BackgroundEffect::RunSolver {
+                request: run_request,
+                cancellation: CancellationToken::new(),
+            },
             message: "worker stopped".to_owned(),
         });
 
@@ -2375,9 +3419,112 @@ mod tests {
                 year: "2026".to_owned(),
                 editor: Some("vim".to_owned()),
                 run_history_limit: "10".to_owned(),
+                run_timeout: "30".to_owned(),
                 session_configured: false,
             }),
         });
         assert!(app.should_quit);
     }
+
+    #[test]
+    fn leaderboard_tab_ranks_members_and_shows_day_gaps_within_the_refresh_floor() {
+        let mut app = app();
+        let year = PuzzleYear::new(2026).unwrap();
+        app.update(Action::NextTab);
+        assert_eq!(
+            app.update(Action::NextTab),
+            [Effect::Background(BackgroundEffect::LoadLeaderboardIds {
+                refresh: false
+            })]
+        );
+        assert_eq!(app.active_tab, Tab::Leaderboard);
+        assert_eq!(
+            app.update(Action::LeaderboardIdsLoaded {
+                refresh: false,
+                result: Ok(vec![42, 7]),
+            }),
+            [Effect::Background(BackgroundEffect::LoadLeaderboard {
+                year,
+                id: 42,
+                refresh: false,
+            })]
+        );
+
+        let unlock = puzzle_unlock_timestamp(puzzle(3, 2026));
+        let json = format!(
+            r#"{{"owner_id":1,"members":{{
+                "1":{{"id":1,"name":"steady","stars":2,"local_score":4,"last_star_ts":{b},
+                    "completion_day_level":{{"3":{{"1":{{"get_star_ts":{a}}},"2":{{"get_star_ts":{b}}}}}}}}},
+                "2":{{"id":2,"name":"sprinter","stars":2,"local_score":6,"last_star_ts":{d},
+                    "completion_day_level":{{"3":{{"1":{{"get_star_ts":{c}}},"2":{{"get_star_ts":{d}}}}}}}}},
+                "3":{{"id":3,"name":"halfway","stars":1,"local_score":1,"last_star_ts":{c},
+                    "completion_day_level":{{"3":{{"1":{{"get_star_ts":{c}}}}}}}}}}}}}"#,
+            a = unlock + 600,
+            b = unlock + 4200,
+            c = unlock + 300,
+            d = unlock + 3900,
+        );
+        let leaderboard = parse_private_leaderboard(&json).expect("parse leaderboard");
+        let rows = leaderboard_day_rows(&leaderboard, puzzle(3, 2026));
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| (row.name.as_str(), row.part2, row.gap()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "sprinter",
+                    Some(Duration::from_secs(3900)),
+                    Some(Duration::from_secs(3600))
+                ),
+                (
+                    "steady",
+                    Some(Duration::from_secs(4200)),
+                    Some(Duration::from_secs(3600))
+                ),
+                ("halfway", None, None),
+            ]
+        );
+
+        app.update(Action::LeaderboardLoaded {
+            year,
+            id: 42,
+            result: Ok((leaderboard.clone(), Some(Duration::from_secs(600)))),
+        });
+        assert!(!app.leaderboard_loading);
+        assert!(app.update(Action::RefreshLeaderboard).is_empty());
+        assert!(app
+            .status
+            .as_deref()
+            .is_some_and(|status| status.contains("refresh again in")));
+
+        app.update(Action::ToggleLeaderboardDay);
+        assert_eq!(app.leaderboard_day, PuzzleDay::new(10).ok());
+        app.update(Action::NextLeaderboardDay);
+        assert_eq!(app.leaderboard_day, PuzzleDay::new(10).ok());
+        for _ in 0..7 {
+            app.update(Action::PreviousLeaderboardDay);
+        }
+        assert_eq!(app.leaderboard_day, PuzzleDay::new(3).ok());
+
+        assert_eq!(
+            app.update(Action::NextLeaderboard),
+            [Effect::Background(BackgroundEffect::LoadLeaderboard {
+                year,
+                id: 7,
+                refresh: false,
+            })]
+        );
+        assert_eq!(
+            (app.leaderboard.as_ref(), app.leaderboard_day),
+            (None, None)
+        );
+        app.update(Action::LeaderboardLoaded {
+            year,
+            id: 42,
+            result: Ok((leaderboard, None)),
+        });
+        assert!(app.leaderboard.is_none() && app.leaderboard_loading);
+    }
 }
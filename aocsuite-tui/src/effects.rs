@@ -4,20 +4,24 @@ use std::{
         mpsc, Arc,
     },
     thread,
+    time::Duration,
 };
 
-use aocsuite_client::{AocClient, AocClientOptions, AocPage};
+use aocsuite_client::{AocClient, AocClientOptions, AocPage, RequestThrottle};
 use aocsuite_config::{AocConfigError, ConfigKey, Configuration};
 use aocsuite_lang::{
     AocLanguageError, ConfirmedLibraryRemoval, ConfirmedTemplateReset, Language, LanguageRunOutput,
-    SolverFile,
+    LanguageSettings, RunOptions, SolverFile, TemplateVariables,
 };
 use aocsuite_launcher::{Launcher, OpenPuzzleRequest};
 use aocsuite_parser::{parse_calendar, parse_submission};
-use aocsuite_storage::{ContentStore, RuntimeLayout, Workspace, WorkspaceError};
+use aocsuite_storage::{
+    ContentStore, PuzzleAnswers, RunHistory, RuntimeLayout, Workspace, WorkspaceError,
+};
 use aocsuite_utils::{
-    valid_puzzle_release, CommandError, CommandExecutor, LanguageId, PartSelection, PuzzleId,
-    PuzzlePart, RunHistoryLimit, SystemCommandExecutor,
+    format_cooldown, valid_puzzle_release, CancellationToken, CommandError, CommandExecutor,
+    FileWatcher, LanguageId, LeaderboardIds, OutputSink, PartSelection, PuzzleId, PuzzlePart,
+    RunHistoryLimit, RunTimeout, SystemCommandExecutor, TemplateName, WatchError, WatchList,
 };
 
 use crate::{
@@ -29,30 +33,84 @@ use crate::{
     TuiError,
 };
 
+/// How often the watcher thread checks whether its watch was replaced or stopped.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 pub(crate) struct EffectRunner {
     sender: Option<mpsc::Sender<BackgroundEffect>>,
     receiver: mpsc::Receiver<Action>,
     worker: Option<thread::JoinHandle<()>>,
     shutdown: Arc<AtomicBool>,
+    action_sender: mpsc::Sender<Action>,
+    watcher: Option<WatchThread>,
+}
+
+struct WatchThread {
+    request: RunRequest,
+    watched: WatchList,
+    stop: CancellationToken,
+    thread: thread::JoinHandle<()>,
 }
 
 impl EffectRunner {
-    pub(crate) fn new(layout: RuntimeLayout) -> Self {
+    /// `force_offline` keeps AoC out of reach whatever the `offline` config key says.
+    pub(crate) fn new(layout: RuntimeLayout, force_offline: bool) -> Self {
         let (effect_sender, effect_receiver) = mpsc::channel();
         let (action_sender, action_receiver) = mpsc::channel();
         let shutdown = Arc::new(AtomicBool::new(false));
         let worker_shutdown = Arc::clone(&shutdown);
+        let watch_sender = action_sender.clone();
         let worker = thread::spawn(move || {
             let executor = SystemCommandExecutor;
-            worker_loop(effect_receiver, action_sender, worker_shutdown, |effect| {
-                run_background_effect(&layout, effect, &executor)
-            });
+            worker_loop(
+                effect_receiver,
+                action_sender,
+                worker_shutdown,
+                |effect, actions| {
+                    run_background_effect(&layout, force_offline, effect, &executor, actions)
+                },
+            );
         });
         Self {
             sender: Some(effect_sender),
             receiver: action_receiver,
             worker: Some(worker),
             shutdown,
+            action_sender: watch_sender,
+            watcher: None,
+        }
+    }
+
+    /// Reports `Action::WatchChanged` for `request` whenever something in `watched` changes.
+    ///
+    /// A running watch of the same files is kept, so changes are not missed while it restarts.
+    pub(crate) fn watch(&mut self, request: RunRequest, watched: WatchList) {
+        if self.watcher.as_ref().is_some_and(|current| {
+            current.request == request
+                && current.watched == watched
+                && !current.thread.is_finished()
+        }) {
+            return;
+        }
+        self.stop_watch();
+        let stop = CancellationToken::new();
+        let actions = self.action_sender.clone();
+        let thread_stop = stop.clone();
+        let thread_watched = watched.clone();
+        let thread =
+            thread::spawn(move || watch_files(request, &thread_watched, &thread_stop, &actions));
+        self.watcher = Some(WatchThread {
+            request,
+            watched,
+            stop,
+            thread,
+        });
+    }
+
+    pub(crate) fn stop_watch(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            watcher.stop.cancel();
+            let _ = watcher.thread.join();
         }
     }
 
@@ -76,20 +134,52 @@ fn worker_loop(
     effect_receiver: mpsc::Receiver<BackgroundEffect>,
     action_sender: mpsc::Sender<Action>,
     shutdown: Arc<AtomicBool>,
-    mut run: impl FnMut(BackgroundEffect) -> Action,
+    mut run: impl FnMut(BackgroundEffect, &mpsc::Sender<Action>) -> Action,
 ) {
     while let Ok(effect) = effect_receiver.recv() {
         if shutdown.load(Ordering::Acquire) {
             break;
         }
-        if action_sender.send(run(effect)).is_err() {
+        let action = run(effect, &action_sender);
+        if action_sender.send(action).is_err() {
             break;
         }
     }
 }
 
+fn watch_files(
+    request: RunRequest,
+    watched: &WatchList,
+    stop: &CancellationToken,
+    actions: &mpsc::Sender<Action>,
+) {
+    let failed = |error: WatchError| Action::WatchFailed {
+        request,
+        message: format!("Stopped watching the solution: {error}"),
+    };
+    let watcher = match FileWatcher::new(watched) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            let _ = actions.send(failed(error));
+            return;
+        }
+    };
+    while !stop.is_cancelled() {
+        let action = match watcher.wait_timeout(WATCH_POLL_INTERVAL) {
+            Ok(None) => continue,
+            Ok(Some(_)) => Action::WatchChanged(request),
+            Err(error) => failed(error),
+        };
+        let stopped = matches!(action, Action::WatchFailed { .. });
+        if actions.send(action).is_err() || stopped {
+            return;
+        }
+    }
+}
+
 impl Drop for EffectRunner {
     fn drop(&mut self) {
+        self.stop_watch();
         self.shutdown.store(true, Ordering::Release);
         self.sender.take();
         if let Some(worker) = self.worker.take() {
@@ -100,12 +190,14 @@ impl Drop for EffectRunner {
 
 fn run_background_effect(
     layout: &RuntimeLayout,
+    force_offline: bool,
     effect: BackgroundEffect,
     executor: &dyn CommandExecutor,
+    actions: &mpsc::Sender<Action>,
 ) -> Action {
     match effect {
         BackgroundEffect::LoadCalendar { year, refresh } => {
-            let result = with_content_store(layout, |content| {
+            let result = with_content_store(layout, actions, force_offline, |content| {
                 let html = if refresh {
                     content.refresh_calendar(year)?
                 } else {
@@ -120,23 +212,75 @@ fn run_background_effect(
                 result,
             }
         }
+        BackgroundEffect::LoadStars(year) => {
+            let result = with_content_store(layout, actions, force_offline, |content| {
+                Ok(content.year_stars(year)?)
+            })
+            .map_err(|error| format!("Could not read stars for {year}: {error}"));
+            Action::StarsLoaded { year, result }
+        }
+        BackgroundEffect::LoadRunHistory { puzzle, language } => {
+            let result = load_run_history(layout, actions, force_offline, puzzle, language)
+                .map_err(|error| format!("Could not read run history for {puzzle}: {error}"));
+            Action::RunHistoryLoaded {
+                puzzle,
+                language,
+                result,
+            }
+        }
+        BackgroundEffect::LoadSolutionVariants { puzzle, language } => {
+            let result = with_language(layout, language, executor, |language| {
+                Ok((
+                    language.solution_variants(puzzle)?,
+                    language.active_solution(puzzle),
+                ))
+            })
+            .map_err(|error| format!("Could not list the solutions of {puzzle}: {error}"));
+            Action::SolutionVariantsLoaded {
+                puzzle,
+                language,
+                result,
+            }
+        }
+        BackgroundEffect::SelectSolution { language, solution } => {
+            let puzzle = solution.puzzle;
+            let result = with_language(layout, language, executor, |language| {
+                language.select_solution(solution)?;
+                Ok((
+                    language.solution_variants(puzzle)?,
+                    language.active_solution(puzzle),
+                ))
+            })
+            .map_err(|error| {
+                format!(
+                    "Could not select the {} solution of {puzzle}: {error}",
+                    solution.variant_name()
+                )
+            });
+            Action::SolutionVariantsLoaded {
+                puzzle,
+                language,
+                result,
+            }
+        }
         BackgroundEffect::LoadCachedDescription(puzzle) => {
-            let result = with_content_store(layout, |content| {
+            let result = with_content_store(layout, actions, force_offline, |content| {
                 Ok(content.load_cached_puzzle_markdown(puzzle)?)
             })
             .map_err(|error| format!("Could not read cached {puzzle}: {error}"));
             Action::CachedDescriptionFinished { puzzle, result }
         }
         BackgroundEffect::DownloadDescription(puzzle) => {
-            let result = with_content_store(layout, |content| {
+            let result = with_content_store(layout, actions, force_offline, |content| {
                 Ok(content.download_puzzle_markdown(puzzle)?)
             })
             .map_err(|error| format!("Could not download {puzzle}: {error}"));
             Action::DescriptionDownloaded { puzzle, result }
         }
         BackgroundEffect::PrepareExercise { puzzle, language } => {
-            let result = prepare_exercise(layout, puzzle, language, executor)
-                .map_err(|error| format!("Could not prepare {puzzle}: {error}"));
+            let result =
+                prepare_exercise(layout, actions, force_offline, puzzle, language, executor)
+                    .map_err(|error| format!("Could not prepare {puzzle}: {error}"));
             Action::ExercisePrepared {
                 puzzle,
                 language,
@@ -154,15 +298,47 @@ fn run_background_effect(
                 result,
             }
         }
-        BackgroundEffect::RunSolver(request) => {
-            let result = run_solver(layout, request, executor).map_err(run_failure);
+        BackgroundEffect::RunSolver {
+            request,
+            cancellation,
+        } => {
+            // Output lines reach the app ahead of the finished run because both share the
+            // action channel.
+            let output_actions = actions.clone();
+            let output = OutputSink::new(move |line| {
+                let _ = output_actions.send(Action::RunOutput { request, line });
+            });
+            let result = run_solver(
+                layout,
+                actions,
+                force_offline,
+                request,
+                cancellation,
+                output,
+                executor,
+            )
+            .map_err(run_failure);
             Action::RunFinished { request, result }
         }
+        BackgroundEffect::ResolveWatchedFiles(request) => {
+            let result = watched_files(layout, request, executor)
+                .map_err(|error| format!("Could not watch {}: {error}", request.puzzle));
+            Action::WatchedFilesResolved { request, result }
+        }
         BackgroundEffect::SubmitAnswer(request) => {
-            let result = submit_answer(layout, &request)
+            let result = submit_answer(layout, actions, force_offline, &request)
                 .map_err(|error| format!("Could not submit the answer: {error}"));
             Action::SubmissionFinished { request, result }
         }
+        BackgroundEffect::LoadSubmissionCooldown(puzzle) => {
+            let result = with_content_store(layout, actions, force_offline, |content| {
+                Ok(content.submission_cooldown(puzzle)?)
+            })
+            .map_err(|error| {
+                format!("Could not read the submission cooldown for {puzzle}: {error}")
+            });
+            Action::SubmissionCooldownLoaded { puzzle, result }
+        }
         BackgroundEffect::LoadLanguageData { language } => {
             let result = load_language_data(layout, language, executor)
                 .map_err(|error| format!("Could not load {language} language data: {error}"));
@@ -213,14 +389,39 @@ fn run_background_effect(
             });
             Action::ConfigSaved { result }
         }
+        BackgroundEffect::LoadLeaderboardIds { refresh } => {
+            let result = Configuration::load(layout.config_dir())
+                .and_then(|config| config.get::<LeaderboardIds>(ConfigKey::Leaderboards))
+                .map(|ids| ids.ids().to_vec())
+                .map_err(|error| format!("Could not read the configured leaderboards: {error}"));
+            Action::LeaderboardIdsLoaded { refresh, result }
+        }
+        BackgroundEffect::LoadLeaderboard { year, id, refresh } => {
+            let result = with_content_store(layout, actions, force_offline, |content| {
+                let leaderboard = match content.cached_private_leaderboard(year, id)? {
+                    Some(leaderboard) if !refresh => leaderboard,
+                    _ => content.private_leaderboard(year, id)?,
+                };
+                Ok((leaderboard, content.leaderboard_refresh_in(year, id)?))
+            })
+            .map_err(|error| format!("Could not load leaderboard {id} for {year}: {error}"));
+            Action::LeaderboardLoaded { year, id, result }
+        }
     }
 }
 
 fn submit_answer(
     layout: &RuntimeLayout,
+    actions: &mpsc::Sender<Action>,
+    force_offline: bool,
     request: &crate::app::SubmissionRequest,
 ) -> Result<aocsuite_parser::AocSubmissionResult, TuiError> {
-    submit_answer_with_options(layout, request, AocClientOptions::default())
+    let config = Configuration::load(layout.config_dir())?;
+    submit_answer_with_options(
+        layout,
+        request,
+        client_options(layout, &config, actions, force_offline)?,
+    )
 }
 
 fn submit_answer_with_options(
@@ -233,15 +434,25 @@ fn submit_answer_with_options(
     let session = config.session()?;
     let client = AocClient::new(Some(&session), options)?;
     let content = ContentStore::open(layout.cache_dir(), &client)?;
+    if let Some(remaining) = content.submission_cooldown(request.puzzle)? {
+        return Err(TuiError::SubmissionCooldown {
+            remaining: format_cooldown(remaining),
+        });
+    }
+    content.check_submission(request.puzzle, request.part, request.answer())?;
     let result =
         parse_submission(&client.submit(request.puzzle, request.part, request.answer())?)?;
-    content.record_submission(request.puzzle, request.part, &result)?;
+    content.record_submission(request.puzzle, request.part, request.answer(), &result)?;
     Ok(result)
 }
 
 fn run_solver(
     layout: &RuntimeLayout,
+    actions: &mpsc::Sender<Action>,
+    force_offline: bool,
     request: RunRequest,
+    cancellation: CancellationToken,
+    output: OutputSink,
     executor: &dyn CommandExecutor,
 ) -> Result<RunReport, RunSolverError> {
     valid_puzzle_release(request.puzzle.day, request.puzzle.year).map_err(TuiError::from)?;
@@ -249,27 +460,76 @@ fn run_solver(
     let retention = config
         .get::<RunHistoryLimit>(ConfigKey::RunHistoryLimit)
         .map_err(TuiError::from)?;
+    let timeout = config
+        .get::<RunTimeout>(ConfigKey::RunTimeout)
+        .map_err(TuiError::from)?;
     let session = load_optional_session(&config).map_err(TuiError::from)?;
-    let client =
-        AocClient::new(session.as_deref(), AocClientOptions::default()).map_err(TuiError::from)?;
+    let options =
+        client_options(layout, &config, actions, force_offline).map_err(TuiError::from)?;
+    let client = AocClient::new(session.as_deref(), options).map_err(TuiError::from)?;
     let content = ContentStore::open(layout.cache_dir(), &client).map_err(TuiError::from)?;
     let workspace = Workspace::new(layout.workspace_dir());
-    let input = match request.input {
-        RunInput::Aoc => content
-            .ensure_input(request.puzzle)
-            .map_err(TuiError::from)?,
-        RunInput::Example => workspace.ensure_example(request.puzzle).map_err(|source| {
-            RunSolverError::SharedExample {
+    let (input, expected, accepted) = match request.input {
+        RunInput::Aoc => (
+            content
+                .ensure_input(request.puzzle)
+                .map_err(TuiError::from)?,
+            None,
+            Some({
+                content
+                    .backfill_accepted_answers(request.puzzle)
+                    .map_err(TuiError::from)?;
+                content
+                    .accepted_answers(request.puzzle)
+                    .map_err(TuiError::from)?
+            }),
+        ),
+        RunInput::Example => {
+            let shared_example = |source| RunSolverError::SharedExample {
                 puzzle: request.puzzle,
                 source,
+            };
+            let input = workspace
+                .ensure_example(request.puzzle, || {
+                    content
+                        .puzzle_examples(request.puzzle)
+                        .map_err(TuiError::from)
+                })
+                .map_err(|error| match error {
+                    TuiError::Workspace(source) => shared_example(source),
+                    error => error.into(),
+                })?;
+            if let Some(answers) = content
+                .cached_example_answers(request.puzzle)
+                .map_err(TuiError::from)?
+            {
+                workspace
+                    .save_example_answers(request.puzzle, &answers)
+                    .map_err(shared_example)?;
             }
-        })?,
+            let expected = workspace
+                .example_answers(request.puzzle)
+                .map_err(shared_example)?;
+            (input, Some(expected), None)
+        }
     };
-    let language = Language::new(request.language, &workspace, executor);
+    let settings = LanguageSettings::from_config(&config).map_err(TuiError::from)?;
+    let language =
+        Language::new(request.language, &workspace, &settings, executor).map_err(TuiError::from)?;
     let output = language
-        .execute(request.puzzle, PartSelection::from(request.part), &input)
+        .execute(
+            request.puzzle,
+            PartSelection::from(request.part),
+            &input,
+            &RunOptions {
+                bench: None,
+                timeout: Some(timeout.get()),
+                cancellation: Some(cancellation),
+                output: Some(output),
+            },
+        )
         .map_err(TuiError::from)?;
-    let mut report = report_from_output(request, &output);
+    let mut report = report_from_output(request, &output, expected.as_ref(), accepted.as_ref());
     record_run_timings(&mut report, |part, runtime_ms| {
         content
             .record_run_timing(
@@ -284,6 +544,45 @@ fn run_solver(
     Ok(report)
 }
 
+fn with_language<T>(
+    layout: &RuntimeLayout,
+    language: LanguageId,
+    executor: &dyn CommandExecutor,
+    operation: impl FnOnce(&Language<'_, '_>) -> Result<T, TuiError>,
+) -> Result<T, TuiError> {
+    let workspace = Workspace::new(layout.workspace_dir());
+    let language = Language::new(language, &workspace, &language_settings(layout)?, executor)?;
+    operation(&language)
+}
+
+fn language_settings(layout: &RuntimeLayout) -> Result<LanguageSettings, TuiError> {
+    Ok(LanguageSettings::from_config(&Configuration::load(
+        layout.config_dir(),
+    )?)?)
+}
+
+fn watched_files(
+    layout: &RuntimeLayout,
+    request: RunRequest,
+    executor: &dyn CommandExecutor,
+) -> Result<WatchList, TuiError> {
+    let workspace = Workspace::new(layout.workspace_dir());
+    let mut watched = Language::new(
+        request.language,
+        &workspace,
+        &language_settings(layout)?,
+        executor,
+    )?
+    .watch_list(request.puzzle)?;
+    if request.input == RunInput::Example {
+        watched.files.push(workspace.example_path(request.puzzle));
+        watched
+            .files
+            .extend(workspace.example_answer_paths(request.puzzle));
+    }
+    Ok(watched)
+}
+
 fn record_run_timings(
     report: &mut RunReport,
     mut record: impl FnMut(PuzzlePart, u128) -> Result<(), String>,
@@ -328,6 +627,20 @@ fn run_failure(error: RunSolverError) -> RunFailure {
             summary: "Could not launch the solver command".to_owned(),
             details: Some(source.to_string()),
         },
+        RunSolverError::Tui(TuiError::Language(error @ AocLanguageError::TimedOut(_))) => {
+            RunFailure {
+                summary: format!("Solver {error}"),
+                details: Some(
+                    "The solver and the processes it started were stopped. Raise the solver \
+                     timeout in the Config tab if the solution needs longer."
+                        .to_owned(),
+                ),
+            }
+        }
+        RunSolverError::Tui(TuiError::Language(AocLanguageError::Cancelled)) => RunFailure {
+            summary: "Solver run cancelled".to_owned(),
+            details: None,
+        },
         error => RunFailure {
             summary: "Solver run could not be completed".to_owned(),
             details: Some(error.to_string()),
@@ -350,7 +663,12 @@ enum RunSolverError {
     },
 }
 
-fn report_from_output(request: RunRequest, output: &LanguageRunOutput) -> RunReport {
+fn report_from_output(
+    request: RunRequest,
+    output: &LanguageRunOutput,
+    expected: Option<&PuzzleAnswers>,
+    accepted: Option<&PuzzleAnswers>,
+) -> RunReport {
     let parts = output
         .run
         .result
@@ -359,6 +677,8 @@ fn report_from_output(request: RunRequest, output: &LanguageRunOutput) -> RunRep
             part: request.part,
             answer: result.answer().to_owned(),
             runtime_ms: result.runtime_ms(),
+            expected: expected.cloned().unwrap_or_default(),
+            accepted: accepted.cloned().unwrap_or_default(),
         })
         .into_iter()
         .collect();
@@ -372,17 +692,77 @@ fn report_from_output(request: RunRequest, output: &LanguageRunOutput) -> RunRep
     }
 }
 
+fn load_run_history(
+    layout: &RuntimeLayout,
+    actions: &mpsc::Sender<Action>,
+    force_offline: bool,
+    puzzle: PuzzleId,
+    language: LanguageId,
+) -> Result<Vec<(PuzzlePart, RunHistory)>, TuiError> {
+    let limit = Configuration::load(layout.config_dir())?
+        .get::<RunHistoryLimit>(ConfigKey::RunHistoryLimit)?;
+    with_content_store(layout, actions, force_offline, |content| {
+        [PuzzlePart::One, PuzzlePart::Two]
+            .into_iter()
+            .map(|part| Ok((part, content.run_history(puzzle, language, part, limit)?)))
+            .collect()
+    })
+}
+
 fn with_content_store<T>(
     layout: &RuntimeLayout,
+    actions: &mpsc::Sender<Action>,
+    force_offline: bool,
     operation: impl FnOnce(&ContentStore<'_>) -> Result<T, TuiError>,
 ) -> Result<T, TuiError> {
     let config = Configuration::load(layout.config_dir())?;
     let session = load_optional_session(&config)?;
-    let client = AocClient::new(session.as_deref(), AocClientOptions::default())?;
+    let client = AocClient::new(
+        session.as_deref(),
+        client_options(layout, &config, actions, force_offline)?,
+    )?;
     let content = ContentStore::open(layout.cache_dir(), &client)?;
     operation(&content)
 }
 
+/// Whether AoC must not be contacted: forced by `--offline` or set by the `offline` config key.
+pub(crate) fn offline_mode(
+    config: &Configuration,
+    force_offline: bool,
+) -> Result<bool, AocConfigError> {
+    Ok(force_offline || config.get::<bool>(ConfigKey::Offline)?)
+}
+
+/// Client options whose requests are throttled together with other AoC Suite processes. A
+/// request waiting for its slot reports `Action::Throttled` so the wait shows in the footer
+/// instead of silently blocking the worker.
+///
+/// The offline mode used by the client is reported as `Action::OfflineMode`, so the footer badge
+/// and submit gating follow the `offline` key as the effects read it.
+fn client_options(
+    layout: &RuntimeLayout,
+    config: &Configuration,
+    actions: &mpsc::Sender<Action>,
+    force_offline: bool,
+) -> Result<AocClientOptions, AocConfigError> {
+    let offline = offline_mode(config, force_offline)?;
+    let _ = actions.send(Action::OfflineMode(offline));
+    let actions = actions.clone();
+    Ok(AocClientOptions {
+        offline,
+        throttle: Some(RequestThrottle {
+            on_wait: Arc::new(move |class, wait| {
+                let _ = actions.send(Action::Throttled(format!(
+                    "Throttled: waiting {}s before the next AoC {class} request",
+                    wait.as_secs_f64().ceil()
+                )));
+            }),
+            ..RequestThrottle::new(layout.throttle_dir())
+        }),
+        ..AocClientOptions::default()
+    })
+}
+
 fn load_optional_session(config: &Configuration) -> Result<Option<String>, AocConfigError> {
     match config.session() {
         Ok(session) => Ok(Some(session)),
@@ -439,6 +819,8 @@ pub(crate) fn run_foreground_effect(
 
 fn prepare_exercise(
     layout: &RuntimeLayout,
+    actions: &mpsc::Sender<Action>,
+    force_offline: bool,
     puzzle: PuzzleId,
     language_id: LanguageId,
     executor: &dyn CommandExecutor,
@@ -446,16 +828,27 @@ fn prepare_exercise(
     valid_puzzle_release(puzzle.day, puzzle.year)?;
     let config = Configuration::load(layout.config_dir())?;
     let session = load_optional_session(&config)?;
-    let client = AocClient::new(session.as_deref(), AocClientOptions::default())?;
+    let client = AocClient::new(
+        session.as_deref(),
+        client_options(layout, &config, actions, force_offline)?,
+    )?;
     let content = ContentStore::open(layout.cache_dir(), &client)?;
     let workspace = Workspace::new(layout.workspace_dir());
-    let language = Language::new(language_id, &workspace, executor);
+    let settings = LanguageSettings::from_config(&config)?;
+    let language = Language::new(language_id, &workspace, &settings, executor)?;
     let editor = config.get::<String>(ConfigKey::Editor)?;
+    let puzzle_description = content.ensure_puzzle_markdown(puzzle)?;
+    let mut variables = TemplateVariables::new(puzzle);
+    if let Some(title) = content.cached_puzzle_title(puzzle)? {
+        variables = variables.with_title(title);
+    }
     Ok(PreparedExercise {
         editor,
-        puzzle_description: content.ensure_puzzle_markdown(puzzle)?,
-        example: workspace.ensure_example(puzzle)?,
-        solution: language.ensure_solver_file(&SolverFile::ActiveSolution(puzzle))?,
+        puzzle_description,
+        example: workspace.ensure_example(puzzle, || {
+            content.puzzle_examples(puzzle).map_err(TuiError::from)
+        })?,
+        solution: language.prepare_solution(language.active_solution(puzzle), None, &variables)?,
         input: content.ensure_input(puzzle)?,
         working_directory: language.project_dir().to_path_buf(),
     })
@@ -467,7 +860,8 @@ fn load_language_data(
     executor: &dyn CommandExecutor,
 ) -> Result<LanguageData, TuiError> {
     let workspace = Workspace::new(layout.workspace_dir());
-    let language = Language::new(language_id, &workspace, executor);
+    let settings = language_settings(layout)?;
+    let language = Language::new(language_id, &workspace, &settings, executor)?;
     let mut packages = language.list_packages()?;
     let mut libraries = language.list_lib_files()?;
     packages.sort_unstable_by_key(|package| package.to_ascii_lowercase());
@@ -485,7 +879,8 @@ fn mutate_language(
     executor: &dyn CommandExecutor,
 ) -> Result<LanguageData, TuiError> {
     let workspace = Workspace::new(layout.workspace_dir());
-    let language = Language::new(language_id, &workspace, executor);
+    let settings = language_settings(layout)?;
+    let language = Language::new(language_id, &workspace, &settings, executor)?;
     match mutation {
         LanguageMutation::AddPackage(package) => language.add_package(package)?,
         LanguageMutation::RemovePackage(package) => language.remove_package(package)?,
@@ -514,14 +909,16 @@ fn prepare_language_file(
     let config = Configuration::load(layout.config_dir())?;
     let editor = config.get::<String>(ConfigKey::Editor)?;
     let workspace = Workspace::new(layout.workspace_dir());
-    let language = Language::new(language_id, &workspace, executor);
-    let path = match &kind {
-        LanguageFileKind::Library(name) => language.ensure_lib_path(name)?,
-        LanguageFileKind::Template if reset => {
-            language.reset_template(ConfirmedTemplateReset::Confirmed)?
-        }
-        LanguageFileKind::Template => language.ensure_solver_file(&SolverFile::SolutionTemplate)?,
-    };
+    let settings = LanguageSettings::from_config(&config)?;
+    let language = Language::new(language_id, &workspace, &settings, executor)?;
+    let path =
+        match &kind {
+            LanguageFileKind::Library(name) => language.ensure_lib_path(name)?,
+            LanguageFileKind::Template if reset => language
+                .reset_template(&TemplateName::default(), ConfirmedTemplateReset::Confirmed)?,
+            LanguageFileKind::Template => language
+                .ensure_solver_file(&SolverFile::SolutionTemplate(TemplateName::default()))?,
+        };
     Ok(PreparedLanguageFile {
         kind,
         editor,
@@ -550,10 +947,12 @@ fn load_config_data(
     let run_history_limit = config
         .get::<aocsuite_utils::RunHistoryLimit>(ConfigKey::RunHistoryLimit)?
         .to_string();
+    let run_timeout = config.get::<RunTimeout>(ConfigKey::RunTimeout)?.to_string();
     Ok(ConfigData {
         year: year.to_string(),
         editor,
         run_history_limit,
+        run_timeout,
         session_configured: config.session_configured()?,
     })
 }
@@ -571,6 +970,7 @@ fn mutate_config(
                 NonSecretConfigField::Year => ConfigKey::Year,
                 NonSecretConfigField::Editor => ConfigKey::Editor,
                 NonSecretConfigField::RunHistoryLimit => ConfigKey::RunHistoryLimit,
+                NonSecretConfigField::RunTimeout => ConfigKey::RunTimeout,
             };
             config
                 .set(key, value.as_deref())
@@ -621,6 +1021,7 @@ fn config_operation(mutation: &ConfigMutation) -> &'static str {
             NonSecretConfigField::Year => "save the default year",
             NonSecretConfigField::Editor => "save the editor executable",
             NonSecretConfigField::RunHistoryLimit => "save run-history retention",
+            NonSecretConfigField::RunTimeout => "save the solver timeout",
         },
         ConfigMutation::SetSession(_) => "save the session",
         ConfigMutation::RemoveSession => "remove the session",
@@ -638,11 +1039,55 @@ mod tests {
 
     use aocsuite_storage::RuntimeLayout;
     use aocsuite_utils::{
-        CommandExecutor, CommandRequest, LanguageId, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear,
+        CommandExecutor, CommandRequest, LanguageId, OutputLine, OutputStream, PuzzleDay, PuzzleId,
+        PuzzlePart, PuzzleYear,
+    };
+
+    use aocsuite_client::{AocClient, AocClientOptions};
+    use aocsuite_config::{ConfigKey, Configuration};
+    use aocsuite_parser::AocSubmissionResult;
+    use aocsuite_storage::{ContentStore, PuzzleAnswers};
+
+    use super::{run_background_effect, submit_answer_with_options};
+    use crate::{
+        app::{Action, BackgroundEffect, RunInput, RunRequest, SubmissionRequest},
+        TuiError,
     };
 
-    use super::run_background_effect;
-    use crate::app::{Action, BackgroundEffect, RunInput, RunRequest};
+    #[test]
+    fn submissions_are_refused_during_a_cooldown_the_ui_has_not_loaded_yet() {
+        let root = test_root("submit-cooldown");
+        let layout = RuntimeLayout::new(root.join("runtime")).unwrap();
+        layout.bootstrap().unwrap();
+        Configuration::load(layout.config_dir())
+            .unwrap()
+            .set(ConfigKey::Session, Some("test-session"))
+            .unwrap();
+        let puzzle = PuzzleId::new(PuzzleDay::new(1).unwrap(), PuzzleYear::new(2024).unwrap());
+        let client = AocClient::new(Some("test-session"), AocClientOptions::default()).unwrap();
+        ContentStore::open(layout.cache_dir(), &client)
+            .unwrap()
+            .record_submission(
+                puzzle,
+                PuzzlePart::One,
+                "41",
+                &AocSubmissionResult::Incorrect,
+            )
+            .unwrap();
+
+        // Point the client at an unreachable host so any request past the check would fail
+        // with a client error rather than the cooldown.
+        let options = AocClientOptions {
+            base_url: "http://127.0.0.1:9".to_owned(),
+            ..AocClientOptions::default()
+        };
+        let request = SubmissionRequest::new(puzzle, PuzzlePart::One, "42".to_owned());
+        match submit_answer_with_options(&layout, &request, options) {
+            Err(TuiError::SubmissionCooldown { .. }) => {}
+            other => panic!("expected the cooldown to refuse the submission, got {other:?}"),
+        }
+        fs::remove_dir_all(root).unwrap();
+    }
 
     #[test]
     fn shared_example_solver_effect_maps_parts_and_executes_solver_once() {
@@ -661,6 +1106,12 @@ mod tests {
                         r#"{"part1":{"answer":"alpha","runtime_ms":3},"part2":null}"#,
                     )?;
                     self.output_paths.lock().unwrap().push(output_path);
+                    if let Some(sink) = &request.output_sink {
+                        sink.send(OutputLine {
+                            stream: OutputStream::Stderr,
+                            text: "debug row".to_owned(),
+                        });
+                    }
                 }
                 Ok(Output {
                     status: successful_status(),
@@ -683,13 +1134,31 @@ mod tests {
             part: PuzzlePart::One,
             input: RunInput::Example,
         };
+        let examples_dir = layout.workspace_dir().join("examples");
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(examples_dir.join(format!("{}.txt", request.puzzle)), "1\n").unwrap();
+        fs::write(
+            examples_dir.join(format!("{}.answers.override", request.puzzle)),
+            "part1: alpha\n",
+        )
+        .unwrap();
         let executor = SolverExecutor {
             solver_executions: AtomicUsize::new(0),
             output_paths: Mutex::new(Vec::new()),
         };
 
-        let action =
-            run_background_effect(&layout, BackgroundEffect::RunSolver(request), &executor);
+        let (actions, streamed) = std::sync::mpsc::channel();
+
+        let action = run_background_effect(
+            &layout,
+            true,
+            BackgroundEffect::RunSolver {
+                request,
+                cancellation: aocsuite_utils::CancellationToken::new(),
+            },
+            &executor,
+            &actions,
+        );
 
         let Action::RunFinished {
             request: returned_request,
@@ -705,9 +1174,21 @@ mod tests {
         assert_eq!(report.parts[0].part, PuzzlePart::One);
         assert_eq!(report.parts[0].answer, "alpha");
         assert_eq!(report.parts[0].runtime_ms, 3);
+        assert_eq!(
+            report.parts[0].expected.part(PuzzlePart::One),
+            Some("alpha")
+        );
+        assert_eq!(report.parts[0].accepted, PuzzleAnswers::default());
         assert!(report.warning.is_none());
         assert_eq!(executor.solver_executions.load(Ordering::Relaxed), 1);
         assert_eq!(executor.output_paths.lock().unwrap().len(), 1);
+        assert!(matches!(
+            streamed.try_iter().collect::<Vec<_>>().as_slice(),
+            [Action::OfflineMode(true), Action::RunOutput { request: streamed_request, line }]
+                if *streamed_request == request
+                    && line.stream == OutputStream::Stderr
+                    && line.text == "debug row"
+        ));
         fs::remove_dir_all(root).unwrap();
     }
 
@@ -20,7 +20,7 @@ use app::{
     Action, App, ConfigDialog, Effect, LanguageDialog, SecretCharacter, SubmissionDialog, Tab,
 };
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use effects::{run_foreground_effect, EffectRunner};
+use effects::{offline_mode, run_foreground_effect, EffectRunner};
 use terminal::TerminalSession;
 use thiserror::Error;
 
@@ -46,13 +46,17 @@ pub enum TuiError {
     Workspace(#[from] WorkspaceError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error("submission cooldown active: wait {remaining} before submitting")]
+    SubmissionCooldown { remaining: String },
     #[error("the background effect runner stopped unexpectedly")]
     EffectRunnerStopped,
 }
 
 pub type TuiResult<T> = Result<T, TuiError>;
 
-pub fn run() -> TuiResult<()> {
+/// Runs the TUI until it quits. `force_offline` never contacts AoC, whatever the `offline` config
+/// key says.
+pub fn run(force_offline: bool) -> TuiResult<()> {
     let root = get_aocsuite_dir()?;
     let layout = RuntimeLayout::new(root)?;
     layout.bootstrap()?;
@@ -71,11 +75,14 @@ pub fn run() -> TuiResult<()> {
         PuzzleId::new(latest_day, latest_year),
         language,
     );
-    let effects = EffectRunner::new(layout.clone());
+    app.offline = offline_mode(&config, force_offline)?;
+    let mut effects = EffectRunner::new(layout.clone(), force_offline);
     for effect in app.initial_effects() {
         match effect {
             Effect::Background(effect) => effects.submit(effect).map_err(|(_, error)| error)?,
-            Effect::Foreground(_) => unreachable!("initial effects are background work"),
+            Effect::Foreground(_) | Effect::Watch { .. } | Effect::StopWatch => {
+                unreachable!("initial effects are background work")
+            }
         }
     }
 
@@ -84,7 +91,7 @@ pub fn run() -> TuiResult<()> {
     while !app.should_quit {
         while let Some(action) = effects.try_receive() {
             let requested = app.update(action);
-            dispatch_effects(&mut app, &effects, &mut terminal, &executor, requested)?;
+            dispatch_effects(&mut app, &mut effects, &mut terminal, &executor, requested)?;
         }
 
         terminal
@@ -96,7 +103,13 @@ pub fn run() -> TuiResult<()> {
                 if key.kind == KeyEventKind::Press {
                     if let Some(action) = action_for_key(&app, key) {
                         let requested = app.update(action);
-                        dispatch_effects(&mut app, &effects, &mut terminal, &executor, requested)?;
+                        dispatch_effects(
+                            &mut app,
+                            &mut effects,
+                            &mut terminal,
+                            &executor,
+                            requested,
+                        )?;
                     }
                 }
             }
@@ -108,7 +121,7 @@ pub fn run() -> TuiResult<()> {
 
 fn dispatch_effects(
     app: &mut App,
-    runner: &EffectRunner,
+    runner: &mut EffectRunner,
     terminal: &mut TerminalSession,
     executor: &SystemCommandExecutor,
     effects: Vec<Effect>,
@@ -131,6 +144,8 @@ fn dispatch_effects(
                 let follow_up = app.update(Action::ForegroundFinished(result));
                 dispatch_effects(app, runner, terminal, executor, follow_up)?;
             }
+            Effect::Watch { request, watched } => runner.watch(request, watched),
+            Effect::StopWatch => runner.stop_watch(),
         }
     }
     Ok(())
@@ -139,6 +154,7 @@ fn dispatch_effects(
 fn action_for_key(app: &App, key: KeyEvent) -> Option<Action> {
     if app.active_run.is_some() {
         return match key.code {
+            KeyCode::Esc => Some(Action::CancelRun),
             KeyCode::Char('q') => Some(Action::Quit),
             KeyCode::Char('g') => Some(Action::OpenLazygit),
             _ => None,
@@ -295,6 +311,21 @@ fn action_for_key(app: &App, key: KeyEvent) -> Option<Action> {
         };
     }
 
+    if app.active_tab == Tab::Leaderboard {
+        return match key.code {
+            KeyCode::Char('r') => Some(Action::RefreshLeaderboard),
+            KeyCode::Up => Some(Action::PreviousLeaderboard),
+            KeyCode::Down => Some(Action::NextLeaderboard),
+            KeyCode::Enter => Some(Action::ToggleLeaderboardDay),
+            KeyCode::Esc if app.leaderboard_day.is_some() => Some(Action::ToggleLeaderboardDay),
+            KeyCode::Left => Some(Action::PreviousLeaderboardDay),
+            KeyCode::Right => Some(Action::NextLeaderboardDay),
+            KeyCode::PageUp => Some(Action::ScrollLeaderboardUp),
+            KeyCode::PageDown => Some(Action::ScrollLeaderboardDown),
+            _ => None,
+        };
+    }
+
     if app.active_tab == Tab::Config {
         return match key.code {
             KeyCode::Char('r') => Some(Action::RefreshConfig),
@@ -320,6 +351,8 @@ fn action_for_key(app: &App, key: KeyEvent) -> Option<Action> {
         (KeyCode::Char('1'), _) => Some(Action::RunPart(aocsuite_utils::PuzzlePart::One)),
         (KeyCode::Char('2'), _) => Some(Action::RunPart(aocsuite_utils::PuzzlePart::Two)),
         (KeyCode::Char('i'), _) => Some(Action::ToggleRunInput),
+        (KeyCode::Char('v'), _) => Some(Action::NextSolutionVariant),
+        (KeyCode::Char('w'), _) => Some(Action::ToggleWatch),
         (KeyCode::Char('u'), _) => Some(Action::RefreshCalendar),
         (KeyCode::Char('b'), _) => Some(Action::OpenBrowser),
         (KeyCode::Enter, _) => Some(Action::OpenExercise),
@@ -372,4 +405,20 @@ mod tests {
             Some(Action::DialogCancel)
         ));
     }
+
+    #[test]
+    fn escape_stops_a_running_solver() {
+        let mut app = app();
+        app.active_run = Some(crate::app::RunRequest {
+            puzzle: app.latest_puzzle,
+            language: LanguageId::Rust,
+            part: aocsuite_utils::PuzzlePart::One,
+            input: crate::app::RunInput::Aoc,
+        });
+
+        assert!(matches!(
+            action_for_key(&app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            Some(Action::CancelRun)
+        ));
+    }
 }
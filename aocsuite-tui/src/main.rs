@@ -1,5 +1,16 @@
+use clap::Parser;
+
+/// Terminal UI for Advent of Code
+#[derive(Parser, Debug)]
+struct AocTui {
+    /// Never contact AoC and work from cached content only (default: the `offline` config key)
+    #[arg(long)]
+    offline: bool,
+}
+
 fn main() {
-    if let Err(error) = aocsuite_tui::run() {
+    let args = AocTui::parse();
+    if let Err(error) = aocsuite_tui::run(args.offline) {
         eprintln!("encountered error: {error}");
         std::process::exit(1);
     }
@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use aocsuite_parser::{AocSubmissionResult, Calendar, CalendarStars};
-use aocsuite_utils::PuzzleId;
+use aocsuite_utils::{format_cooldown, OutputStream, PuzzleId};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
@@ -13,9 +13,9 @@ use ratatui::{
 };
 
 use crate::app::{
-    friendly_puzzle, App, ConfigDialog, ConfigField, ConfigOperationState, DescriptionState,
-    LanguageConfirmation, LanguageDialog, LanguageFocus, LanguageOperationState, LanguageTextInput,
-    RunDialog, RunInput, RunRequest, SubmissionDialog, Tab,
+    friendly_puzzle, leaderboard_day_rows, App, ConfigDialog, ConfigField, ConfigOperationState,
+    DescriptionState, LanguageConfirmation, LanguageDialog, LanguageFocus, LanguageOperationState,
+    LanguageTextInput, LeaderboardView, RunDialog, RunInput, RunRequest, SubmissionDialog, Tab,
 };
 
 pub(crate) fn render(frame: &mut Frame<'_>, app: &App) {
@@ -26,15 +26,16 @@ pub(crate) fn render(frame: &mut Frame<'_>, app: &App) {
     match app.active_tab {
         Tab::Calendar => render_calendar_tab(frame, root[0], app),
         Tab::Language => render_language_tab(frame, root[0], app),
+        Tab::Leaderboard => render_leaderboard_tab(frame, root[0], app),
         Tab::Config => render_config_tab(frame, root[0], app),
     }
     render_footer(frame, root[1], app);
     if app.active_submission.is_some() {
         render_submitting(frame, app.run_spinner_frame);
     } else if let Some(dialog) = &app.submission_dialog {
-        render_submission_dialog(frame, dialog);
+        render_submission_dialog(frame, dialog, app);
     } else if let Some(request) = app.active_run {
-        render_running(frame, request, app.run_spinner_frame);
+        render_running(frame, request, app);
     } else if let Some(dialog) = &app.run_dialog {
         render_run_dialog(frame, dialog);
     } else if let Some(dialog) = &app.config_dialog {
@@ -85,7 +86,7 @@ fn submission_prose(result: &AocSubmissionResult) -> String {
     }
 }
 
-fn render_submission_dialog(frame: &mut Frame<'_>, dialog: &SubmissionDialog) {
+fn render_submission_dialog(frame: &mut Frame<'_>, dialog: &SubmissionDialog, app: &App) {
     let area = centered_dialog(frame.area());
     if area.width < 4 || area.height < 4 {
         return;
@@ -95,7 +96,7 @@ fn render_submission_dialog(frame: &mut Frame<'_>, dialog: &SubmissionDialog) {
         SubmissionDialog::Part { puzzle, part } => frame.render_widget(
             Paragraph::new(vec![
                 Line::from(format!("{} Day {}", puzzle.year, puzzle.day)),
-                Line::default(),
+                cooldown_line(app, *puzzle),
                 choice_line("Part 1", "Part 2", *part == aocsuite_utils::PuzzlePart::Two),
             ])
             .block(
@@ -118,6 +119,7 @@ fn render_submission_dialog(frame: &mut Frame<'_>, dialog: &SubmissionDialog) {
                     error.as_deref().unwrap_or_default(),
                     Style::default().fg(Color::Red),
                 ),
+                cooldown_line(app, *puzzle),
             ])
             .block(
                 Block::default()
@@ -134,6 +136,7 @@ fn render_submission_dialog(frame: &mut Frame<'_>, dialog: &SubmissionDialog) {
                 )),
                 Line::from(format!("Answer: {}", request.answer())),
                 choice_line("Cancel", "Submit", *submit),
+                cooldown_line(app, request.puzzle),
             ])
             .block(
                 Block::default()
@@ -166,6 +169,16 @@ fn render_submission_dialog(frame: &mut Frame<'_>, dialog: &SubmissionDialog) {
     }
 }
 
+fn cooldown_line(app: &App, puzzle: PuzzleId) -> Line<'static> {
+    match app.submission_cooldown_remaining(puzzle) {
+        Some(remaining) => Line::styled(
+            format!("Cooldown: {} remaining", format_cooldown(remaining)),
+            Style::default().fg(Color::Yellow),
+        ),
+        None => Line::default(),
+    }
+}
+
 fn choice_line<'a>(left: &'a str, right: &'a str, right_selected: bool) -> Line<'a> {
     let selected = Style::default()
         .fg(Color::Yellow)
@@ -241,10 +254,11 @@ fn run_title(label: &str, request: RunRequest, width: u16) -> String {
     truncated
 }
 
-fn render_running(frame: &mut Frame<'_>, request: RunRequest, spinner_frame: usize) {
+fn render_running(frame: &mut Frame<'_>, request: RunRequest, app: &App) {
     let full = frame.area();
     let width = full.width.saturating_sub(4).min(84);
-    let height = full.height.saturating_sub(2).min(7);
+    let maximum_height = if app.run_log.is_empty() { 7 } else { 22 };
+    let height = full.height.saturating_sub(2).min(maximum_height);
     let area = Rect::new(
         full.x + full.width.saturating_sub(width) / 2,
         full.y + full.height.saturating_sub(height) / 2,
@@ -254,17 +268,41 @@ fn render_running(frame: &mut Frame<'_>, request: RunRequest, spinner_frame: usi
     if area.width < 4 || area.height < 4 {
         return;
     }
-    let spinner = ["|", "/", "-", "\\"][spinner_frame % 4];
+    let spinner = ["|", "/", "-", "\\"][app.run_spinner_frame % 4];
+    let elapsed = app
+        .run_elapsed()
+        .map(|elapsed| format!(" {:.1}s", elapsed.as_secs_f64()))
+        .unwrap_or_default();
     let title = run_title("Running", request, area.width);
-    frame.render_widget(Clear, area);
-    frame.render_widget(
-        Paragraph::new(Line::styled(
+    // The spinner and hint lines stay visible; the log shows its most recent lines.
+    let log_rows = usize::from(area.height.saturating_sub(4));
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
             spinner,
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
-        ))
-        .block(Block::default().borders(Borders::ALL).title(title)),
+        ),
+        Span::raw(elapsed),
+    ])];
+    lines.extend(
+        app.run_log
+            .iter()
+            .skip(app.run_log.len().saturating_sub(log_rows))
+            .map(|line| match line.stream {
+                OutputStream::Stdout => Line::raw(line.text.as_str()),
+                OutputStream::Stderr => {
+                    Line::styled(line.text.as_str(), Style::default().fg(Color::LightRed))
+                }
+            }),
+    );
+    lines.push(Line::styled(
+        "Esc stops the solver",
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)),
         area,
     );
 }
@@ -305,6 +343,18 @@ fn render_run_dialog(frame: &mut Frame<'_>, dialog: &RunDialog) {
                     "Answer\n{}\n\nRuntime\n{} ms\n",
                     part.answer, part.runtime_ms
                 ));
+                for (label, answers, [pass, fail]) in [
+                    ("Expected", &part.expected, ["PASS", "FAIL"]),
+                    ("Accepted", &part.accepted, ["MATCH", "MISMATCH"]),
+                ] {
+                    if let (Some(known), Some(matches)) = (
+                        answers.part(part.part),
+                        answers.matches(part.part, &part.answer),
+                    ) {
+                        let verdict = if matches { pass } else { fail };
+                        text.push_str(&format!("\n{label}\n{known} ({verdict})\n"));
+                    }
+                }
             }
             for (label, output) in [
                 ("Compile stdout", &report.compile_stdout),
@@ -346,15 +396,87 @@ fn render_calendar_tab(frame: &mut Frame<'_>, area: Rect, app: &App) {
         .direction(direction)
         .constraints(constraints)
         .split(area);
-    render_calendar(frame, panes[0], app);
+    let calendar_panes = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Fill(1), Constraint::Length(6)])
+        .split(panes[0]);
+    render_calendar(frame, calendar_panes[0], app);
+    render_run_history(frame, calendar_panes[1], app);
     render_description(frame, panes[1], app);
 }
 
+fn render_run_history(frame: &mut Frame<'_>, area: Rect, app: &App) {
+    let lines = match app.selected_run_history() {
+        Some(history) => history
+            .parts
+            .iter()
+            .flat_map(|(part, history)| {
+                let summary = match (history.min(), history.median(), history.max()) {
+                    (Some(min), Some(median), Some(max)) => format!(
+                        "{} runs  min {} ms  median {} ms  max {} ms",
+                        history.runs.len(),
+                        min.as_millis(),
+                        median.as_millis(),
+                        max.as_millis()
+                    ),
+                    _ => "no recorded runs".to_owned(),
+                };
+                let recent = history
+                    .runs
+                    .iter()
+                    .map(|run| run.duration.as_millis().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                [
+                    Line::from(vec![
+                        Span::styled(format!("Part {part}  "), Style::default().fg(Color::Yellow)),
+                        Span::raw(summary),
+                    ]),
+                    Line::styled(
+                        if recent.is_empty() {
+                            String::new()
+                        } else {
+                            format!("  recent: {recent} ms")
+                        },
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    let mut title = format!(" Run history ({}) ", app.language);
+    if let Some(variants) = app
+        .selected_solution_variants()
+        .filter(|variants| variants.solutions.len() > 1)
+    {
+        let position = variants
+            .solutions
+            .iter()
+            .position(|solution| *solution == variants.active)
+            .map_or(0, |index| index + 1);
+        title = format!(
+            " Run history ({}, {} solution {position}/{}) ",
+            app.language,
+            variants.active.variant_name(),
+            variants.solutions.len()
+        );
+    }
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)),
+        area,
+    );
+}
+
 fn render_calendar(frame: &mut Frame<'_>, area: Rect, app: &App) {
     let title = format!(" {} ", app.selected_year);
     let body = match &app.calendar {
         Some(calendar) => {
-            let (stars, total, completed) = completion(calendar);
+            let (mut stars, total, mut completed) = completion(calendar);
+            if let Some(year_stars) = &app.year_stars {
+                stars = year_stars.total() as usize;
+                completed = year_stars.completed_days();
+            }
             let selected_puzzle = app.selected_puzzle();
             let selected_row = selected_puzzle.and_then(|puzzle| {
                 calendar
@@ -475,27 +597,38 @@ fn render_language_tab(frame: &mut Frame<'_>, area: Rect, app: &App) {
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(3), Constraint::Min(4)])
         .split(area);
-    let rust_style = if app.language == aocsuite_utils::LanguageId::Rust {
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD)
-    } else {
-        Style::default()
-    };
-    let python_style = if app.language == aocsuite_utils::LanguageId::Python {
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD)
-    } else {
-        Style::default()
-    };
+    let selected = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut languages = Vec::new();
+    for language in aocsuite_utils::LanguageId::BUILT_IN {
+        if !languages.is_empty() {
+            languages.push(Span::raw(" | "));
+        }
+        let label = match language {
+            aocsuite_utils::LanguageId::Rust => "Rust",
+            aocsuite_utils::LanguageId::Python => "Python",
+            aocsuite_utils::LanguageId::Go => "Go",
+            aocsuite_utils::LanguageId::Cpp => "C++",
+            aocsuite_utils::LanguageId::TypeScript => "TypeScript",
+            aocsuite_utils::LanguageId::Custom(_) => {
+                unreachable!("custom languages are not built in")
+            }
+        };
+        let style = if app.language == *language {
+            selected
+        } else {
+            Style::default()
+        };
+        languages.push(Span::styled(label, style));
+    }
+    if let aocsuite_utils::LanguageId::Custom(custom) = app.language {
+        languages.push(Span::raw(" | "));
+        languages.push(Span::styled(custom.to_string(), selected));
+    }
     frame.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled("Rust", rust_style),
-            Span::raw(" | "),
-            Span::styled("Python", python_style),
-        ]))
-        .block(Block::default().borders(Borders::ALL).title(" Language ")),
+        Paragraph::new(Line::from(languages))
+            .block(Block::default().borders(Borders::ALL).title(" Language ")),
         sections[0],
     );
 
@@ -654,6 +787,159 @@ fn render_language_dialog(frame: &mut Frame<'_>, dialog: &LanguageDialog) {
     }
 }
 
+fn render_leaderboard_tab(frame: &mut Frame<'_>, area: Rect, app: &App) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(16), Constraint::Min(20)])
+        .split(area);
+    let ids: Vec<String> = app
+        .leaderboard_ids
+        .iter()
+        .flatten()
+        .map(u32::to_string)
+        .collect();
+    render_language_list(
+        frame,
+        panes[0],
+        "Boards",
+        &ids,
+        app.leaderboard_selection,
+        true,
+        None,
+    );
+
+    let view = app.leaderboard.as_ref();
+    let mut title = match (view, app.leaderboard_day) {
+        (Some(view), Some(day)) => format!(" {} Day {day} - leaderboard {} ", view.year, view.id),
+        (Some(view), None) => format!(" {} leaderboard {} ", view.year, view.id),
+        (None, _) => " Leaderboard ".to_owned(),
+    };
+    if app.leaderboard_loading {
+        title.push_str("- loading... ");
+    } else if let Some(remaining) = view.and_then(LeaderboardView::refresh_in) {
+        title.push_str(&format!("- refresh in {} ", format_cooldown(remaining)));
+    }
+    let muted = Style::default().fg(Color::DarkGray);
+    let lines = match (&app.leaderboard_ids, view) {
+        (Some(ids), _) if ids.is_empty() => vec![
+            Line::from("No private leaderboards configured."),
+            Line::styled(
+                "Add their ids with `aocsuite-cli config set leaderboards`.",
+                muted,
+            ),
+        ],
+        (_, None) => Vec::new(),
+        (_, Some(view)) => match app.leaderboard_day {
+            Some(day) => leaderboard_day_lines(view, PuzzleId::new(day, view.year)),
+            None => leaderboard_ranking_lines(view, app.latest_puzzle),
+        },
+    };
+    frame.render_widget(
+        Paragraph::new(lines)
+            .scroll((app.leaderboard_scroll, 0))
+            .block(Block::default().borders(Borders::ALL).title(title)),
+        panes[1],
+    );
+}
+
+fn leaderboard_ranking_lines(
+    view: &LeaderboardView,
+    latest_puzzle: PuzzleId,
+) -> Vec<Line<'static>> {
+    let days = view.days(latest_puzzle);
+    let header = |digit: fn(u32) -> char| {
+        days.iter()
+            .map(|day| digit(u32::from(*day)))
+            .collect::<String>()
+    };
+    let mut lines = vec![
+        Line::styled(
+            format!(
+                "{:<11}{}",
+                "",
+                header(|day| char::from_digit(day / 10, 10)
+                    .filter(|tens| *tens != '0')
+                    .unwrap_or(' '))
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::styled(
+            format!(
+                "{:<11}{}",
+                "",
+                header(|day| char::from_digit(day % 10, 10).expect("single digit"))
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    for (rank, member) in view.leaderboard.ranking() {
+        let rank = rank.map(|rank| format!("{rank})")).unwrap_or_default();
+        let mut spans = vec![Span::raw(format!("{rank:>4} {:>5} ", member.local_score))];
+        spans.extend(days.iter().map(|day| {
+            let completion = member.day(*day);
+            let color = match completion.stars() {
+                2 => Color::Yellow,
+                1 => Color::LightBlue,
+                _ => Color::DarkGray,
+            };
+            Span::styled(completion.glyph().to_string(), Style::default().fg(color))
+        }));
+        spans.push(Span::raw(format!(" {}", member.display_name())));
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+fn leaderboard_day_lines(view: &LeaderboardView, puzzle: PuzzleId) -> Vec<Line<'static>> {
+    let rows = leaderboard_day_rows(&view.leaderboard, puzzle);
+    if rows.is_empty() {
+        return vec![Line::styled(
+            "Nobody has a star on this day yet.",
+            Style::default().fg(Color::DarkGray),
+        )];
+    }
+    let name_width = rows
+        .iter()
+        .map(|row| row.name.chars().count())
+        .max()
+        .unwrap_or_default()
+        .clamp(6, 24);
+    let time =
+        |duration: Option<Duration>| duration.map_or_else(|| "-".to_owned(), format_star_time);
+    let mut lines = vec![Line::styled(
+        format!(
+            "{:<name_width$}  {:>8}  {:>8}  {:>8}",
+            "Member", "Part 1", "Part 2", "Gap"
+        ),
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    lines.extend(rows.iter().map(|row| {
+        let name: String = row.name.chars().take(name_width).collect();
+        Line::from(format!(
+            "{name:<name_width$}  {:>8}  {:>8}  {:>8}",
+            time(row.part1),
+            time(row.part2),
+            time(row.gap()),
+        ))
+    }));
+    lines
+}
+
+/// Formats a star time like AoC's personal stats: `hh:mm:ss`, or `>24h` after a day.
+fn format_star_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 24 * 60 * 60 {
+        ">24h".to_owned()
+    } else {
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
 fn render_config_tab(frame: &mut Frame<'_>, area: Rect, app: &App) {
     let title = match app.config_operation {
         ConfigOperationState::Idle => " Config ".to_owned(),
@@ -675,6 +961,7 @@ fn render_config_tab(frame: &mut Frame<'_>, area: Rect, app: &App) {
                         .clone()
                         .unwrap_or_else(|| "Not configured".to_owned()),
                     ConfigField::RunHistoryLimit => config.run_history_limit.clone(),
+                    ConfigField::RunTimeout => format!("{}s", config.run_timeout),
                     ConfigField::Session if config.session_configured => "Configured".to_owned(),
                     ConfigField::Session => "Not configured".to_owned(),
                 });
@@ -806,6 +1093,27 @@ fn render_footer(frame: &mut Frame<'_>, area: Rect, app: &App) {
         ])
         .split(area);
     let mut left = vec![Span::raw("? help")];
+    if app.offline {
+        left.extend([
+            Span::raw("  "),
+            Span::styled(
+                "OFFLINE",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]);
+    }
+    if let Some(watched) = app.watching {
+        left.extend([
+            Span::raw("  "),
+            Span::styled(
+                format!("watching {}", run_context(watched, false)),
+                Style::default().fg(Color::Green),
+            ),
+        ]);
+    }
     if let Some(status) = &app.status {
         left.extend([Span::raw("  "), Span::raw(status)]);
     }
@@ -858,7 +1166,14 @@ fn render_help_dialog(frame: &mut Frame<'_>, app: &App) {
             key_line("d", "Download or refresh puzzle description", area.width),
             key_line("s", "Submit an answer", area.width),
             key_line("1 / 2", "Run puzzle part one / two", area.width),
+            key_line("Esc", "Stop the running solver", area.width),
             key_line("i", "Toggle AoC / shared-example input", area.width),
+            key_line("v", "Switch to the next solution variant", area.width),
+            key_line(
+                "w",
+                "Re-run the part whenever its files are saved",
+                area.width,
+            ),
             key_line("u", "Refresh calendar", area.width),
             key_line("b", "Open puzzle in browser", area.width),
             key_line("Enter", "Open exercise in editor", area.width),
@@ -878,6 +1193,17 @@ fn render_help_dialog(frame: &mut Frame<'_>, app: &App) {
             key_line("Enter", "Open selected library", area.width),
             key_line("t / T", "Open / reset template", area.width),
         ]),
+        Tab::Leaderboard => lines.extend([
+            key_line("Up / Down", "Select private leaderboard", area.width),
+            key_line("Enter / Esc", "Show / leave a day's star times", area.width),
+            key_line("Left / Right", "Previous / next day", area.width),
+            key_line("PageUp / PageDown", "Scroll members", area.width),
+            key_line(
+                "r",
+                "Reload ids and refresh, at most every 15 minutes",
+                area.width,
+            ),
+        ]),
         Tab::Config => lines.extend([
             key_line("Up / Down", "Select configuration field", area.width),
             key_line("Enter", "Edit selected field", area.width),
@@ -952,7 +1278,10 @@ fn completion(calendar: &Calendar) -> (usize, usize, usize) {
 #[cfg(test)]
 mod tests {
     use aocsuite_parser::{Calendar, CalendarCell, CalendarRow, Rgb};
-    use aocsuite_utils::{LanguageId, PuzzleDay, PuzzleId, PuzzleYear};
+    use aocsuite_storage::YearStars;
+    use aocsuite_utils::{
+        LanguageId, OutputLine, OutputStream, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear,
+    };
     use ratatui::{
         backend::TestBackend,
         style::{Color, Modifier},
@@ -998,6 +1327,7 @@ mod tests {
                 year: "2026".to_owned(),
                 editor: Some("vim".to_owned()),
                 run_history_limit: "10".to_owned(),
+                run_timeout: "30".to_owned(),
                 session_configured: false,
             }),
         });
@@ -1053,6 +1383,24 @@ mod tests {
         assert!(!rendered.contains("Puzzle"));
     }
 
+    #[test]
+    fn stored_stars_replace_the_calendar_derived_completion() {
+        let backend = TestBackend::new(120, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = app();
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        assert!(buffer_text(terminal.backend().buffer()).contains("0/2 stars  0 completed days"));
+
+        app.update(Action::StarsLoaded {
+            year: PuzzleYear::new(2026).unwrap(),
+            result: Ok(YearStars {
+                days: vec![(PuzzleDay::new(10).unwrap(), 2)],
+            }),
+        });
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        assert!(buffer_text(terminal.backend().buffer()).contains("2/2 stars  1 completed days"));
+    }
+
     #[test]
     fn footer_keeps_help_and_status_left_with_tabs_right() {
         let backend = TestBackend::new(80, 20);
@@ -1069,7 +1417,7 @@ mod tests {
         assert!(!rendered.contains("AoC Suite"));
         assert!(first_line.contains("2026"));
         assert!(footer.starts_with("? help  Preparing workspace Git..."));
-        assert!(footer.ends_with("Calendar | Language | Config"));
+        assert!(footer.ends_with("Calendar | Language | Leaderboard | Config"));
 
         let calendar_x = footer.find("Calendar").unwrap() as u16;
         let calendar_cell = &buffer[(
@@ -1080,6 +1428,23 @@ mod tests {
         assert!(calendar_cell.modifier.contains(Modifier::BOLD));
     }
 
+    #[test]
+    fn offline_badge_leads_the_footer_status() {
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = app();
+        app.offline = true;
+        app.status = Some("Loading".to_owned());
+
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let footer = buffer_text(buffer).lines().last().unwrap().to_owned();
+        assert!(footer.starts_with("? help  OFFLINE  Loading"));
+        let badge = &buffer[(8, buffer.area.height - 1)];
+        assert_eq!((badge.fg, badge.bg), (Color::Black, Color::Yellow));
+    }
+
     #[test]
     fn narrow_footer_keeps_the_active_tab_visible() {
         let backend = TestBackend::new(24, 12);
@@ -1095,6 +1460,75 @@ mod tests {
         assert!(footer.ends_with("Language"));
     }
 
+    #[test]
+    fn running_dialog_shows_elapsed_time_and_the_latest_output() {
+        let backend = TestBackend::new(80, 14);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = app();
+        app.update(Action::RunPart(PuzzlePart::One));
+        let request = app.active_run.expect("run started");
+        for row in 0..12 {
+            app.update(Action::RunOutput {
+                request,
+                line: OutputLine {
+                    stream: OutputStream::Stdout,
+                    text: format!("row {row}"),
+                },
+            });
+        }
+
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        let rendered = buffer_text(terminal.backend().buffer());
+        assert!(rendered.contains("| 0."));
+        assert!(rendered.contains("row 11"));
+        assert!(!rendered.contains("row 3 "));
+        assert!(rendered.contains("Esc stops the solver"));
+    }
+
+    #[test]
+    fn leaderboard_day_view_lists_star_times_and_their_gap() {
+        let backend = TestBackend::new(80, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = app();
+        let year = PuzzleYear::new(2026).unwrap();
+        let unlock = aocsuite_utils::puzzle_unlock_timestamp(PuzzleId::new(
+            PuzzleDay::new(10).unwrap(),
+            year,
+        ));
+        let json = format!(
+            r#"{{"owner_id":1,"members":{{"1":{{"id":1,"name":"owner","stars":2,
+                "local_score":2,"last_star_ts":{},"completion_day_level":{{"10":{{
+                "1":{{"get_star_ts":{}}},"2":{{"get_star_ts":{}}}}}}}}}}}}}"#,
+            unlock + 100_000,
+            unlock + 754,
+            unlock + 100_000,
+        );
+        app.update(Action::NextTab);
+        app.update(Action::NextTab);
+        app.update(Action::LeaderboardIdsLoaded {
+            refresh: false,
+            result: Ok(vec![42]),
+        });
+        app.update(Action::LeaderboardLoaded {
+            year,
+            id: 42,
+            result: Ok((
+                aocsuite_parser::parse_private_leaderboard(&json).unwrap(),
+                None,
+            )),
+        });
+        app.update(Action::ToggleLeaderboardDay);
+
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        let rendered = buffer_text(terminal.backend().buffer());
+        assert!(rendered.contains("2026 Day 10 - leaderboard 42"));
+        assert!(rendered.contains("> 42"));
+        assert!(rendered.contains("Member    Part 1    Part 2       Gap"));
+        assert!(rendered.contains("owner   00:12:34      >24h      >24h"));
+    }
+
     fn buffer_text(buffer: &ratatui::buffer::Buffer) -> String {
         let area = buffer.area;
         (area.y..area.y + area.height)
@@ -1,4 +1,4 @@
-use std::{fmt, str::FromStr};
+use std::{fmt, str::FromStr, time::Duration};
 
 use thiserror::Error;
 
@@ -8,6 +8,8 @@ pub enum DomainError {
     PuzzleDay(String),
     #[error("puzzle year must be 2015 or later, got '{0}'")]
     PuzzleYear(String),
+    #[error("invalid puzzle id '{0}'")]
+    PuzzleId(String),
     #[error("invalid puzzle part '{0}'")]
     PuzzlePart(String),
     #[error("invalid part selection '{0}'")]
@@ -16,6 +18,32 @@ pub enum DomainError {
     Language(String),
     #[error("run history limit must be greater than zero, got '{0}'")]
     RunHistoryLimit(String),
+    #[error("benchmark iterations must be greater than zero, got '{0}'")]
+    BenchIterations(String),
+    #[error("run timeout must be a positive number of seconds, got '{0}'")]
+    RunTimeout(String),
+    #[error("invalid C++ flags '{0}', check for unbalanced quotes")]
+    CppFlags(String),
+    #[error("TypeScript runtime must be node, bun or deno, got '{0}'")]
+    TypeScriptRuntime(String),
+    #[error("Python backend must be auto, pip or uv, got '{0}'")]
+    PythonBackend(String),
+    #[error("Rust profile must be release or debug, got '{0}'")]
+    RustProfile(String),
+    #[error(
+        "invalid solution variant '{0}', expected up to 24 lowercase letters, digits, '-' or '_'"
+    )]
+    SolutionVariant(String),
+    #[error("invalid solution id '{0}'")]
+    SolutionId(String),
+    #[error(
+        "invalid template name '{0}', expected up to 24 lowercase letters, digits, '-' or '_'"
+    )]
+    TemplateName(String),
+    #[error("invalid Rust release option '{0}', expected lto=<off|thin|fat|true|false>, codegen-units=<n> or target-cpu=native")]
+    RustReleaseOption(String),
+    #[error("private leaderboard id must be a positive number, got '{0}'")]
+    LeaderboardId(String),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -137,6 +165,219 @@ impl fmt::Display for PuzzleId {
     }
 }
 
+impl FromStr for PuzzleId {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || DomainError::PuzzleId(value.to_owned());
+        let (year, day) = value
+            .strip_prefix("year")
+            .and_then(|rest| rest.split_once("_day"))
+            .ok_or_else(invalid)?;
+        if !year.bytes().all(|byte| byte.is_ascii_digit())
+            || !day.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        Ok(Self::new(
+            day.parse().map_err(|_| invalid())?,
+            year.parse().map_err(|_| invalid())?,
+        ))
+    }
+}
+
+/// ASCII name of at most [`InlineName::MAX_LEN`] bytes, stored inline so that the ids holding
+/// it stay `Copy`. Zero padding keeps the derived order alphabetical.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct InlineName {
+    bytes: [u8; InlineName::MAX_LEN],
+    len: u8,
+}
+
+impl InlineName {
+    const MAX_LEN: usize = 24;
+
+    /// Callers validate `name` first; it must be ASCII and fit.
+    fn new(name: &str) -> Self {
+        assert!(name.is_ascii() && name.len() <= Self::MAX_LEN);
+        let mut bytes = [0; Self::MAX_LEN];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Self {
+            bytes,
+            len: name.len() as u8,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)]).expect("inline names are ASCII")
+    }
+}
+
+/// `as_str`, `Debug` and `Display` of a newtype around [`InlineName`].
+macro_rules! impl_inline_name {
+    ($($type:ident),+ $(,)?) => {
+        $(
+            impl $type {
+                pub const MAX_LEN: usize = InlineName::MAX_LEN;
+
+                pub fn as_str(&self) -> &str {
+                    self.0.as_str()
+                }
+            }
+
+            impl fmt::Debug for $type {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter
+                        .debug_tuple(stringify!($type))
+                        .field(&self.as_str())
+                        .finish()
+                }
+            }
+
+            impl fmt::Display for $type {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str(self.as_str())
+                }
+            }
+        )+
+    };
+}
+
+impl_inline_name!(SolutionVariant, CustomLanguage);
+
+/// Name of an alternative solution of a puzzle, e.g. `fast` for `year2024_day5.fast.rs`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SolutionVariant(InlineName);
+
+impl SolutionVariant {
+    /// Selects the puzzle's main solution wherever a variant name is accepted.
+    pub const DEFAULT_NAME: &str = "default";
+
+    pub fn new(name: &str) -> Result<Self, DomainError> {
+        if !is_file_name_part(name, Self::MAX_LEN) || name == Self::DEFAULT_NAME {
+            return Err(DomainError::SolutionVariant(name.to_owned()));
+        }
+        Ok(Self(InlineName::new(name)))
+    }
+}
+
+impl FromStr for SolutionVariant {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(value)
+    }
+}
+
+/// Whether `name` can be embedded in a file name: up to `max_len` lowercase letters, digits, `-`
+/// or `_`, starting with a letter or digit.
+fn is_file_name_part(name: &str, max_len: usize) -> bool {
+    let bytes = name.as_bytes();
+    bytes
+        .first()
+        .is_some_and(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit())
+        && bytes.len() <= max_len
+        && bytes.iter().all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'-' | b'_')
+        })
+}
+
+/// Name of a solution template of a language, e.g. `grid`. The `default` template is the one
+/// new solutions start from unless another is chosen.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TemplateName(String);
+
+impl TemplateName {
+    pub const MAX_LEN: usize = 24;
+    pub const DEFAULT_NAME: &str = "default";
+
+    pub fn new(name: &str) -> Result<Self, DomainError> {
+        if !is_file_name_part(name, Self::MAX_LEN) {
+            return Err(DomainError::TemplateName(name.to_owned()));
+        }
+        Ok(Self(name.to_owned()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.0 == Self::DEFAULT_NAME
+    }
+}
+
+impl Default for TemplateName {
+    fn default() -> Self {
+        Self(Self::DEFAULT_NAME.to_owned())
+    }
+}
+
+impl fmt::Display for TemplateName {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl FromStr for TemplateName {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(value)
+    }
+}
+
+/// One solution file of a puzzle: its main solution or a named variant. Displayed as the file
+/// stem, `year2024_day5` or `year2024_day5.fast`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SolutionId {
+    pub puzzle: PuzzleId,
+    pub variant: Option<SolutionVariant>,
+}
+
+impl SolutionId {
+    pub const fn new(puzzle: PuzzleId, variant: Option<SolutionVariant>) -> Self {
+        Self { puzzle, variant }
+    }
+
+    /// The variant name, or `default` for the main solution.
+    pub fn variant_name(&self) -> &str {
+        self.variant
+            .as_ref()
+            .map_or(SolutionVariant::DEFAULT_NAME, SolutionVariant::as_str)
+    }
+}
+
+impl From<PuzzleId> for SolutionId {
+    fn from(puzzle: PuzzleId) -> Self {
+        Self::new(puzzle, None)
+    }
+}
+
+impl fmt::Display for SolutionId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.variant {
+            Some(variant) => write!(formatter, "{}.{variant}", self.puzzle),
+            None => self.puzzle.fmt(formatter),
+        }
+    }
+}
+
+impl FromStr for SolutionId {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = |_| DomainError::SolutionId(value.to_owned());
+        match value.split_once('.') {
+            Some((puzzle, variant)) => Ok(Self::new(
+                puzzle.parse().map_err(invalid)?,
+                Some(variant.parse().map_err(invalid)?),
+            )),
+            None => Ok(Self::new(value.parse().map_err(invalid)?, None)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PuzzlePart {
     One,
@@ -207,6 +448,39 @@ impl FromStr for PartSelection {
 pub enum LanguageId {
     Rust,
     Python,
+    Go,
+    Cpp,
+    TypeScript,
+    /// A language described by a user-provided manifest.
+    Custom(CustomLanguage),
+}
+
+/// Name of a manifest-driven language: a lowercase ASCII identifier of at most
+/// [`CustomLanguage::MAX_LEN`] bytes that may also contain `-` and `_`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomLanguage(InlineName);
+
+impl CustomLanguage {
+    pub fn new(name: &str) -> Result<Self, DomainError> {
+        let bytes = name.as_bytes();
+        let valid = bytes.first().is_some_and(u8::is_ascii_lowercase)
+            && bytes.len() <= Self::MAX_LEN
+            && bytes.iter().all(|byte| {
+                byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'-' | b'_')
+            })
+            && !LanguageId::BUILT_IN
+                .iter()
+                .any(|language| language.to_string() == name);
+        if !valid {
+            return Err(DomainError::Language(name.to_owned()));
+        }
+        Ok(Self(InlineName::new(name)))
+    }
+
+    /// File name of the language's manifest, e.g. `zig.toml`.
+    pub fn manifest_file_name(&self) -> String {
+        format!("{self}.toml")
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -242,11 +516,345 @@ impl FromStr for RunHistoryLimit {
     }
 }
 
+/// Number of timed repetitions per part in benchmark mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BenchIterations(u32);
+
+impl BenchIterations {
+    pub fn new(value: u32) -> Result<Self, DomainError> {
+        if value == 0 {
+            return Err(DomainError::BenchIterations(value.to_string()));
+        }
+        Ok(Self(value))
+    }
+
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for BenchIterations {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(formatter)
+    }
+}
+
+impl FromStr for BenchIterations {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .parse::<u32>()
+            .map_err(|_| DomainError::BenchIterations(value.to_owned()))
+            .and_then(Self::new)
+    }
+}
+
+/// Longest time a solver run may take before it is killed, in whole seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RunTimeout(Duration);
+
+impl RunTimeout {
+    pub fn from_secs(seconds: u64) -> Result<Self, DomainError> {
+        if seconds == 0 {
+            return Err(DomainError::RunTimeout(seconds.to_string()));
+        }
+        Ok(Self(Duration::from_secs(seconds)))
+    }
+
+    pub const fn get(self) -> Duration {
+        self.0
+    }
+}
+
+impl fmt::Display for RunTimeout {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.as_secs().fmt(formatter)
+    }
+}
+
+impl FromStr for RunTimeout {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .strip_suffix('s')
+            .unwrap_or(value)
+            .parse::<u64>()
+            .map_err(|_| DomainError::RunTimeout(value.to_owned()))
+            .and_then(Self::from_secs)
+    }
+}
+
+/// Compiler used for C++ solutions unless one is configured.
+pub const DEFAULT_CPP_COMPILER: &str = "g++";
+
+/// Flags passed to the C++ compiler, written like a shell command line so that quoted flags such
+/// as `'-DTITLE="a b"'` stay a single argument.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppFlags(Vec<String>);
+
+impl CppFlags {
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl Default for CppFlags {
+    fn default() -> Self {
+        Self(vec!["-O2".to_owned(), "-std=c++20".to_owned()])
+    }
+}
+
+impl fmt::Display for CppFlags {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only quote flags that would not survive splitting, so `-std=c++20` stays readable.
+        let mut flags = Vec::with_capacity(self.0.len());
+        for flag in &self.0 {
+            if flag.is_empty() || flag.contains(|c: char| c.is_whitespace() || "'\"\\#".contains(c))
+            {
+                flags.push(shlex::try_quote(flag).map_err(|_| fmt::Error)?);
+            } else {
+                flags.push(flag.into());
+            }
+        }
+        formatter.write_str(&flags.join(" "))
+    }
+}
+
+impl FromStr for CppFlags {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        shlex::split(value)
+            .filter(|flags| !flags.iter().any(|flag| flag.contains('\0')))
+            .map(Self)
+            .ok_or_else(|| DomainError::CppFlags(value.to_owned()))
+    }
+}
+
+/// JavaScript runtime that executes TypeScript solutions; Node runs them through `tsx`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TypeScriptRuntime {
+    #[default]
+    Node,
+    Bun,
+    Deno,
+}
+
+impl fmt::Display for TypeScriptRuntime {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Node => "node",
+            Self::Bun => "bun",
+            Self::Deno => "deno",
+        })
+    }
+}
+
+impl FromStr for TypeScriptRuntime {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "node" => Ok(Self::Node),
+            "bun" => Ok(Self::Bun),
+            "deno" => Ok(Self::Deno),
+            _ => Err(DomainError::TypeScriptRuntime(value.to_owned())),
+        }
+    }
+}
+
+/// Tool that creates the Python virtual environment and installs packages into it. `Auto` uses
+/// uv when it is on `PATH` and pip otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PythonBackend {
+    #[default]
+    Auto,
+    Pip,
+    Uv,
+}
+
+impl fmt::Display for PythonBackend {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Auto => "auto",
+            Self::Pip => "pip",
+            Self::Uv => "uv",
+        })
+    }
+}
+
+impl FromStr for PythonBackend {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "pip" => Ok(Self::Pip),
+            "uv" => Ok(Self::Uv),
+            _ => Err(DomainError::PythonBackend(value.to_owned())),
+        }
+    }
+}
+
+/// Cargo profile that Rust solutions are built and run with. Debug builds keep overflow checks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RustProfile {
+    #[default]
+    Release,
+    Debug,
+}
+
+impl fmt::Display for RustProfile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Release => "release",
+            Self::Debug => "debug",
+        })
+    }
+}
+
+impl FromStr for RustProfile {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "release" => Ok(Self::Release),
+            "debug" | "dev" => Ok(Self::Debug),
+            _ => Err(DomainError::RustProfile(value.to_owned())),
+        }
+    }
+}
+
+/// `[profile.release]` overrides for the Rust solution crate, written as space-separated
+/// `lto=fat codegen-units=1 target-cpu=native`. Options left out keep the crate's own values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RustReleaseOptions {
+    lto: Option<String>,
+    codegen_units: Option<u32>,
+    target_cpu_native: bool,
+}
+
+impl RustReleaseOptions {
+    /// Cargo's `lto` value: `off`, `thin`, `fat`, `true` or `false`.
+    pub fn lto(&self) -> Option<&str> {
+        self.lto.as_deref()
+    }
+
+    pub fn codegen_units(&self) -> Option<u32> {
+        self.codegen_units
+    }
+
+    pub fn target_cpu_native(&self) -> bool {
+        self.target_cpu_native
+    }
+}
+
+impl fmt::Display for RustReleaseOptions {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = Vec::new();
+        if let Some(lto) = &self.lto {
+            options.push(format!("lto={lto}"));
+        }
+        if let Some(codegen_units) = self.codegen_units {
+            options.push(format!("codegen-units={codegen_units}"));
+        }
+        if self.target_cpu_native {
+            options.push("target-cpu=native".to_owned());
+        }
+        formatter.write_str(&options.join(" "))
+    }
+}
+
+impl FromStr for RustReleaseOptions {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut options = Self::default();
+        for option in value.split_whitespace() {
+            let invalid = || DomainError::RustReleaseOption(option.to_owned());
+            match option.split_once('=').ok_or_else(invalid)? {
+                ("lto", lto @ ("off" | "thin" | "fat" | "true" | "false")) => {
+                    options.lto = Some(lto.to_owned());
+                }
+                ("codegen-units", units) => {
+                    options.codegen_units = Some(
+                        units
+                            .parse()
+                            .ok()
+                            .filter(|units| *units > 0)
+                            .ok_or_else(invalid)?,
+                    );
+                }
+                ("target-cpu", "native") => options.target_cpu_native = true,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Private leaderboard ids, written space-separated as `123456 987654`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LeaderboardIds(Vec<u32>);
+
+impl LeaderboardIds {
+    pub fn ids(&self) -> &[u32] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for LeaderboardIds {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids: Vec<String> = self.0.iter().map(u32::to_string).collect();
+        formatter.write_str(&ids.join(" "))
+    }
+}
+
+impl FromStr for LeaderboardIds {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut ids = Vec::new();
+        for id in value.split_whitespace() {
+            let id = id
+                .parse::<u32>()
+                .ok()
+                .filter(|id| *id > 0)
+                .ok_or_else(|| DomainError::LeaderboardId(id.to_owned()))?;
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        Ok(Self(ids))
+    }
+}
+
+impl LanguageId {
+    /// Languages with a runner shipped with aocsuite, in display order.
+    pub const BUILT_IN: &[LanguageId] = &[
+        Self::Rust,
+        Self::Python,
+        Self::Go,
+        Self::Cpp,
+        Self::TypeScript,
+    ];
+}
+
 impl fmt::Display for LanguageId {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.write_str(match self {
             Self::Rust => "rust",
             Self::Python => "python",
+            Self::Go => "go",
+            Self::Cpp => "cpp",
+            Self::TypeScript => "typescript",
+            Self::Custom(language) => language.as_str(),
         })
     }
 }
@@ -258,7 +866,12 @@ impl FromStr for LanguageId {
         match value.to_ascii_lowercase().as_str() {
             "rust" => Ok(Self::Rust),
             "python" => Ok(Self::Python),
-            _ => Err(DomainError::Language(value.to_owned())),
+            "go" => Ok(Self::Go),
+            "cpp" | "c++" => Ok(Self::Cpp),
+            "typescript" | "ts" => Ok(Self::TypeScript),
+            name => CustomLanguage::new(name)
+                .map(Self::Custom)
+                .map_err(|_| DomainError::Language(value.to_owned())),
         }
     }
 }
@@ -3,6 +3,7 @@ use std::{
     io::Write,
     path::Path,
     sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
 };
 
 use chrono::{DateTime, Datelike, TimeZone, Utc};
@@ -11,15 +12,23 @@ use thiserror::Error;
 
 pub mod domain;
 pub mod process;
+pub mod watch;
 
 pub use domain::{
-    DomainError, LanguageId, PartSelection, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear,
-    RunHistoryLimit,
+    BenchIterations, CppFlags, CustomLanguage, DomainError, LanguageId, LeaderboardIds,
+    PartSelection, PuzzleDay, PuzzleId, PuzzlePart, PuzzleYear, PythonBackend, RunHistoryLimit,
+    RunTimeout, RustProfile, RustReleaseOptions, SolutionId, SolutionVariant, TemplateName,
+    TypeScriptRuntime, DEFAULT_CPP_COMPILER,
 };
 pub use process::{
-    execute_command, CommandError, CommandExecutor, CommandRequest, ProcessMode,
+    execute_command, stop_supervised_commands_on_interrupt, CancellationToken, CommandError,
+    CommandExecutor, CommandRequest, OutputLine, OutputSink, OutputStream, ProcessMode,
     SystemCommandExecutor,
 };
+pub use watch::{FileWatcher, WatchError, WatchList, WatchResult, WatchedDir};
+
+/// The Advent of Code website every page URL is relative to.
+pub const AOC_BASE_URL: &str = "https://adventofcode.com";
 
 type AocReleaseResult<T> = Result<T, ReleaseError>;
 
@@ -130,6 +139,26 @@ fn valid_puzzle_release_at(
     }
 }
 
+/// Unix timestamp of the midnight US/Eastern at which the puzzle unlocks.
+pub fn puzzle_unlock_timestamp(puzzle: PuzzleId) -> i64 {
+    Eastern
+        .with_ymd_and_hms(puzzle.year.get(), 12, u32::from(puzzle.day.get()), 0, 0, 0)
+        .single()
+        .expect("midnight in December is unambiguous")
+        .timestamp()
+}
+
+/// Formats a submission cooldown as `42s` or `5m 05s`, rounding partial seconds up so the
+/// remaining time never reads as zero while the wait is still running.
+pub fn format_cooldown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs_f64().ceil() as u64;
+    if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{seconds}s")
+    }
+}
+
 fn valid_puzzle_day(day: PuzzleDay, year: PuzzleYear) -> bool {
     year.get() != 2025 || day.get() <= 12
 }
@@ -188,11 +217,14 @@ pub fn default_puzzle_date_at(now_utc: DateTime<Utc>) -> (PuzzleDay, PuzzleYear)
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use chrono::{TimeZone, Utc};
 
     use super::{
-        default_puzzle_date_at, valid_puzzle_release_at, valid_year_release_at, PuzzleDay,
-        PuzzleYear, ReleaseError,
+        default_puzzle_date_at, format_cooldown, puzzle_unlock_timestamp, valid_puzzle_release_at,
+        valid_year_release_at, CppFlags, LanguageId, LeaderboardIds, PuzzleDay, PuzzleId,
+        PuzzleYear, ReleaseError, RustReleaseOptions, SolutionId,
     };
 
     fn puzzle(day: u32, year: i32) -> (PuzzleDay, PuzzleYear) {
@@ -249,6 +281,101 @@ mod tests {
         assert!(valid_year_release_at(year, utc(2026, 12, 1, 5, 0)).is_ok());
     }
 
+    #[test]
+    fn unknown_language_names_parse_as_custom_languages() {
+        assert_eq!("Python".parse::<LanguageId>().unwrap(), LanguageId::Python);
+        assert_eq!("go".parse::<LanguageId>().unwrap(), LanguageId::Go);
+        let zig = "zig".parse::<LanguageId>().expect("custom language");
+        assert!(matches!(zig, LanguageId::Custom(_)));
+        assert_eq!(zig.to_string(), "zig");
+        assert_eq!(
+            "C-Plus_Plus".parse::<LanguageId>().unwrap().to_string(),
+            "c-plus_plus"
+        );
+        for invalid in ["", "2go", "c#", "a-very-long-language-name-indeed"] {
+            assert!(invalid.parse::<LanguageId>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn solution_ids_round_trip_and_reserve_the_default_variant_name() {
+        let fast = "year2024_day5.fast"
+            .parse::<SolutionId>()
+            .expect("variant id");
+        assert_eq!(fast.puzzle, PuzzleId::new(puzzle(5, 2024).0, year(2024)));
+        assert_eq!(fast.variant_name(), "fast");
+        assert_eq!(fast.to_string(), "year2024_day5.fast");
+        let main = "year2024_day5".parse::<SolutionId>().expect("main id");
+        assert_eq!(main, SolutionId::from(fast.puzzle));
+        assert_eq!(main.variant_name(), "default");
+        for invalid in [
+            "year2024_day5.default",
+            "year2024_day5.",
+            "year2024_day5.Fast",
+            "year2024_day5.a.b",
+            "year2024_day5.-fast",
+            "year2024_day5.a-really-long-variant-name-x",
+        ] {
+            assert!(invalid.parse::<SolutionId>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn leaderboard_ids_are_deduplicated_and_must_be_positive() {
+        let ids = " 42 7  42 "
+            .parse::<LeaderboardIds>()
+            .expect("leaderboard ids");
+        assert_eq!(ids.ids(), [42, 7]);
+        assert_eq!(ids.to_string(), "42 7");
+        assert!("".parse::<LeaderboardIds>().unwrap().is_empty());
+        for invalid in ["0", "-3", "abc", "42,7"] {
+            assert!(invalid.parse::<LeaderboardIds>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn puzzles_unlock_at_midnight_eastern_time() {
+        assert_eq!(
+            puzzle_unlock_timestamp(PuzzleId::new(
+                PuzzleDay::new(1).unwrap(),
+                PuzzleYear::new(2024).unwrap()
+            )),
+            1733029200
+        );
+    }
+
+    #[test]
+    fn cpp_flags_keep_quoted_flags_together() {
+        let flags = r#"-O2 '-DTITLE="a b"'  -Wall"#.parse::<CppFlags>().expect("cpp flags");
+        assert_eq!(flags.as_slice(), ["-O2", r#"-DTITLE="a b""#, "-Wall"]);
+        assert_eq!(flags.to_string().parse::<CppFlags>(), Ok(flags));
+        assert_eq!(CppFlags::default().to_string(), "-O2 -std=c++20");
+        assert!("-DTITLE='unbalanced".parse::<CppFlags>().is_err());
+    }
+
+    #[test]
+    fn rust_release_options_round_trip_and_reject_unknown_options() {
+        let options = "codegen-units=1  lto=thin target-cpu=native"
+            .parse::<RustReleaseOptions>()
+            .expect("release options");
+        assert_eq!(options.lto(), Some("thin"));
+        assert_eq!(options.codegen_units(), Some(1));
+        assert!(options.target_cpu_native());
+        assert_eq!(
+            options.to_string(),
+            "lto=thin codegen-units=1 target-cpu=native"
+        );
+        for invalid in [
+            "lto=max",
+            "codegen-units=0",
+            "target-cpu=znver4",
+            "opt-level=3",
+            "lto",
+        ] {
+            assert!(invalid.parse::<RustReleaseOptions>().is_err(), "{invalid}");
+        }
+    }
+
     #[test]
     fn atomic_write_replaces_a_file_without_leaving_a_temporary_file() {
         let dir = std::env::temp_dir().join(format!("aocsuite-utils-test-{}", std::process::id()));
@@ -292,4 +419,12 @@ mod tests {
         );
         std::fs::remove_dir_all(dir).expect("remove test directory");
     }
+
+    #[test]
+    fn cooldowns_are_shown_in_minutes_and_seconds_rounded_up() {
+        assert_eq!(format_cooldown(Duration::from_secs(42)), "42s");
+        assert_eq!(format_cooldown(Duration::from_secs(305)), "5m 05s");
+        assert_eq!(format_cooldown(Duration::from_millis(65_500)), "1m 06s");
+        assert_eq!(format_cooldown(Duration::from_millis(300)), "1s");
+    }
 }
@@ -1,8 +1,15 @@
 use std::{
     ffi::OsString,
-    io,
+    fmt,
+    io::{self, BufRead, BufReader, Read},
     path::PathBuf,
-    process::{Command, Output, Stdio},
+    process::{Child, Command, Output, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
 };
 
 use thiserror::Error;
@@ -14,6 +21,128 @@ pub enum ProcessMode {
     Foreground,
 }
 
+/// How often a supervised command is checked for exit, timeout and cancellation.
+const SUPERVISION_INTERVAL: Duration = Duration::from_millis(20);
+/// Longest an interrupted process waits for its supervised commands to be stopped.
+const INTERRUPT_GRACE: Duration = Duration::from_secs(2);
+/// Exit status of a process stopped by an interrupt, as shells report one killed by SIGINT.
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Set once the process received an interrupt; every supervised command then counts as
+/// cancelled.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+/// Supervised commands currently running in this process.
+static SUPERVISED: AtomicUsize = AtomicUsize::new(0);
+
+/// Stops every supervised command, then exits, on Ctrl-C or a termination signal.
+///
+/// Supervised commands run in their own process group, so the terminal's interrupt only reaches
+/// this process. Without the handler their process trees would keep running after it exits,
+/// beyond the reach of their timeouts.
+pub fn stop_supervised_commands_on_interrupt() -> io::Result<()> {
+    ctrlc::set_handler(|| {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        let started = Instant::now();
+        while SUPERVISED.load(Ordering::SeqCst) > 0 && started.elapsed() < INTERRUPT_GRACE {
+            thread::sleep(SUPERVISION_INTERVAL);
+        }
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    })
+    .map_err(io::Error::other)
+}
+
+/// Counts a supervised command as running until dropped.
+struct SupervisedCommand;
+
+impl SupervisedCommand {
+    fn start() -> io::Result<Self> {
+        SUPERVISED.fetch_add(1, Ordering::SeqCst);
+        let supervised = Self;
+        if INTERRUPTED.load(Ordering::SeqCst) {
+            return Err(interrupted());
+        }
+        Ok(supervised)
+    }
+}
+
+impl Drop for SupervisedCommand {
+    fn drop(&mut self) {
+        SUPERVISED.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn interrupted() -> io::Error {
+    io::Error::new(io::ErrorKind::Interrupted, "command was cancelled")
+}
+
+/// Shared flag that asks a running command to stop; clones observe the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancellationToken {}
+
+/// The pipe a streamed output line was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// One line of command output without its line ending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLine {
+    pub stream: OutputStream,
+    pub text: String,
+}
+
+/// Receives output lines while a command runs; stdout and stderr may call it concurrently.
+#[derive(Clone)]
+pub struct OutputSink(Arc<dyn Fn(OutputLine) + Send + Sync>);
+
+impl OutputSink {
+    pub fn new(sink: impl Fn(OutputLine) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(sink))
+    }
+
+    pub fn send(&self, line: OutputLine) {
+        (self.0)(line);
+    }
+}
+
+impl fmt::Debug for OutputSink {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("OutputSink")
+    }
+}
+
+impl PartialEq for OutputSink {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for OutputSink {}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandRequest {
     pub program: OsString,
@@ -22,6 +151,9 @@ pub struct CommandRequest {
     pub environment: Vec<(OsString, OsString)>,
     pub inherit_environment: bool,
     pub mode: ProcessMode,
+    pub timeout: Option<Duration>,
+    pub cancellation: Option<CancellationToken>,
+    pub output_sink: Option<OutputSink>,
 }
 
 impl CommandRequest {
@@ -33,6 +165,9 @@ impl CommandRequest {
             environment: Vec::new(),
             inherit_environment: true,
             mode: ProcessMode::Captured,
+            timeout: None,
+            cancellation: None,
+            output_sink: None,
         }
     }
 
@@ -69,6 +204,32 @@ impl CommandRequest {
         self.mode = ProcessMode::Foreground;
         self
     }
+
+    /// Kills the command and its children when it runs longer than `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Kills the command and its children once `token` is cancelled.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Streams captured output to `sink` line by line while still returning all of it.
+    pub fn stream_output(mut self, sink: OutputSink) -> Self {
+        self.output_sink = Some(sink);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        INTERRUPTED.load(Ordering::SeqCst)
+            || self
+                .cancellation
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+    }
 }
 
 pub trait CommandExecutor: Send + Sync {
@@ -85,13 +246,26 @@ pub enum CommandError {
         request: Box<CommandRequest>,
         output: Box<Output>,
     },
+
+    #[error("command timed out after {}s", .0.as_secs())]
+    TimedOut(Duration),
+
+    #[error("command was cancelled")]
+    Cancelled,
 }
 
 pub fn execute_command(
     executor: &dyn CommandExecutor,
     request: CommandRequest,
 ) -> Result<Output, CommandError> {
-    let output = executor.execute(&request)?;
+    let output =
+        executor
+            .execute(&request)
+            .map_err(|error| match (error.kind(), request.timeout) {
+                _ if request.is_cancelled() => CommandError::Cancelled,
+                (io::ErrorKind::TimedOut, Some(timeout)) => CommandError::TimedOut(timeout),
+                _ => CommandError::Io(error),
+            })?;
     if output.status.success() {
         Ok(output)
     } else {
@@ -119,6 +293,13 @@ impl CommandExecutor for SystemCommandExecutor {
         }
 
         match request.mode {
+            ProcessMode::Captured
+                if request.timeout.is_some()
+                    || request.cancellation.is_some()
+                    || request.output_sink.is_some() =>
+            {
+                execute_supervised(command, request)
+            }
             ProcessMode::Captured => command.output(),
             ProcessMode::Foreground => {
                 let status = command
@@ -136,11 +317,132 @@ impl CommandExecutor for SystemCommandExecutor {
     }
 }
 
+/// Captures a command like [`Command::output`] while polling for its timeout and cancellation
+/// and streaming its output lines.
+///
+/// The command runs in its own process group so that stopping it also stops the processes it
+/// started. An interrupt of this process stops it too, see
+/// [`stop_supervised_commands_on_interrupt`].
+fn execute_supervised(mut command: Command, request: &CommandRequest) -> io::Result<Output> {
+    let _supervised = SupervisedCommand::start()?;
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        command.process_group(0);
+    }
+    let mut child = command.spawn()?;
+    let stdout = read_in_background(
+        child.stdout.take(),
+        OutputStream::Stdout,
+        request.output_sink.clone(),
+    );
+    let stderr = read_in_background(
+        child.stderr.take(),
+        OutputStream::Stderr,
+        request.output_sink.clone(),
+    );
+    let started = Instant::now();
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let stopped = if request.is_cancelled() {
+            Some(interrupted())
+        } else if request
+            .timeout
+            .is_some_and(|timeout| started.elapsed() >= timeout)
+        {
+            Some(io::Error::new(io::ErrorKind::TimedOut, "command timed out"))
+        } else {
+            None
+        };
+        if let Some(error) = stopped {
+            kill_process_tree(&mut child);
+            let _ = child.wait();
+            return Err(error);
+        }
+        thread::sleep(SUPERVISION_INTERVAL);
+    };
+
+    let collect = |reader: thread::JoinHandle<io::Result<Vec<u8>>>| {
+        reader
+            .join()
+            .map_err(|_| io::Error::other("output reader panicked"))?
+    };
+    Ok(Output {
+        status,
+        stdout: collect(stdout)?,
+        stderr: collect(stderr)?,
+    })
+}
+
+fn read_in_background(
+    stream: Option<impl Read + Send + 'static>,
+    kind: OutputStream,
+    sink: Option<OutputSink>,
+) -> thread::JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut contents = Vec::new();
+        let Some(mut stream) = stream else {
+            return Ok(contents);
+        };
+        let Some(sink) = sink else {
+            stream.read_to_end(&mut contents)?;
+            return Ok(contents);
+        };
+        let mut reader = BufReader::new(stream);
+        loop {
+            let start = contents.len();
+            if reader.read_until(b'\n', &mut contents)? == 0 {
+                return Ok(contents);
+            }
+            let line = &contents[start..];
+            let line = line.strip_suffix(b"\n").unwrap_or(line);
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            sink.send(OutputLine {
+                stream: kind,
+                text: String::from_utf8_lossy(line).into_owned(),
+            });
+        }
+    })
+}
+
+fn kill_process_tree(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        // The child leads its own process group, so its id is also the group id.
+        if let Ok(group) = libc::pid_t::try_from(child.id()) {
+            // SAFETY: `killpg` only sends a signal and has no memory-safety preconditions.
+            unsafe {
+                libc::killpg(group, libc::SIGKILL);
+            }
+        }
+    }
+    #[cfg(windows)]
+    {
+        let _ = Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &child.id().to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+}
+
 #[cfg(test)]
 mod tests {
     use std::io;
 
-    use super::{execute_command, CommandError, CommandExecutor, CommandRequest};
+    use super::{
+        execute_command, CancellationToken, CommandError, CommandExecutor, CommandRequest,
+        OutputLine, OutputSink, OutputStream, SystemCommandExecutor,
+    };
 
     #[cfg(unix)]
     #[test]
@@ -165,4 +467,159 @@ mod tests {
                 if output.stdout == b"partial output" && output.stderr == b"command failed"
         ));
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn timed_out_commands_are_killed_with_their_children() {
+        let temp = tempfile::tempdir().expect("create test directory");
+        let marker = temp.path().join("survivor");
+        let started = std::time::Instant::now();
+
+        let result = execute_command(
+            &SystemCommandExecutor,
+            CommandRequest::new("sh")
+                .arg("-c")
+                .arg(format!("(sleep 1; touch '{}') & wait", marker.display()))
+                .timeout(std::time::Duration::from_millis(200)),
+        );
+
+        assert!(matches!(result, Err(CommandError::TimedOut(timeout))
+            if timeout == std::time::Duration::from_millis(200)));
+        assert!(started.elapsed() < std::time::Duration::from_secs(1));
+        std::thread::sleep(std::time::Duration::from_millis(1_200));
+        assert!(!marker.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn cancelled_commands_stop_and_finished_commands_keep_their_output() {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            cancel.cancel();
+        });
+
+        assert!(matches!(
+            execute_command(
+                &SystemCommandExecutor,
+                CommandRequest::new("sleep").arg("5").cancellation(token),
+            ),
+            Err(CommandError::Cancelled)
+        ));
+        canceller.join().expect("cancel the command");
+
+        let output = execute_command(
+            &SystemCommandExecutor,
+            CommandRequest::new("sh")
+                .arg("-c")
+                .arg("echo answer; echo warning >&2")
+                .timeout(std::time::Duration::from_secs(5))
+                .cancellation(CancellationToken::new()),
+        )
+        .expect("run supervised command");
+        assert_eq!(output.stdout, b"answer\n");
+        assert_eq!(output.stderr, b"warning\n");
+    }
+
+    /// Directory through which [`interrupted_parents_stop_their_supervised_commands`] talks to
+    /// the copy of this test binary it interrupts.
+    #[cfg(unix)]
+    const INTERRUPTED_PARENT_DIR: &str = "AOCSUITE_INTERRUPTED_PARENT_DIR";
+
+    /// Runs as the interrupted parent when [`INTERRUPTED_PARENT_DIR`] is set, and does nothing
+    /// otherwise.
+    #[cfg(unix)]
+    #[test]
+    fn interrupted_parent() {
+        let Some(dir) = std::env::var_os(INTERRUPTED_PARENT_DIR) else {
+            return;
+        };
+        let dir = std::path::PathBuf::from(dir);
+        super::stop_supervised_commands_on_interrupt().expect("install the interrupt handler");
+        let result = execute_command(
+            &SystemCommandExecutor,
+            CommandRequest::new("sh")
+                .arg("-c")
+                .arg(format!(
+                    "touch '{}'; (sleep 1; touch '{}') & wait",
+                    dir.join("started").display(),
+                    dir.join("survivor").display()
+                ))
+                .timeout(std::time::Duration::from_secs(30)),
+        );
+        assert!(matches!(result, Err(CommandError::Cancelled)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn interrupted_parents_stop_their_supervised_commands() {
+        let temp = tempfile::tempdir().expect("create test directory");
+        let mut parent = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "process::tests::interrupted_parent"])
+            .env(INTERRUPTED_PARENT_DIR, temp.path())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .expect("start the parent");
+        let started = std::time::Instant::now();
+        while !temp.path().join("started").exists() {
+            assert!(started.elapsed() < std::time::Duration::from_secs(10));
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        let pid = libc::pid_t::try_from(parent.id()).unwrap();
+        // SAFETY: `kill` only sends a signal and has no memory-safety preconditions.
+        unsafe {
+            libc::kill(pid, libc::SIGINT);
+        }
+
+        // The handler exits with 130 once the command is stopped, unless the parent's test
+        // finished first; either way the command must have been cancelled.
+        let status = parent.wait().expect("wait for the parent");
+        assert!(matches!(status.code(), Some(0 | 130)), "{status}");
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(1_200));
+        assert!(!temp.path().join("survivor").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn streamed_output_arrives_before_the_command_exits() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sink = OutputSink::new(move |line| {
+            let _ = sender.send((line, std::time::Instant::now()));
+        });
+
+        let output = execute_command(
+            &SystemCommandExecutor,
+            CommandRequest::new("sh")
+                .arg("-c")
+                .arg("echo first; echo warning >&2; sleep 0.3; printf last")
+                .stream_output(sink),
+        )
+        .expect("run streamed command");
+        let finished = std::time::Instant::now();
+
+        let mut lines = receiver.try_iter().collect::<Vec<_>>();
+        lines.sort_by_key(|(line, _)| line.text.clone());
+        let line = |stream, text: &str| OutputLine {
+            stream,
+            text: text.to_owned(),
+        };
+        assert_eq!(
+            lines
+                .iter()
+                .map(|(line, _)| line.clone())
+                .collect::<Vec<_>>(),
+            [
+                line(OutputStream::Stdout, "first"),
+                line(OutputStream::Stdout, "last"),
+                line(OutputStream::Stderr, "warning"),
+            ]
+        );
+        assert!(finished.duration_since(lines[0].1) >= std::time::Duration::from_millis(200));
+        assert_eq!(output.stdout, b"first\nlast");
+        assert_eq!(output.stderr, b"warning\n");
+    }
 }
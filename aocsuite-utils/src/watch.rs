@@ -0,0 +1,254 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use thiserror::Error;
+
+/// Quiet period that ends a burst of changes, so one save triggers one run.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Error)]
+pub enum WatchError {
+    #[error("could not watch '{}': {source}", path.display())]
+    Watch {
+        path: PathBuf,
+        source: notify::Error,
+    },
+
+    #[error("file watcher failed: {0}")]
+    Event(#[from] notify::Error),
+
+    #[error("file watcher stopped")]
+    Stopped,
+}
+
+pub type WatchResult<T> = Result<T, WatchError>;
+
+/// A directory watched for every file with `extension`, including files created after the
+/// watch started, apart from the file stems in `excluded` (compared case-insensitively).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchedDir {
+    pub path: PathBuf,
+    pub extension: String,
+    pub excluded: Vec<String>,
+}
+
+impl WatchedDir {
+    fn contains(&self, dir: &Path, path: &Path) -> bool {
+        path.parent() == Some(dir)
+            && path
+                .extension()
+                .is_some_and(|extension| extension == self.extension.as_str())
+            && path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| {
+                    !self
+                        .excluded
+                        .iter()
+                        .any(|excluded| excluded.eq_ignore_ascii_case(stem))
+                })
+    }
+}
+
+/// The files and directories a [`FileWatcher`] reports changes to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WatchList {
+    pub files: Vec<PathBuf>,
+    pub dirs: Vec<WatchedDir>,
+}
+
+/// Reports debounced changes to a fixed set of files and directories.
+///
+/// Each file is watched through its parent directory, so editors that save by replacing the
+/// file and files that do not exist yet are both picked up.
+pub struct FileWatcher {
+    files: BTreeSet<PathBuf>,
+    dirs: Vec<(PathBuf, WatchedDir)>,
+    events: mpsc::Receiver<notify::Result<Event>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    pub fn new(watched: &WatchList) -> WatchResult<Self> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = sender.send(event);
+        })?;
+
+        let mut watched_dirs = BTreeSet::new();
+        let mut watch_dir = |dir: &Path| -> WatchResult<PathBuf> {
+            let dir = if dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                dir
+            };
+            let dir = dir.canonicalize().map_err(|error| WatchError::Watch {
+                path: dir.to_path_buf(),
+                source: error.into(),
+            })?;
+            if watched_dirs.insert(dir.clone()) {
+                watcher
+                    .watch(&dir, RecursiveMode::NonRecursive)
+                    .map_err(|source| WatchError::Watch {
+                        path: dir.clone(),
+                        source,
+                    })?;
+            }
+            Ok(dir)
+        };
+
+        let mut files = BTreeSet::new();
+        for file in &watched.files {
+            let (Some(parent), Some(name)) = (file.parent(), file.file_name()) else {
+                continue;
+            };
+            files.insert(watch_dir(parent)?.join(name));
+        }
+        let mut dirs = Vec::new();
+        for dir in &watched.dirs {
+            dirs.push((watch_dir(&dir.path)?, dir.clone()));
+        }
+
+        Ok(Self {
+            files,
+            dirs,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Blocks until a watched file changes and returns the files changed in that burst.
+    pub fn wait(&self) -> WatchResult<Vec<PathBuf>> {
+        loop {
+            if let Some(changed) = self.wait_timeout(Duration::from_secs(3600))? {
+                return Ok(changed);
+            }
+        }
+    }
+
+    /// Like [`FileWatcher::wait`], but gives up when no change starts within `timeout`.
+    pub fn wait_timeout(&self, timeout: Duration) -> WatchResult<Option<Vec<PathBuf>>> {
+        let deadline = Instant::now() + timeout;
+        let mut changed = BTreeSet::new();
+        while changed.is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.events.recv_timeout(remaining) {
+                Ok(event) => self.collect(event?, &mut changed),
+                Err(mpsc::RecvTimeoutError::Timeout) => return Ok(None),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Err(WatchError::Stopped),
+            }
+        }
+        loop {
+            match self.events.recv_timeout(DEBOUNCE) {
+                Ok(event) => self.collect(event?, &mut changed),
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    return Ok(Some(changed.into_iter().collect()))
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Err(WatchError::Stopped),
+            }
+        }
+    }
+
+    fn collect(&self, event: Event, changed: &mut BTreeSet<PathBuf>) {
+        // Reading a file reports access events, which would re-run on every run.
+        if matches!(event.kind, EventKind::Access(_) | EventKind::Other) {
+            return;
+        }
+        changed.extend(event.paths.into_iter().filter(|path| {
+            self.files.contains(path)
+                || self
+                    .dirs
+                    .iter()
+                    .any(|(canonical, dir)| dir.contains(canonical, path))
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use super::{FileWatcher, WatchList, WatchedDir};
+
+    #[test]
+    fn bursts_of_saves_are_reported_once_and_other_files_are_ignored() {
+        let temp = tempfile::tempdir().expect("create test directory");
+        let root = temp
+            .path()
+            .canonicalize()
+            .expect("canonical test directory");
+        let solution = root.join("solution.rs");
+        let missing = root.join("examples").join("2024-01.txt");
+        fs::write(&solution, "fn main() {}\n").expect("write solution");
+        fs::create_dir_all(missing.parent().unwrap()).expect("create examples");
+        let watcher = FileWatcher::new(&WatchList {
+            files: vec![solution.clone(), missing.clone()],
+            dirs: Vec::new(),
+        })
+        .expect("watch files");
+
+        fs::write(root.join("notes.txt"), "unrelated\n").expect("write unrelated file");
+        assert_eq!(
+            watcher
+                .wait_timeout(Duration::from_millis(300))
+                .expect("wait for changes"),
+            None
+        );
+
+        for contents in ["fn main() { 1; }\n", "fn main() { 2; }\n"] {
+            fs::write(&solution, contents).expect("save solution");
+        }
+        fs::write(&missing, "1\n").expect("create example");
+        let changed = watcher
+            .wait_timeout(Duration::from_secs(5))
+            .expect("wait for changes")
+            .expect("files changed");
+        assert_eq!(changed, [missing, solution]);
+        assert_eq!(
+            watcher
+                .wait_timeout(Duration::from_millis(300))
+                .expect("wait for changes"),
+            None
+        );
+    }
+
+    #[test]
+    fn files_added_to_a_watched_directory_are_reported_unless_excluded() {
+        let temp = tempfile::tempdir().expect("create test directory");
+        let lib = temp
+            .path()
+            .canonicalize()
+            .expect("canonical test directory");
+        let watcher = FileWatcher::new(&WatchList {
+            files: Vec::new(),
+            dirs: vec![WatchedDir {
+                path: lib.clone(),
+                extension: "py".to_owned(),
+                excluded: vec!["main".to_owned()],
+            }],
+        })
+        .expect("watch library directory");
+
+        fs::write(lib.join("Main.py"), "").expect("write excluded file");
+        fs::write(lib.join("notes.txt"), "").expect("write other file");
+        assert_eq!(
+            watcher
+                .wait_timeout(Duration::from_millis(300))
+                .expect("wait for changes"),
+            None
+        );
+
+        let grid = lib.join("grid.py");
+        fs::write(&grid, "").expect("add library");
+        let changed = watcher
+            .wait_timeout(Duration::from_secs(5))
+            .expect("wait for changes")
+            .expect("library added");
+        assert_eq!(changed, [grid]);
+    }
+}